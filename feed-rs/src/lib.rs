@@ -70,6 +70,40 @@
 //!     .build();
 //! let feed = parser.parse(BufReader::new(file)).unwrap();
 //! ```
+//!
+//! ## Writing
+//!
+//! A feed in the model may be serialised back to one of the supported formats, regardless of the format it was parsed from.
+//! For example, to publish a filtered version of a feed as Atom:
+//!
+//! ```rust,no_run
+//! use std::fs::File;
+//! use std::io::BufReader;
+//! use feed_rs::{parser, writer};
+//!
+//! let file = File::open("example.xml").unwrap();
+//! let mut feed = parser::parse(BufReader::new(file)).unwrap();
+//! feed.entries.retain(|entry| entry.title.is_some());
+//!
+//! let mut out = File::create("filtered.xml").unwrap();
+//! writer::atom::write(&feed, &mut out).unwrap();
+//! ```
+//!
+//! ## Validation
+//!
+//! The parser accepts (and where possible repairs) feeds that do not follow their specification. To check a feed you publish, the `validator`
+//! reports each violation (e.g. missing required elements, or timestamps in the wrong format) with its severity and location:
+//!
+//! ```rust,no_run
+//! use std::fs::File;
+//! use std::io::BufReader;
+//! use feed_rs::validator;
+//!
+//! let file = File::open("example.xml").unwrap();
+//! for violation in validator::validate(BufReader::new(file)).unwrap() {
+//!     println!("{}", violation);
+//! }
+//! ```
 
 // TODO review the Rust doc guidelines and fix up links
 // TODO improve tests with Coverage analysis e.g. https://github.com/mozilla/grcov
@@ -87,3 +121,5 @@ mod xml;
 
 pub mod model;
 pub mod parser;
+pub mod validator;
+pub mod writer;
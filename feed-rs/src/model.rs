@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::time::Duration;
 
 use chrono::{DateTime, Utc};
@@ -61,7 +62,7 @@ pub struct Feed {
     /// Links to related pages
     /// * Atom (recommended): Identifies a related Web page.
     /// * RSS 1 + 2 (required): The URL to the HTML website corresponding to the channel.
-    /// * JSON Feed: the homepage and feed URLs
+    /// * JSON Feed: the homepage and feed URLs, the next page of items (rel "next") and subscription hubs (rel "hub")
     pub links: Vec<Link>,
 
     /// Structured classification of the feed
@@ -91,19 +92,36 @@ pub struct Feed {
     /// RSS 2 (optional): The publication date for the content in the channel.
     pub published: Option<DateTime<Utc>>,
     /// Rating for the content
-    /// * Populated from the media or itunes namespaces
+    /// * Populated from the media or itunes namespaces, with "media:rating" taking precedence
     pub rating: Option<MediaRating>,
+    /// Media RSS elements at the channel level (e.g. thumbnails, credits and copyright), which apply to all media within the feed
+    ///
+    /// Entries do not inherit these values unless requested when building the parser (see `parser::Builder::inherit_media()`)
+    pub media: Option<MediaObject>,
     /// Rights restricting content within the feed
     /// * Atom (optional): Conveys information about rights, e.g. copyrights, held in and over the feed.
     /// * RSS 2 (optional) "copyright": Copyright notice for content in the channel.
     pub rights: Option<Text>,
     /// RSS 2 (optional): It's a number of minutes that indicates how long a channel can be cached before refreshing from the source.
     pub ttl: Option<u32>,
+    /// JSON Feed (optional): Indicates the feed is finished, so will not be updated again.
+    pub expired: bool,
+    /// Extension for Podcasting 2.0 - <https://podcastindex.org/namespace/1.0>
+    pub podcast: Option<PodcastChannel>,
+    /// Extension for iTunes podcasts - <https://help.apple.com/itc/podcasts_connect/#/itcb54353390>
+    ///
+    /// Tags mapped to other fields (e.g. "itunes:image" to the logo) are not repeated here
+    pub itunes: Option<ItunesMeta>,
 
     /// The individual items within the feed
     /// * Atom (optional): Individual entries within the feed (e.g. a blog post)
     /// * RSS 1+2 (optional): Individual items within the channel.
     pub entries: Vec<Entry>,
+
+    /// Elements of the feed in namespaces that are not otherwise supported (e.g. "georss:point")
+    pub extensions: ExtensionMap,
+    /// JSON Feed: extension objects (e.g. "_microblog") and any other members of the feed that are not otherwise supported, by key
+    pub json_extensions: BTreeMap<String, serde_json::Value>,
 }
 
 impl Feed {
@@ -124,9 +142,80 @@ impl Feed {
             logo: None,
             published: None,
             rating: None,
+            media: None,
             rights: None,
             ttl: None,
+            expired: false,
+            podcast: None,
+            itunes: None,
             entries: Vec::new(),
+            extensions: ExtensionMap::new(),
+            json_extensions: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the canonical URL the feed advertises for itself, if it differs from the URL the feed was fetched from
+    ///
+    /// Hosts signal that a feed has moved with "itunes:new-feed-url", which takes precedence, or by changing the "self" link of the feed
+    /// (an "atom:link" element with rel="self", or the "feed_url" of a JSON Feed).
+    /// A relative self link is resolved against the fetched URL, and URLs are compared once normalised (e.g. the case of the host).
+    /// When a relocation is reported, subscribers should update their stored URL.
+    ///
+    /// # Arguments
+    ///
+    /// * `fetched` - the URL the feed was fetched from
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed_rs::model::RelocationSource;
+    /// use feed_rs::parser;
+    /// let xml = r#"
+    /// <rss version="2.0" xmlns:itunes="http://www.itunes.com/dtds/podcast-1.0.dtd">
+    ///   <channel>
+    ///     <title>sample podcast</title>
+    ///     <itunes:new-feed-url>https://example.com/new/feed.xml</itunes:new-feed-url>
+    ///   </channel>
+    /// </rss>
+    /// "#;
+    /// let feed = parser::parse(xml.as_bytes()).unwrap();
+    ///
+    /// let relocation = feed.relocation("https://example.com/old/feed.xml").unwrap();
+    /// assert_eq!(relocation.url.as_str(), "https://example.com/new/feed.xml");
+    /// assert_eq!(relocation.source, RelocationSource::ItunesNewFeedUrl);
+    ///
+    /// // Nothing is reported once subscribers use the new URL
+    /// assert!(feed.relocation("https://example.com/new/feed.xml").is_none());
+    /// ```
+    pub fn relocation(&self, fetched: &str) -> Option<Relocation> {
+        let fetched = Url::parse(fetched).ok();
+        let resolve = |href: &str| match &fetched {
+            Some(base) => base.join(href).ok(),
+            None => Url::parse(href).ok(),
+        };
+
+        let new_feed_url = self.itunes.as_ref().and_then(|meta| meta.new_feed_url.clone());
+        let relocation = match new_feed_url {
+            Some(url) => Relocation {
+                url,
+                source: RelocationSource::ItunesNewFeedUrl,
+            },
+            None => self
+                .links
+                .iter()
+                .filter(|link| link.rel.as_deref() == Some("self"))
+                .find_map(|link| resolve(&link.href))
+                .map(|url| Relocation {
+                    url,
+                    source: RelocationSource::SelfLink,
+                })?,
+        };
+
+        // The feed has not moved if it was fetched from the advertised URL
+        if fetched.as_ref() == Some(&relocation.url) {
+            None
+        } else {
+            Some(relocation)
         }
     }
 }
@@ -158,6 +247,16 @@ impl Feed {
         self
     }
 
+    pub fn extension(mut self, uri: &str, extension: Extension) -> Self {
+        extension.add_to(uri, &mut self.extensions);
+        self
+    }
+
+    pub fn json_extension(mut self, key: &str, value: serde_json::Value) -> Self {
+        self.json_extensions.insert(key.to_owned(), value);
+        self
+    }
+
     pub fn generator(mut self, generator: Generator) -> Self {
         self.generator = Some(generator);
         self
@@ -173,6 +272,11 @@ impl Feed {
         self
     }
 
+    pub fn itunes(mut self, meta: ItunesMeta) -> Self {
+        self.itunes = Some(meta);
+        self
+    }
+
     pub fn language(mut self, language: &str) -> Self {
         self.language = Some(language.to_owned());
         self
@@ -188,11 +292,21 @@ impl Feed {
         self
     }
 
+    pub fn media(mut self, media: MediaObject) -> Self {
+        self.media = Some(media);
+        self
+    }
+
     pub fn published(mut self, pub_date: &str) -> Self {
         self.published = parse_timestamp_lenient(pub_date);
         self
     }
 
+    pub fn rating(mut self, rating: MediaRating) -> Self {
+        self.rating = Some(rating);
+        self
+    }
+
     pub fn rights(mut self, rights: Text) -> Self {
         self.rights = Some(rights);
         self
@@ -263,7 +377,7 @@ pub struct Entry {
     /// * Atom (recommended): Identifies a related Web page.
     /// * RSS 2 (optional): The URL of the item.
     /// * RSS 1 (required): The item's URL.
-    /// * JSON Feed: the url and external URL for the item is the first items, then each subsequent attachment
+    /// * JSON Feed: the url and external URL for the item is the first items, then each subsequent attachment, then the banner image (rel "banner")
     pub links: Vec<Link>,
     /// A short summary of the item
     /// * Atom (recommended): Conveys a short summary, abstract, or excerpt of the entry.
@@ -297,13 +411,30 @@ pub struct Entry {
     /// 2) a default for any other "media:*" elements found at the item level
     ///
     /// See the Atom tests for youtube and newscred for examples
+    ///
+    /// JSON Feed: each attachment, and the image as a thumbnail
     pub media: Vec<MediaObject>,
+    /// Extension for Podcasting 2.0 - <https://podcastindex.org/namespace/1.0>
+    ///
+    /// Elements describing the media of the item (e.g. transcripts) are instead added to the default MediaObject
+    pub podcast: Option<PodcastItem>,
+    /// Extension for iTunes podcasts - <https://help.apple.com/itc/podcasts_connect/#/itcb54353390>
+    ///
+    /// Tags describing the media of the item (e.g. "itunes:duration") are instead added to the default MediaObject
+    pub itunes: Option<ItunesMeta>,
 
-    /// Atom (optional): The language specified on the item
+    /// The language of the item
+    /// * Atom (optional): The language specified on the item
+    /// * JSON Feed (optional): The language of the item, if it differs from the feed
     pub language: Option<String>,
     /// Atom (optional): The base url specified on the item to resolve any relative
     /// references found within the scope on the item
     pub base: Option<String>,
+
+    /// Elements of the entry in namespaces that are not otherwise supported (e.g. "yt:videoId")
+    pub extensions: ExtensionMap,
+    /// JSON Feed: extension objects (e.g. "_podcast") and any other members of the item that are not otherwise supported, by key
+    pub json_extensions: BTreeMap<String, serde_json::Value>,
 }
 
 impl Default for Entry {
@@ -322,8 +453,12 @@ impl Default for Entry {
             source: None,
             rights: None,
             media: Vec::new(),
+            podcast: None,
+            itunes: None,
             language: None,
             base: None,
+            extensions: ExtensionMap::new(),
+            json_extensions: BTreeMap::new(),
         }
     }
 }
@@ -350,11 +485,26 @@ impl Entry {
         self
     }
 
+    pub fn extension(mut self, uri: &str, extension: Extension) -> Self {
+        extension.add_to(uri, &mut self.extensions);
+        self
+    }
+
+    pub fn json_extension(mut self, key: &str, value: serde_json::Value) -> Self {
+        self.json_extensions.insert(key.to_owned(), value);
+        self
+    }
+
     pub fn id(mut self, id: &str) -> Self {
         self.id = id.trim().to_string();
         self
     }
 
+    pub fn itunes(mut self, meta: ItunesMeta) -> Self {
+        self.itunes = Some(meta);
+        self
+    }
+
     pub fn link(mut self, link: Link) -> Self {
         self.links.push(link);
         self
@@ -524,6 +674,55 @@ impl Content {
     }
 }
 
+/// Elements in namespaces that are not otherwise supported, by namespace URI and then element name
+///
+/// Elements without a namespace are recorded under an empty URI.
+pub type ExtensionMap = BTreeMap<String, BTreeMap<String, Vec<Extension>>>;
+
+/// An element in a namespace that is not otherwise supported, for applications to interpret
+///
+/// For example, `<yt:videoId>abc</yt:videoId>` is recorded as an extension with the name "videoId" and value "abc", within the YouTube namespace.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Extension {
+    /// Name of the element, without the namespace prefix
+    pub name: String,
+    /// Text content of the element, if any
+    pub value: Option<String>,
+    /// Attributes of the element, by name (including the namespace prefix, if any)
+    pub attrs: BTreeMap<String, String>,
+    /// Child elements
+    pub children: ExtensionMap,
+}
+
+#[cfg(test)]
+impl Extension {
+    pub fn new(name: &str) -> Self {
+        Extension {
+            name: name.to_owned(),
+            ..Default::default()
+        }
+    }
+
+    pub fn attr(mut self, name: &str, value: &str) -> Self {
+        self.attrs.insert(name.to_owned(), value.to_owned());
+        self
+    }
+
+    pub fn child(mut self, uri: &str, extension: Extension) -> Self {
+        extension.add_to(uri, &mut self.children);
+        self
+    }
+
+    pub fn value(mut self, value: &str) -> Self {
+        self.value = Some(value.to_owned());
+        self
+    }
+
+    fn add_to(self, uri: &str, extensions: &mut ExtensionMap) {
+        extensions.entry(uri.to_owned()).or_default().entry(self.name.clone()).or_default().push(self);
+    }
+}
+
 /// Information on the tools used to generate the feed
 ///
 /// Atom: Identifies the software used to generate the feed, for debugging and other purposes.
@@ -716,12 +915,67 @@ pub struct MediaObject {
     pub community: Option<MediaCommunity>,
     /// Credits
     pub credits: Vec<MediaCredit>,
+    /// Keywords describing the media (from the media:keywords element)
+    pub keywords: Vec<String>,
+    /// Categories of the media (from media:category elements)
+    pub categories: Vec<Category>,
+    /// Hashes used to verify the media (from media:hash elements)
+    pub hashes: Vec<MediaHash>,
+    /// A player for the media, if declared outside of a media:content element (from the media:player element)
+    pub player: Option<MediaPlayer>,
+    /// Markup for embedding the media (from the media:embed element)
+    pub embed: Option<MediaEmbed>,
+    /// Licenses of the media (from media:license elements)
+    pub licenses: Vec<MediaLicense>,
+    /// Restrictions on where the media may be played (from media:restriction elements)
+    pub restrictions: Vec<MediaRestriction>,
+    /// Prices of the media (from media:price elements)
+    pub prices: Vec<MediaPrice>,
+    /// Links to the media on P2P networks (from media:peerLink elements)
+    pub peer_links: Vec<Link>,
+    /// Links to subtitles for the media, with the language in `href_lang` (from media:subTitle elements)
+    pub subtitles: Vec<Link>,
+    /// Locations related to the media (from media:location elements)
+    pub locations: Vec<MediaLocation>,
+    /// Scenes within the media (from the media:scenes element)
+    pub scenes: Vec<MediaScene>,
+    /// Status of the media (from the media:status element)
+    pub status: Option<MediaStatus>,
+    /// Copyright of the media (from the media:copyright element)
+    pub copyright: Option<MediaCopyright>,
+    /// Pages linking to the media (from the media:backLinks element)
+    pub back_links: Vec<Url>,
+    /// Podcasting 2.0 elements describing the media (e.g. transcripts and chapters)
+    pub podcast: Option<PodcastMedia>,
 }
 
 impl MediaObject {
     // Checks if this object has been populated with content
     pub(crate) fn has_content(&self) -> bool {
-        self.title.is_some() || self.description.is_some() || !self.content.is_empty() || !self.thumbnails.is_empty() || !self.texts.is_empty()
+        self.title.is_some()
+            || self.description.is_some()
+            || !self.content.is_empty()
+            || !self.thumbnails.is_empty()
+            || !self.texts.is_empty()
+            || !self.keywords.is_empty()
+            || !self.categories.is_empty()
+            || !self.hashes.is_empty()
+            || self.player.is_some()
+            || self.embed.is_some()
+            || !self.licenses.is_empty()
+            || !self.restrictions.is_empty()
+            || !self.prices.is_empty()
+            || !self.peer_links.is_empty()
+            || !self.subtitles.is_empty()
+            || !self.locations.is_empty()
+            || !self.scenes.is_empty()
+            || self.status.is_some()
+            || self.copyright.is_some()
+            || !self.back_links.is_empty()
+            || self.podcast.is_some()
+            || self.duration.is_some()
+            || self.community.is_some()
+            || !self.credits.is_empty()
     }
 }
 
@@ -752,6 +1006,11 @@ impl MediaObject {
         self
     }
 
+    pub fn keyword(mut self, keyword: &str) -> Self {
+        self.keywords.push(keyword.to_string());
+        self
+    }
+
     pub fn text(mut self, text: MediaText) -> Self {
         self.texts.push(text);
         self
@@ -828,6 +1087,8 @@ pub struct MediaContent {
     pub size: Option<u64>,
     /// Rating
     pub rating: Option<MediaRating>,
+    /// A player for the media (from a child media:player element)
+    pub player: Option<MediaPlayer>,
 }
 
 #[cfg(test)]
@@ -842,6 +1103,15 @@ impl MediaContent {
         self
     }
 
+    pub fn player(mut self, url: &str) -> Self {
+        self.player = Some(MediaPlayer {
+            url: Url::parse(url).unwrap(),
+            height: None,
+            width: None,
+        });
+        self
+    }
+
     pub fn url(mut self, url: &str) -> Self {
         self.url = Some(Url::parse(url).unwrap());
         self
@@ -873,10 +1143,20 @@ impl MediaContent {
             duration: None,
             size: None,
             rating: None,
+            player: None,
         }
     }
 }
 
+/// Represents a "media:copyright" item from the RSS Media spec
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct MediaCopyright {
+    /// The copyright notice
+    pub notice: String,
+    /// URL for additional copyright information
+    pub url: Option<Url>,
+}
+
 /// Represents a "media:credit" item from the RSS Media spec
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct MediaCredit {
@@ -890,6 +1170,75 @@ impl MediaCredit {
     }
 }
 
+/// Represents a "media:embed" item from the RSS Media spec
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct MediaEmbed {
+    /// URL of the embeddable player
+    pub url: Option<Url>,
+    /// Height and width of the player
+    pub height: Option<u32>,
+    pub width: Option<u32>,
+    /// Parameters for the player, keyed by name (from media:param elements)
+    pub params: BTreeMap<String, String>,
+}
+
+/// Represents a "media:hash" item from the RSS Media spec
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct MediaHash {
+    /// The algorithm used to create the hash (defaults to "md5" per the spec)
+    pub algo: String,
+    /// The hash, as hex
+    pub value: String,
+}
+
+/// Represents a "media:license" item from the RSS Media spec
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct MediaLicense {
+    /// Name of the license
+    pub name: Option<String>,
+    /// MIME type of the license document
+    pub content_type: Option<MediaTypeBuf>,
+    /// URL of the license document
+    pub href: Option<Url>,
+}
+
+/// Represents a "media:location" item from the RSS Media spec
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct MediaLocation {
+    /// Description of the place
+    pub description: Option<String>,
+    /// Time in the media at which the location becomes relevant
+    pub start_time: Option<Duration>,
+    /// Time in the media at which the location is no longer relevant
+    pub end_time: Option<Duration>,
+    /// Latitude and longitude of the place (from a georss:point or gml:pos child element)
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+}
+
+/// Represents a "media:player" item from the RSS Media spec
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct MediaPlayer {
+    /// URL of the player
+    pub url: Url,
+    /// Height and width of the player
+    pub height: Option<u32>,
+    pub width: Option<u32>,
+}
+
+/// Represents a "media:price" item from the RSS Media spec
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct MediaPrice {
+    /// Type of the price ("rent", "purchase", "package" or "subscription")
+    pub price_type: Option<String>,
+    /// The price, omitted if free or a subscription
+    pub price: Option<f64>,
+    /// URL for information about purchasing the media
+    pub info: Option<Url>,
+    /// ISO 4217 code of the currency of the price
+    pub currency: Option<String>,
+}
+
 /// Rating of the feed, item or media within the content
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct MediaRating {
@@ -910,6 +1259,54 @@ impl MediaRating {
     }
 }
 
+/// Represents a "media:restriction" item from the RSS Media spec
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct MediaRestriction {
+    /// Whether the media may or may not be played in the listed places
+    pub relationship: MediaRelationship,
+    /// Type of the places ("country", "uri" or "sharing")
+    pub restriction_type: Option<String>,
+    /// The places, or "all" and "none"
+    pub values: Vec<String>,
+}
+
+/// The relationship of a "media:restriction" to the places it lists
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum MediaRelationship {
+    Allow,
+    Deny,
+}
+
+/// Represents a "media:scene" item from the RSS Media spec
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct MediaScene {
+    /// Title of the scene
+    pub title: Option<String>,
+    /// Description of the scene
+    pub description: Option<String>,
+    /// Time in the media at which the scene starts
+    pub start_time: Option<Duration>,
+    /// Time in the media at which the scene ends
+    pub end_time: Option<Duration>,
+}
+
+/// Represents a "media:status" item from the RSS Media spec
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct MediaStatus {
+    /// The state of the media
+    pub state: MediaState,
+    /// The reason for the state, as text or a URL
+    pub reason: Option<String>,
+}
+
+/// The state of media, from "media:status"
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum MediaState {
+    Active,
+    Blocked,
+    Deleted,
+}
+
 /// Represents a "media:text" item from the RSS Media spec
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct MediaText {
@@ -984,6 +1381,279 @@ impl Person {
     }
 }
 
+/// Podcasting 2.0 elements at the channel level
+///
+/// [Podcast namespace]: https://podcastindex.org/namespace/1.0
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct PodcastChannel {
+    /// A globally unique, permanent identifier for the podcast (from podcast:guid)
+    pub guid: Option<String>,
+    /// Whether other platforms may import the feed (from podcast:locked)
+    pub locked: Option<PodcastLocked>,
+    /// Ways to support the podcast financially (from podcast:funding)
+    pub funding: Vec<PodcastFunding>,
+    /// People involved with the podcast (from podcast:person)
+    pub persons: Vec<PodcastPerson>,
+    /// Payment of value to the podcast (from podcast:value)
+    pub value: Vec<PodcastValue>,
+}
+
+/// Podcasting 2.0 elements at the item level, other than those describing the media
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct PodcastItem {
+    /// Season the episode belongs to (from podcast:season)
+    pub season: Option<PodcastSeason>,
+    /// Number of the episode (from podcast:episode)
+    pub episode: Option<PodcastEpisode>,
+    /// People involved with the episode (from podcast:person)
+    pub persons: Vec<PodcastPerson>,
+    /// Payment of value to the episode, replacing that of the channel (from podcast:value)
+    pub value: Vec<PodcastValue>,
+}
+
+/// Podcasting 2.0 elements describing the media of an item
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct PodcastMedia {
+    /// Transcripts or captions of the media (from podcast:transcript)
+    pub transcripts: Vec<PodcastTranscript>,
+    /// Chapters of the media (from podcast:chapters)
+    pub chapters: Option<PodcastChapters>,
+    /// Segments suitable for sharing (from podcast:soundbite)
+    pub soundbites: Vec<PodcastSoundbite>,
+    /// Alternate versions of the media e.g. at a different bitrate (from podcast:alternateEnclosure)
+    pub alternate_enclosures: Vec<PodcastAlternateEnclosure>,
+}
+
+/// iTunes podcast tags at the channel or item level
+///
+/// Fields not applicable at a given level (e.g. "itunes:episode" for the channel) are not populated.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ItunesMeta {
+    /// Channel: whether episodes are intended to be consumed in any order, or in sequence (from itunes:type)
+    pub podcast_type: Option<ItunesPodcastType>,
+    /// Channel: the URL the podcast has moved to (from itunes:new-feed-url)
+    pub new_feed_url: Option<Url>,
+    /// Channel: whether the podcast is finished, so no more episodes will be published (from itunes:complete)
+    pub complete: Option<bool>,
+    /// Channel: the owner of the podcast, who is also listed in the contributors of the feed (from itunes:owner)
+    pub owner: Option<Person>,
+    /// Item: the number of the episode (from itunes:episode)
+    pub episode: Option<u32>,
+    /// Item: the season the episode belongs to (from itunes:season)
+    pub season: Option<u32>,
+    /// Item: the type of the episode (from itunes:episodeType)
+    pub episode_type: Option<ItunesEpisodeType>,
+    /// Item: whether the episode contains explicit content (from itunes:explicit)
+    ///
+    /// At the channel level this is instead the `rating` of the feed
+    pub explicit: Option<bool>,
+    /// Whether the podcast or episode should be hidden from directories (from itunes:block)
+    pub block: Option<bool>,
+    /// Keywords describing the podcast or episode (from itunes:keywords)
+    pub keywords: Vec<String>,
+    /// A short description of the podcast or episode (from itunes:subtitle)
+    pub subtitle: Option<String>,
+}
+
+/// Type of a podcast, from itunes:type
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum ItunesPodcastType {
+    /// Episodes may be consumed in any order, with the newest presented first
+    Episodic,
+    /// Episodes are intended to be consumed in sequence, with the oldest presented first
+    Serial,
+}
+
+/// Type of an episode, from itunes:episodeType
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum ItunesEpisodeType {
+    /// Complete content
+    Full,
+    /// A short preview of the podcast or a season
+    Trailer,
+    /// Extra content (e.g. behind the scenes)
+    Bonus,
+}
+
+/// An alternate version of the media of an item
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct PodcastAlternateEnclosure {
+    /// MIME type of the media
+    pub content_type: Option<MediaTypeBuf>,
+    /// Size of the media in bytes
+    pub length: Option<u64>,
+    /// Average encoding bitrate in bits per second
+    pub bitrate: Option<f64>,
+    /// Height of the media, if video
+    pub height: Option<u32>,
+    /// Language of the media, if different from the feed
+    pub language: Option<String>,
+    /// A short title for this version of the media
+    pub title: Option<String>,
+    /// Groups alternate versions of the media (e.g. different languages)
+    pub rel: Option<String>,
+    /// Codecs of the media, as defined in RFC 6381
+    pub codecs: Option<String>,
+    /// Whether this is the same media as the enclosure of the item
+    pub default: bool,
+    /// Locations from which the media may be retrieved
+    pub sources: Vec<PodcastSource>,
+    /// Used to verify the integrity of the media
+    pub integrity: Option<PodcastIntegrity>,
+}
+
+/// A location from which media may be retrieved
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct PodcastSource {
+    /// URI of the media (e.g. a HTTP URL, or a magnet link)
+    pub uri: String,
+    /// MIME type of the media, if different from that of the alternate enclosure
+    pub content_type: Option<MediaTypeBuf>,
+}
+
+/// Verifies the integrity of media
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct PodcastIntegrity {
+    /// Type of verification ("sri" or "pgp-signature")
+    pub integrity_type: String,
+    /// The hash or signature
+    pub value: String,
+}
+
+/// Chapters of the media of an item
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct PodcastChapters {
+    /// URL of the chapters file
+    pub url: Url,
+    /// MIME type of the chapters file (e.g. "application/json+chapters")
+    pub content_type: Option<MediaTypeBuf>,
+}
+
+/// Number of an episode
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct PodcastEpisode {
+    /// The number of the episode, which may be fractional
+    pub number: f64,
+    /// Alternative text to display in place of the number
+    pub display: Option<String>,
+}
+
+/// A way to support the podcast financially
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct PodcastFunding {
+    /// URL of the page to support the podcast
+    pub url: Url,
+    /// Label for the link
+    pub label: Option<String>,
+}
+
+/// Whether a podcast may be imported into other platforms
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct PodcastLocked {
+    /// If true, the podcast may not be imported
+    pub locked: bool,
+    /// Email address of the owner, who may verify a transfer
+    pub owner: Option<String>,
+}
+
+/// A person involved with a podcast or episode
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct PodcastPerson {
+    /// Name of the person
+    pub name: String,
+    /// Role of the person from the Podcast Taxonomy Project (e.g. "guest"), the spec defaulting to "host" if absent
+    pub role: Option<String>,
+    /// Group of the role (e.g. "writing"), the spec defaulting to "cast" if absent
+    pub group: Option<String>,
+    /// URL of a picture of the person
+    pub img: Option<Url>,
+    /// URL of a page relating to the person
+    pub href: Option<Url>,
+}
+
+/// A season of a podcast
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct PodcastSeason {
+    /// The number of the season
+    pub number: u32,
+    /// Name of the season
+    pub name: Option<String>,
+}
+
+/// A segment of the media suitable for sharing
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct PodcastSoundbite {
+    /// Time at which the segment starts
+    pub start_time: Duration,
+    /// Duration of the segment
+    pub duration: Duration,
+    /// Title of the segment
+    pub title: Option<String>,
+}
+
+/// A transcript or captions of the media of an item
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct PodcastTranscript {
+    /// URL of the transcript
+    pub url: Url,
+    /// MIME type of the transcript (e.g. "text/vtt")
+    pub content_type: Option<MediaTypeBuf>,
+    /// Language of the transcript, if different from the feed
+    pub language: Option<String>,
+    /// "captions" if the transcript is intended as closed captions
+    pub rel: Option<String>,
+}
+
+/// Payment of value to a podcast (e.g. via the Lightning Network)
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct PodcastValue {
+    /// The service used to transfer value (e.g. "lightning")
+    pub value_type: String,
+    /// The method of transfer (e.g. "keysend")
+    pub method: String,
+    /// Suggested amount to transfer per minute of playback
+    pub suggested: Option<String>,
+    /// Recipients of the value
+    pub recipients: Vec<PodcastValueRecipient>,
+}
+
+/// A recipient of value transferred to a podcast
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct PodcastValueRecipient {
+    /// Name of the recipient
+    pub name: Option<String>,
+    /// Key identifying the recipient to the receiving node
+    pub custom_key: Option<String>,
+    /// Value of the custom key
+    pub custom_value: Option<String>,
+    /// Type of the address (e.g. "node")
+    pub recipient_type: String,
+    /// Address of the recipient
+    pub address: String,
+    /// Share of the value for the recipient
+    pub split: u32,
+    /// Whether this recipient receives a fee rather than a share
+    pub fee: bool,
+}
+
+/// The canonical URL advertised by a feed, as returned by [`Feed::relocation`]
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Relocation {
+    /// The URL subscribers should use for the feed
+    pub url: Url,
+    /// The signal the URL was taken from
+    pub source: RelocationSource,
+}
+
+/// The signal a feed used to advertise its canonical URL
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum RelocationSource {
+    /// The "itunes:new-feed-url" element of a podcast
+    ItunesNewFeedUrl,
+    /// A "self" link (an "atom:link" element with rel="self", or the "feed_url" of a JSON Feed)
+    SelfLink,
+}
+
 /// Textual content, or link to the content, for a given entry.
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct Text {
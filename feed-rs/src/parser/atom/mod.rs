@@ -7,7 +7,7 @@ use crate::parser::mediarss::handle_media_element;
 use crate::parser::util;
 use crate::parser::util::if_some_then;
 use crate::parser::{mediarss, Parser};
-use crate::parser::{ParseErrorKind, ParseFeedError, ParseFeedResult};
+use crate::parser::{ParseErrorKind, ParseFeedError, ParseFeedResult, WarningKind};
 use crate::xml::{Element, NS};
 
 #[cfg(test)]
@@ -22,47 +22,14 @@ pub(crate) fn parse_feed<R: BufRead>(parser: &Parser, root: Element<R>) -> Parse
     for child in root.children() {
         let child = child?;
         match child.ns_and_tag() {
-            (NS::Atom, "id") => if_some_then(child.child_as_text(), |id| feed.id = id),
+            (NS::Atom, "entry") => if_some_then(parser.isolate_entry(child, handle_entry)?, |entry| feed.entries.push(entry)),
 
-            (NS::Atom, "title") => feed.title = handle_text(child)?,
-
-            (NS::Atom, "updated") => if_some_then(child.child_as_text(), |text| feed.updated = parser.parse_timestamp(&text)),
-
-            (NS::Atom, "author") => if_some_then(handle_person(child)?, |person| feed.authors.push(person)),
-
-            (NS::Atom, "link") => if_some_then(handle_link(child), |link| feed.links.push(link)),
-
-            (NS::Atom, "category") => if_some_then(handle_category(child), |category| feed.categories.push(category)),
-
-            (NS::Atom, "contributor") => if_some_then(handle_person(child)?, |person| feed.contributors.push(person)),
-
-            (NS::Atom, "generator") => feed.generator = handle_generator(child),
-
-            (NS::Atom, "icon") => feed.icon = handle_image(child),
-
-            (NS::Atom, "logo") => feed.logo = handle_image(child),
-
-            (NS::Atom, "rights") => feed.rights = handle_text(child)?,
-
-            (NS::Atom, "subtitle") => feed.description = handle_text(child)?,
-
-            (NS::Atom, "entry") => if_some_then(handle_entry(parser, child)?, |entry| feed.entries.push(entry)),
-
-            // Nothing required for unknown elements
-            _ => {}
+            _ => handle_feed_element(parser, &mut feed, child)?,
         }
     }
 
     if parser.sanitize_content {
-        if let Some(t) = feed.description.as_mut() {
-            t.sanitize()
-        }
-        if let Some(t) = feed.rights.as_mut() {
-            t.sanitize()
-        }
-        if let Some(t) = feed.title.as_mut() {
-            t.sanitize()
-        }
+        util::sanitize_feed(&mut feed);
     }
 
     Ok(feed)
@@ -79,6 +46,46 @@ pub(crate) fn parse_entry<R: BufRead>(parser: &Parser, root: Element<R>) -> Pars
     Ok(feed)
 }
 
+// Handles the children of <feed> other than the entries
+pub(crate) fn handle_feed_element<R: BufRead>(parser: &Parser, feed: &mut Feed, child: Element<R>) -> ParseFeedResult<()> {
+    match child.ns_and_tag() {
+        (NS::Atom, "id") => if_some_then(child.child_as_text(), |id| feed.id = id),
+
+        (NS::Atom, "title") => feed.title = handle_text(child)?,
+
+        (NS::Atom, "updated") => feed.updated = util::handle_timestamp(parser, child),
+
+        (NS::Atom, "author") => if_some_then(handle_person(child)?, |person| feed.authors.push(person)),
+
+        (NS::Atom, "link") => if_some_then(handle_link(child), |link| feed.links.push(link)),
+
+        (NS::Atom, "category") => if_some_then(handle_category(child), |category| feed.categories.push(category)),
+
+        (NS::Atom, "contributor") => if_some_then(handle_person(child)?, |person| feed.contributors.push(person)),
+
+        (NS::Atom, "generator") => feed.generator = handle_generator(child),
+
+        (NS::Atom, "icon") => feed.icon = handle_image(child),
+
+        (NS::Atom, "logo") => feed.logo = handle_image(child),
+
+        (NS::Atom, "rights") => feed.rights = handle_text(child)?,
+
+        (NS::Atom, "subtitle") => feed.description = handle_text(child)?,
+
+        // Media RSS elements apply to all media within the feed
+        (NS::MediaRSS, _) => mediarss::handle_media_channel_element(child, feed)?,
+
+        // Elements in unsupported namespaces are retained as extensions
+        (NS::Unknown, _) => util::handle_extension(child, &mut feed.extensions)?,
+
+        // Nothing required for other elements
+        _ => {}
+    }
+
+    Ok(())
+}
+
 // Handles an Atom <category>
 fn handle_category<R: BufRead>(element: Element<R>) -> Option<Category> {
     // Always need a term
@@ -112,7 +119,7 @@ fn handle_content<R: BufRead>(element: Element<R>) -> ParseFeedResult<Option<Con
         let mime = match &content_type {
             Some(ct) => ct
                 .parse::<MediaTypeBuf>()
-                .map_err(|_| ParseFeedError::ParseError(ParseErrorKind::UnknownMimeType(ct.into())))?,
+                .map_err(|_| ParseFeedError::ParseError(ParseErrorKind::UnknownMimeType(ct.into()), None))?,
             None => {
                 // According to the spec the content type only SHOULD be provided.
                 // Unfortunately `Content` has media type as required. So we treat a missing type as text/html as that is probably the most common. Not to mention that `body` will be `None` so we are just providing a content type for nothing.
@@ -123,7 +130,10 @@ fn handle_content<R: BufRead>(element: Element<R>) -> ParseFeedResult<Option<Con
         if element.child_as_text().is_some() {
             // > If the "src" attribute is present, atom:content MUST be empty.
             // `ParseFeedError` has no appropriate error type, so use `MissingContent` which is what would have been returned before support for `src` was added.
-            return Err(ParseFeedError::ParseError(ParseErrorKind::MissingContent("non-empty atom:content with src")));
+            return Err(ParseFeedError::ParseError(
+                ParseErrorKind::MissingContent("non-empty atom:content with src"),
+                None,
+            ));
         }
 
         let content = Content {
@@ -155,20 +165,22 @@ fn handle_content<R: BufRead>(element: Element<R>) -> ParseFeedResult<Option<Con
                     Some(content)
                 })
                 // The text is required for a text or HTML element
-                .ok_or(ParseFeedError::ParseError(ParseErrorKind::MissingContent("content.text")))
+                .ok_or(ParseFeedError::ParseError(ParseErrorKind::MissingContent("content.text"), None))
         }
 
         // XML per "Otherwise, if the type attribute ends in +xml or /xml, then an xml document of this type is contained inline."
-        Some(ct) if ct.ends_with(" +xml") || ct.ends_with("/xml") => {
-            handle_text(element)?
+        Some(ct) if ct.ends_with("+xml") || ct.ends_with("/xml") => {
+            element
+                .children_as_string()?
                 .map(|body| {
-                    let mut content = Content::default();
-                    content.body = Some(body.content);
-                    content.content_type = MediaTypeBuf::new(names::TEXT, names::XML);
-                    Some(content)
+                    Some(Content {
+                        body: Some(body.trim().into()),
+                        content_type: ct.parse().unwrap_or_else(|_| MediaTypeBuf::new(names::TEXT, names::XML)),
+                        ..Default::default()
+                    })
                 })
                 // The XML is required for an XML content element
-                .ok_or(ParseFeedError::ParseError(ParseErrorKind::MissingContent("content.xml")))
+                .ok_or(ParseFeedError::ParseError(ParseErrorKind::MissingContent("content.xml"), None))
         }
 
         // Escaped text per "Otherwise, if the type attribute starts with text, then an escaped document of this type is contained inline." and
@@ -186,16 +198,16 @@ fn handle_content<R: BufRead>(element: Element<R>) -> ParseFeedResult<Option<Con
                         Some(content)
                     })
                     // The text is required for an inline text or base64 element
-                    .ok_or(ParseFeedError::ParseError(ParseErrorKind::MissingContent("content.inline")))
+                    .ok_or(ParseFeedError::ParseError(ParseErrorKind::MissingContent("content.inline"), None))
             } else {
-                Err(ParseFeedError::ParseError(ParseErrorKind::UnknownMimeType(ct.into())))
+                Err(ParseFeedError::ParseError(ParseErrorKind::UnknownMimeType(ct.into()), None))
             }
         }
     }
 }
 
 // Handles an Atom <entry>
-fn handle_entry<R: BufRead>(parser: &Parser, element: Element<R>) -> ParseFeedResult<Option<Entry>> {
+pub(crate) fn handle_entry<R: BufRead>(parser: &Parser, element: Element<R>) -> ParseFeedResult<Option<Entry>> {
     // Create a default MediaRSS content object for non-grouped elements
     let mut media_obj = MediaObject::default();
 
@@ -209,7 +221,7 @@ fn handle_entry<R: BufRead>(parser: &Parser, element: Element<R>) -> ParseFeedRe
 
             (NS::Atom, "title") => entry.title = handle_text(child)?,
 
-            (NS::Atom, "updated") => if_some_then(child.child_as_text(), |text| entry.updated = parser.parse_timestamp(&text)),
+            (NS::Atom, "updated") => entry.updated = util::handle_timestamp(parser, child),
 
             (NS::Atom, "author") => if_some_then(handle_person(child)?, |person| entry.authors.push(person)),
 
@@ -228,7 +240,7 @@ fn handle_entry<R: BufRead>(parser: &Parser, element: Element<R>) -> ParseFeedRe
             (NS::Atom, "contributor") => if_some_then(handle_person(child)?, |person| entry.contributors.push(person)),
 
             // Some feeds have "pubDate" instead of "published"
-            (NS::Atom, "published") | (NS::Atom, "pubDate") => if_some_then(child.child_as_text(), |text| entry.published = parser.parse_timestamp(&text)),
+            (NS::Atom, "published") | (NS::Atom, "pubDate") => entry.published = util::handle_timestamp(parser, child),
 
             (NS::Atom, "rights") => entry.rights = handle_text(child)?,
 
@@ -238,7 +250,10 @@ fn handle_entry<R: BufRead>(parser: &Parser, element: Element<R>) -> ParseFeedRe
             // MediaRSS tags that are not grouped are parsed into the default object
             (NS::MediaRSS, _) => handle_media_element(child, &mut media_obj)?,
 
-            // Nothing required for unknown elements
+            // Elements in unsupported namespaces are retained as extensions
+            (NS::Unknown, _) => util::handle_extension(child, &mut entry.extensions)?,
+
+            // Nothing required for other elements
             _ => {}
         }
     }
@@ -302,13 +317,13 @@ pub(crate) fn handle_link<R: BufRead>(element: Element<R>) -> Option<Link> {
     element.attr_value("href").map(|href| {
         let mut link = Link::new(href, element.xml_base.as_ref());
 
-        for attr in element.attributes {
+        for attr in &element.attributes {
             match attr.name.as_str() {
                 "rel" => link.rel = Some(attr.value.clone()),
                 "type" => link.media_type = Some(attr.value.clone()),
                 "hreflang" => link.href_lang = Some(attr.value.clone()),
                 "title" => link.title = Some(attr.value.clone()),
-                "length" => link.length = attr.value.parse::<u64>().ok(),
+                "length" => link.length = util::parse_attr(&element, WarningKind::InvalidNumber, attr),
 
                 // Nothing required for unrecognised attributes
                 _ => {}
@@ -325,7 +340,7 @@ pub(crate) fn handle_link<R: BufRead>(element: Element<R>) -> Option<Link> {
 }
 
 // Handles an Atom <author> or <contributor>
-fn handle_person<R: BufRead>(element: Element<R>) -> ParseFeedResult<Option<Person>> {
+pub(crate) fn handle_person<R: BufRead>(element: Element<R>) -> ParseFeedResult<Option<Person>> {
     let mut person = Person::new("unknown");
 
     for child in element.children() {
@@ -356,7 +371,7 @@ pub(crate) fn handle_text<R: BufRead>(element: Element<R>) -> ParseFeedResult<Op
         "html" | "xhtml" | "text/html" => Ok(MediaTypeBuf::new(names::TEXT, names::HTML)),
 
         // Unknown content type
-        _ => Err(ParseFeedError::ParseError(ParseErrorKind::UnknownMimeType(type_attr.into()))),
+        _ => Err(ParseFeedError::ParseError(ParseErrorKind::UnknownMimeType(type_attr.into()), None)),
     }?;
 
     element
@@ -367,5 +382,5 @@ pub(crate) fn handle_text<R: BufRead>(element: Element<R>) -> ParseFeedResult<Op
             Some(text)
         })
         // Need the text for a text element
-        .ok_or(ParseFeedError::ParseError(ParseErrorKind::MissingContent("text")))
+        .ok_or(ParseFeedError::ParseError(ParseErrorKind::MissingContent("text"), None))
 }
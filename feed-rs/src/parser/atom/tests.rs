@@ -1,5 +1,6 @@
 use crate::model::{
-    Category, Content, Entry, Feed, FeedType, Generator, Image, Link, MediaCommunity, MediaContent, MediaObject, MediaText, MediaThumbnail, Person, Text,
+    Category, Content, Entry, Extension, Feed, FeedType, Generator, Image, Link, MediaCommunity, MediaContent, MediaObject, MediaText, MediaThumbnail, Person,
+    Text,
 };
 use crate::parser;
 use crate::util::test;
@@ -125,6 +126,8 @@ fn test_example_3() {
             .media_type("application/atom+xml"))
         .link(Link::new("http://pubsubhubbub.appspot.com/", None)
             .rel("hub"))
+        .extension("http://rssnamespace.org/feedburner/ext/1.0", Extension::new("info")
+            .attr("uri", "theakamaiblog"))
         .entry(Entry::default()
             .title(Text::new("Time to Transfer Risk: Why Security Complexity & VPNs Are No Longer Sustainable".into()))
             .language("en-us")
@@ -148,7 +151,9 @@ fn test_example_3() {
             .content(Content::default()
                 .body(r#"<p>We all heed the gospel of patching, but as recent incidents made clear, even cutting-edge disruptors struggle to patch everything, everywhere, and all the time.</p>
         <img src="http://feeds.feedburner.com/~r/TheAkamaiBlog/~4/NnQEuqRSyug" height="1" width="1" alt=""/>"#)
-                .content_type("text/html")));
+                .content_type("text/html"))
+            .extension("http://rssnamespace.org/feedburner/ext/1.0", Extension::new("origLink")
+                .value("https://blogs.akamai.com/2019/07/time-to-transfer-risk-why-security-complexity-vpns-are-no-longer-sustainable.html")));
 
     // Check
     assert_eq!(actual, expected);
@@ -219,7 +224,11 @@ fn test_example_5() {
             .category(Category::new("nc")
                 .label("Contributor"))
             .category(Category::new("nc")
-                .label("Author")));
+                .label("Author"))
+            .extension("http://www.georss.org/georss", Extension::new("point")
+                .value("40.3465004 -124.4599991"))
+            .extension("http://www.georss.org/georss", Extension::new("elev")
+                .value("-29350")));
 
     // Check
     assert_eq!(actual, expected);
@@ -481,7 +490,13 @@ fn test_entry() {
             )
             .summary(Text::new(
                 "This Atom Entry XML Doc publishes tech specifications of Nikon D300S Digital Camera".into(),
-            )),
+            ))
+            .extension(
+                "http://www.svnit.ac.in/coed/mtech/research/2009/khuba/",
+                Extension::new("Semantics")
+                    .value("http://www.daman.nic.in/khuba/ontology/camera.owl")
+                    .attr("available", "OfflineAtURL"),
+            ),
     );
 
     // Check
@@ -607,3 +622,32 @@ fn test_atom_content_xml_base() {
     let actual = parser::parse(test_data.as_bytes()).unwrap();
     assert!(actual.entries[0].base.as_ref().unwrap().eq("https://numi.st/post/2022/travel-uke/"));
 }
+
+// Verify inline XML content is retained as markup, with the declared type
+#[test]
+fn test_atom_content_xml() {
+    let test_data = r#"<feed xmlns="http://www.w3.org/2005/Atom">
+        <id>urn:feed:1</id>
+        <entry>
+            <id>urn:entry:1</id>
+            <content type="image/svg+xml">
+                <svg xmlns="http://www.w3.org/2000/svg"><circle r="1"/></svg>
+            </content>
+        </entry>
+        <entry>
+            <id>urn:entry:2</id>
+            <content type="application/xml"><note><to>Jane</to></note></content>
+        </entry>
+    </feed>"#;
+    let actual = parser::parse(test_data.as_bytes()).unwrap();
+
+    let content = actual.entries[0].content.as_ref().unwrap();
+    assert_eq!(content.content_type.as_str(), "image/svg+xml");
+    assert_eq!(
+        content.body.as_deref(),
+        Some(r#"<svg xmlns="http://www.w3.org/2000/svg"><circle r="1"></circle></svg>"#)
+    );
+    let content = actual.entries[1].content.as_ref().unwrap();
+    assert_eq!(content.content_type.as_str(), "application/xml");
+    assert_eq!(content.body.as_deref(), Some("<note><to>Jane</to></note>"));
+}
@@ -1,10 +1,14 @@
 use std::io::BufRead;
 use std::time::Duration;
 
-use crate::model::{Category, Feed, Image, MediaCredit, MediaObject, MediaRating, MediaThumbnail, Person};
+use url::Url;
+
+use crate::model::{
+    Category, Entry, Feed, Image, ItunesEpisodeType, ItunesMeta, ItunesPodcastType, MediaCredit, MediaObject, MediaRating, MediaThumbnail, Person,
+};
 use crate::parser::atom;
-use crate::parser::util::{if_some_then, parse_npt};
-use crate::parser::ParseFeedResult;
+use crate::parser::util::{self, if_some_then, parse_npt};
+use crate::parser::{ParseFeedResult, WarningKind};
 use crate::xml::{Element, NS};
 
 // Process <itunes> elements at channel level updating the Feed object as required
@@ -27,7 +31,24 @@ pub(crate) fn handle_itunes_channel_element<R: BufRead>(element: Element<R>, fee
         }),
 
         (NS::Itunes, "author") => if_some_then(element.child_as_text(), |person| feed.authors.push(Person::new(&person))),
-        (NS::Itunes, "owner") => if_some_then(handle_owner(element)?, |owner| feed.contributors.push(owner)),
+        (NS::Itunes, "owner") => if_some_then(handle_owner(element)?, |owner| {
+            itunes_meta(&mut feed.itunes).owner = Some(owner.clone());
+            feed.contributors.push(owner);
+        }),
+
+        (NS::Itunes, "type") => if_some_then(handle_type(element), |podcast_type| {
+            itunes_meta(&mut feed.itunes).podcast_type = Some(podcast_type)
+        }),
+
+        (NS::Itunes, "new-feed-url") => if_some_then(handle_url(element), |url| itunes_meta(&mut feed.itunes).new_feed_url = Some(url)),
+
+        (NS::Itunes, "complete") => if_some_then(handle_yes_no(element), |complete| itunes_meta(&mut feed.itunes).complete = Some(complete)),
+
+        (NS::Itunes, "block") => if_some_then(handle_yes_no(element), |block| itunes_meta(&mut feed.itunes).block = Some(block)),
+
+        (NS::Itunes, "keywords") => if_some_then(handle_keywords(element), |keywords| itunes_meta(&mut feed.itunes).keywords = keywords),
+
+        (NS::Itunes, "subtitle") => if_some_then(element.child_as_text(), |subtitle| itunes_meta(&mut feed.itunes).subtitle = Some(subtitle)),
 
         // Nothing required for unknown elements
         _ => {}
@@ -37,7 +58,9 @@ pub(crate) fn handle_itunes_channel_element<R: BufRead>(element: Element<R>, fee
 }
 
 // Process <itunes> elements at item level and turn them into something that looks like MediaRSS objects.
-pub(crate) fn handle_itunes_item_element<R: BufRead>(element: Element<R>, media_obj: &mut MediaObject) -> ParseFeedResult<()> {
+//
+// Tags that do not describe the media are added to the entry
+pub(crate) fn handle_itunes_item_element<R: BufRead>(element: Element<R>, entry: &mut Entry, media_obj: &mut MediaObject) -> ParseFeedResult<()> {
     match element.ns_and_tag() {
         (NS::Itunes, "title") => media_obj.title = atom::handle_text(element)?,
 
@@ -49,6 +72,24 @@ pub(crate) fn handle_itunes_item_element<R: BufRead>(element: Element<R>, media_
 
         (NS::Itunes, "summary") => media_obj.description = atom::handle_text(element)?,
 
+        (NS::Itunes, "episode") => if_some_then(handle_number(element), |episode| itunes_meta(&mut entry.itunes).episode = Some(episode)),
+
+        (NS::Itunes, "season") => if_some_then(handle_number(element), |season| itunes_meta(&mut entry.itunes).season = Some(season)),
+
+        (NS::Itunes, "episodeType") => if_some_then(handle_episode_type(element), |episode_type| {
+            itunes_meta(&mut entry.itunes).episode_type = Some(episode_type)
+        }),
+
+        (NS::Itunes, "explicit") => if_some_then(handle_explicit_flag(element), |explicit| {
+            itunes_meta(&mut entry.itunes).explicit = Some(explicit)
+        }),
+
+        (NS::Itunes, "block") => if_some_then(handle_yes_no(element), |block| itunes_meta(&mut entry.itunes).block = Some(block)),
+
+        (NS::Itunes, "keywords") => if_some_then(handle_keywords(element), |keywords| itunes_meta(&mut entry.itunes).keywords = keywords),
+
+        (NS::Itunes, "subtitle") => if_some_then(element.child_as_text(), |subtitle| itunes_meta(&mut entry.itunes).subtitle = Some(subtitle)),
+
         // Nothing required for unknown elements
         _ => {}
     }
@@ -88,6 +129,16 @@ fn handle_duration<R: BufRead>(element: Element<R>) -> Option<Duration> {
     element.child_as_text().and_then(|text| parse_npt(&text))
 }
 
+// Handles <itunes:episodeType>
+fn handle_episode_type<R: BufRead>(element: Element<R>) -> Option<ItunesEpisodeType> {
+    element.child_as_text().and_then(|text| match text.trim().to_lowercase().as_str() {
+        "full" => Some(ItunesEpisodeType::Full),
+        "trailer" => Some(ItunesEpisodeType::Trailer),
+        "bonus" => Some(ItunesEpisodeType::Bonus),
+        _ => None,
+    })
+}
+
 // Handles <itunes:explicit> by mapping to {true|false} and wrapping in MediaRating instance
 fn handle_explicit<R: BufRead>(element: Element<R>) -> Option<MediaRating> {
     element
@@ -96,11 +147,38 @@ fn handle_explicit<R: BufRead>(element: Element<R>) -> Option<MediaRating> {
         .map(|v| MediaRating::new(v).urn("itunes"))
 }
 
+// Handles <itunes:explicit> at the item level, accepting the historical values ("yes", "clean" etc) as well as {true|false}
+fn handle_explicit_flag<R: BufRead>(element: Element<R>) -> Option<bool> {
+    element.child_as_text().and_then(|text| match text.trim().to_lowercase().as_str() {
+        "true" | "yes" | "explicit" => Some(true),
+        "false" | "no" | "clean" => Some(false),
+        _ => None,
+    })
+}
+
 // Handles <itunes:image>
 fn handle_image<R: BufRead>(element: Element<R>) -> Option<MediaThumbnail> {
     element.attr_value("href").map(|url| MediaThumbnail::new(Image::new(url)))
 }
 
+// Handles <itunes:keywords>, which is a comma separated list
+fn handle_keywords<R: BufRead>(element: Element<R>) -> Option<Vec<String>> {
+    element.child_as_text().map(|text| {
+        text.split(',')
+            .map(str::trim)
+            .filter(|keyword| !keyword.is_empty())
+            .map(str::to_string)
+            .collect()
+    })
+}
+
+// Handles <itunes:episode> and <itunes:season>
+fn handle_number<R: BufRead>(element: Element<R>) -> Option<u32> {
+    element
+        .child_as_text()
+        .and_then(|text| util::parse_text(&element, WarningKind::InvalidNumber, text.trim()))
+}
+
 // Handles <itunes:owner>
 fn handle_owner<R: BufRead>(element: Element<R>) -> ParseFeedResult<Option<Person>> {
     let mut email = None;
@@ -123,3 +201,27 @@ fn handle_owner<R: BufRead>(element: Element<R>) -> ParseFeedResult<Option<Perso
         None
     })
 }
+
+// Handles <itunes:type>
+fn handle_type<R: BufRead>(element: Element<R>) -> Option<ItunesPodcastType> {
+    element.child_as_text().and_then(|text| match text.trim().to_lowercase().as_str() {
+        "episodic" => Some(ItunesPodcastType::Episodic),
+        "serial" => Some(ItunesPodcastType::Serial),
+        _ => None,
+    })
+}
+
+// Handles <itunes:new-feed-url>
+fn handle_url<R: BufRead>(element: Element<R>) -> Option<Url> {
+    element.child_as_text().and_then(|text| util::parse_uri(text.trim(), element.xml_base.as_ref()))
+}
+
+// Handles <itunes:block> and <itunes:complete>, where only "Yes" has an effect
+fn handle_yes_no<R: BufRead>(element: Element<R>) -> Option<bool> {
+    element.child_as_text().map(|text| text.trim().eq_ignore_ascii_case("yes"))
+}
+
+// iTunes fields of the feed or entry, created on first use
+fn itunes_meta(meta: &mut Option<ItunesMeta>) -> &mut ItunesMeta {
+    meta.get_or_insert_with(Default::default)
+}
@@ -1,40 +1,115 @@
+use std::cell::{Cell, RefCell};
+use std::collections::BTreeMap;
+use std::fmt;
 use std::io::Read;
+use std::mem;
+use std::time::Duration;
 
+use chrono::{DateTime, Utc};
 use mediatype::{names, MediaTypeBuf};
-
-use crate::model::{Category, Content, Entry, Feed, FeedType, Image, Link, Person, Text};
+use serde::de::value::MapAccessDeserializer;
+use serde::de::{self, DeserializeSeed, MapAccess, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer};
+use serde_json::{Map, Value};
+
+use crate::model::{Category, Content, Entry, Feed, FeedType, Image, Link, MediaContent, MediaObject, MediaThumbnail, Person, Text};
+use crate::parser::limits::{Limit, Limits};
+use crate::parser::util;
 use crate::parser::util::if_some_then;
-use crate::parser::{ParseFeedError, ParseFeedResult, Parser};
+use crate::parser::{ParseErrorKind, ParseFeedError, ParseFeedResult, ParseWarning, Parser, WarningKind};
+
+pub(crate) mod stream;
 
 #[cfg(test)]
 mod tests;
 
+/// State of parsing a single JSON Feed, held apart from the parser so the parser may be shared by several feeds at once
+pub(crate) struct Context<'a> {
+    parser: &'a Parser,
+    // Content that could not be interpreted (if requested)
+    warnings: Option<RefCell<Vec<ParseWarning>>>,
+}
+
+impl<'a> Context<'a> {
+    /// Creates the context for a feed, collecting warnings if requested
+    pub(crate) fn new(parser: &'a Parser, report: bool) -> Context<'a> {
+        Context {
+            parser,
+            warnings: report.then(RefCell::default),
+        }
+    }
+
+    /// Returns the warnings collected so far
+    pub(crate) fn take_warnings(&self) -> Vec<ParseWarning> {
+        self.warnings.as_ref().map(RefCell::take).unwrap_or_default()
+    }
+
+    // Records a warning if requested by the caller
+    fn warn(&self, warning: ParseWarning) {
+        if let Some(warnings) = &self.warnings {
+            warnings.borrow_mut().push(warning);
+        }
+    }
+}
+
 /// Parses a JSON feed into our model
-pub(crate) fn parse<R: Read>(parser: &Parser, stream: R) -> ParseFeedResult<Feed> {
-    let parsed = serde_json::from_reader(stream);
-    if let Ok(json_feed) = parsed {
-        convert(parser, json_feed)
-    } else {
+pub(crate) fn parse<R: Read>(context: &Context, stream: R) -> ParseFeedResult<Feed> {
+    let exceeded = Cell::new(false);
+    let document = Document { context, exceeded: &exceeded };
+
+    let mut deserializer = serde_json::Deserializer::from_reader(stream);
+    match document.deserialize(&mut deserializer).and_then(|jf| deserializer.end().map(|_| jf)) {
+        Ok(json_feed) => convert(context, json_feed),
+
+        // Reading stopped at the first item beyond the limit
+        Err(_) if exceeded.get() => Err(ParseFeedError::ParseError(ParseErrorKind::LimitExceeded(Limit::Entries), None)),
+
         // Unable to parse the JSON
-        Err(ParseFeedError::JsonSerde(parsed.err().unwrap()))
+        Err(e) => Err(ParseFeedError::JsonSerde(e)),
     }
 }
 
 // Convert the JSON Feed into our standard model
-fn convert(parser: &Parser, jf: JsonFeed) -> ParseFeedResult<Feed> {
+fn convert(context: &Context, mut jf: JsonFeed) -> ParseFeedResult<Feed> {
+    let items = mem::take(&mut jf.items);
+    let mut feed = convert_header(Some(context), jf)?;
+
+    // Convert items within the JSON feed
+    for (index, value) in items.into_iter().enumerate() {
+        if let Some(ji) = parse_item(context, index, value)? {
+            feed.entries.push(convert_item(context, &feed.authors, index, ji));
+        }
+    }
+
+    Ok(feed)
+}
+
+// Convert the feed level fields (everything other than the items), recording values that were coerced with the context if supplied
+// (the streaming parser converts the fields more than once, as they may follow the items)
+pub(crate) fn convert_header(context: Option<&Context>, jf: JsonFeed) -> ParseFeedResult<Feed> {
     let mut feed = Feed::new(FeedType::JSON);
+    let record = |member: &str, value: &str| {
+        if let Some(context) = context {
+            coerced(context, format!("/{}", member), value)
+        }
+    };
 
-    // If the version exists, it should be something we support
-    if !jf.version.starts_with("https://jsonfeed.org/version/1") {
-        return ParseFeedResult::Err(ParseFeedError::JsonUnsupportedVersion(jf.version));
+    // The version should be something we support, though we also accept variants of version 1 and the version being absent
+    match jf.version {
+        Some(version) if version.starts_with("https://jsonfeed.org/version/1") => {}
+        Some(version) if is_version_1(&version) => record("version", &version),
+        Some(version) => return ParseFeedResult::Err(ParseFeedError::JsonUnsupportedVersion(version)),
+        None => record("version", ""),
     }
 
-    // Convert feed level fields
-    feed.title = Some(Text::new(jf.title));
+    match jf.title {
+        Some(title) => feed.title = Some(Text::new(title)),
+        None => record("title", ""),
+    }
 
     if_some_then(jf.home_page_url, |uri| feed.links.push(Link::new(uri, None)));
 
-    if_some_then(jf.feed_url, |uri| feed.links.push(Link::new(uri, None)));
+    if_some_then(jf.feed_url, |uri| feed.links.push(rel_link(uri, "self")));
 
     if_some_then(jf.description, |text| feed.description = Some(Text::new(text)));
 
@@ -44,23 +119,75 @@ fn convert(parser: &Parser, jf: JsonFeed) -> ParseFeedResult<Feed> {
 
     if_some_then(jf.favicon, |uri| feed.icon = Some(Image::new(uri)));
 
-    handle_authors(&mut feed.authors, &jf.author, &jf.authors);
+    if_some_then(jf.next_url, |uri| feed.links.push(rel_link(uri, "next")));
 
-    // Convert items within the JSON feed
-    jf.items.into_iter().for_each(|ji| {
-        feed.entries.push(handle_item(parser, ji));
+    // The type of hub (e.g. "WebSub") is retained as the title of the link, with hubs lacking a URL dropped
+    if_some_then(jf.hubs, |hubs| {
+        for (index, hub) in hubs.into_iter().enumerate() {
+            if hub.hub_type.is_none() {
+                record(&format!("hubs/{}/type", index), "");
+            }
+            match hub.url {
+                Some(url) => {
+                    let mut link = rel_link(url, "hub");
+                    link.title = hub.hub_type;
+                    feed.links.push(link);
+                }
+                None => record(&format!("hubs/{}/url", index), ""),
+            }
+        }
     });
 
-    // Per the spec, any items without an author inherit the feed
-    if !feed.authors.is_empty() {
-        feed.entries.iter_mut().for_each(|entry| {
-            if entry.authors.is_empty() {
-                entry.authors.append(&mut feed.authors.clone());
+    feed.expired = jf.expired.unwrap_or(false);
+
+    handle_authors(&mut feed.authors, &jf.author, &jf.authors);
+
+    feed.json_extensions = jf.extensions;
+
+    Ok(feed)
+}
+
+// Deserialises the item at the given index within the items
+// Values that are not objects are skipped, as are objects that are not valid items when parsing leniently (reporting the error to the registered handler)
+pub(crate) fn parse_item(context: &Context, index: usize, value: Value) -> ParseFeedResult<Option<JsonItem>> {
+    let parser = context.parser;
+    let skipped = |value: String| {
+        context.warn(ParseWarning {
+            kind: WarningKind::SkippedEntry,
+            path: format!("/items/{}", index),
+            value,
+        })
+    };
+
+    if !value.is_object() {
+        skipped(value.to_string());
+        return Ok(None);
+    }
+
+    match serde_json::from_value(value) {
+        Ok(ji) => Ok(Some(ji)),
+        Err(e) if parser.lenient => {
+            let e = ParseFeedError::JsonSerde(e);
+            skipped(e.to_string());
+            if let Some(entry_error_handler) = &parser.entry_error_handler {
+                entry_error_handler(&e);
             }
-        });
+            Ok(None)
+        }
+        Err(e) => Err(ParseFeedError::JsonSerde(e)),
     }
+}
 
-    Ok(feed)
+// Convert an item (at the given index within the items), which inherits the authors of the feed if it has none
+pub(crate) fn convert_item(context: &Context, feed_authors: &[Person], index: usize, ji: JsonItem) -> Entry {
+    let mut entry = handle_item(context, index, ji);
+
+    // Per the spec, any items without an author inherit the feed
+    if entry.authors.is_empty() {
+        entry.authors = feed_authors.to_vec();
+    }
+
+    entry
 }
 
 fn accumulate_author(authors: &mut Vec<Person>, ja: &JsonAuthor) {
@@ -75,15 +202,28 @@ fn accumulate_author(authors: &mut Vec<Person>, ja: &JsonAuthor) {
     }
 }
 
-// Handles an attachment
-fn handle_attachment(attachment: JsonAttachment) -> Link {
-    let mut link = Link::new(&attachment.url, None);
-
-    link.media_type = Some(attachment.mime_type);
-    link.title = attachment.title;
+// Handles an attachment, which is both a link and a media object (if the URL is absolute)
+fn handle_attachment(attachment: JsonAttachment, entry: &mut Entry) {
+    let Some(url) = attachment.url else { return };
+    let mut link = Link::new(&url, None);
+    link.media_type.clone_from(&attachment.mime_type);
+    link.title.clone_from(&attachment.title);
     link.length = attachment.size_in_bytes;
-
-    link
+    entry.links.push(link);
+
+    let mut content = MediaContent::new();
+    content.url = util::parse_uri(&url, None);
+    content.content_type = attachment.mime_type.and_then(|mime_type| mime_type.parse().ok());
+    content.size = attachment.size_in_bytes;
+    content.duration = attachment.duration_in_seconds.and_then(|seconds| Duration::try_from_secs_f64(seconds).ok());
+    if content.url.is_some() {
+        entry.media.push(MediaObject {
+            title: attachment.title.map(Text::new),
+            duration: content.duration,
+            content: vec![content],
+            ..Default::default()
+        });
+    }
 }
 
 // Converts author / authors objects into our model
@@ -109,12 +249,63 @@ fn handle_content(content: Option<String>, content_type: MediaTypeBuf) -> Option
     })
 }
 
+// Records a warning for a value that was missing or of an unexpected type, and was converted
+fn coerced(context: &Context, path: String, value: &str) {
+    context.warn(ParseWarning {
+        kind: WarningKind::CoercedValue,
+        path,
+        value: value.to_string(),
+    });
+}
+
+// Converts a scalar value to a string (None for null, arrays and objects)
+fn scalar_string(value: Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s),
+        Value::Null | Value::Array(_) | Value::Object(_) => None,
+        value => Some(value.to_string()),
+    }
+}
+
+// Checks if the version is a variant of version 1 (e.g. "1.0" or "http://jsonfeed.org/version/1")
+fn is_version_1(version: &str) -> bool {
+    let version = version.trim().trim_start_matches("https://").trim_start_matches("http://");
+    let version = version.strip_prefix("jsonfeed.org/version/").unwrap_or(version);
+    version == "1" || version.starts_with("1.")
+}
+
+// Creates a link with the given relation
+fn rel_link(uri: String, rel: &str) -> Link {
+    let mut link = Link::new(uri, None);
+    link.rel = Some(rel.into());
+    link
+}
+
+// Parses a timestamp, recording a warning for the member of the item if it is invalid
+fn handle_timestamp(context: &Context, index: usize, member: &str, text: &str) -> Option<DateTime<Utc>> {
+    let timestamp = context.parser.parse_timestamp(text);
+    if timestamp.is_none() {
+        context.warn(ParseWarning {
+            kind: WarningKind::InvalidTimestamp,
+            path: format!("/items/{}/{}", index, member),
+            value: text.to_string(),
+        });
+    }
+    timestamp
+}
+
 // Converts a JSON feed item into our model
-fn handle_item(parser: &Parser, ji: JsonItem) -> Entry {
-    let mut entry = Entry {
-        id: ji.id.unwrap_or("".into()),
-        ..Default::default()
+fn handle_item(context: &Context, index: usize, ji: JsonItem) -> Entry {
+    // Identifiers should be strings, but numbers (and other values) are converted
+    let id = match ji.id {
+        Some(Value::String(id)) => id,
+        Some(Value::Null) | None => "".into(),
+        Some(value) => {
+            coerced(context, format!("/items/{}/id", index), &value.to_string());
+            scalar_string(value).unwrap_or_default()
+        }
     };
+    let mut entry = Entry { id, ..Default::default() };
 
     if_some_then(ji.url, |uri| entry.links.push(Link::new(uri, None)));
 
@@ -122,6 +313,8 @@ fn handle_item(parser: &Parser, ji: JsonItem) -> Entry {
 
     if_some_then(ji.title, |text| entry.title = Some(Text::new(text)));
 
+    if_some_then(ji.language, |language| entry.language = Some(language));
+
     // Content HTML, content text and summary are mapped across to our model with the preference toward HTML and explicit summary fields
     entry.content = handle_content(ji.content_html, MediaTypeBuf::new(names::TEXT, names::HTML));
     entry.summary = ji.summary.map(Text::new);
@@ -136,24 +329,64 @@ fn handle_item(parser: &Parser, ji: JsonItem) -> Entry {
         }
     }
 
-    if_some_then(ji.date_published, |published| entry.published = parser.parse_timestamp(&published));
+    if_some_then(ji.date_published, |published| {
+        entry.published = handle_timestamp(context, index, "date_published", &published)
+    });
 
-    if_some_then(ji.date_modified, |modified| entry.updated = parser.parse_timestamp(&modified));
+    if_some_then(ji.date_modified, |modified| {
+        entry.updated = handle_timestamp(context, index, "date_modified", &modified)
+    });
 
     handle_authors(&mut entry.authors, &ji.author, &ji.authors);
 
+    // Tags should be a list of strings, but other scalar values are converted (and a single tag accepted in place of the list)
     if_some_then(ji.tags, |tags| {
-        tags.into_iter().map(|t| Category::new(&t)).for_each(|category| entry.categories.push(category))
+        let path = format!("/items/{}/tags", index);
+        let tags: Vec<String> = match tags {
+            Value::Array(tags) => tags
+                .into_iter()
+                .enumerate()
+                .filter_map(|(i, tag)| {
+                    if !tag.is_string() {
+                        coerced(context, format!("{}/{}", path, i), &tag.to_string());
+                    }
+                    scalar_string(tag)
+                })
+                .collect(),
+            tag => {
+                coerced(context, path, &tag.to_string());
+                scalar_string(tag).into_iter().collect()
+            }
+        };
+        tags.iter().map(|t| Category::new(t)).for_each(|category| entry.categories.push(category))
     });
 
+    // Attachments without a URL are dropped
     if_some_then(ji.attachments, |attachments| {
-        attachments.into_iter().map(handle_attachment).for_each(|link| entry.links.push(link))
+        for (i, attachment) in attachments.into_iter().enumerate() {
+            if attachment.url.is_none() {
+                coerced(context, format!("/items/{}/attachments/{}/url", index, i), "");
+            }
+            handle_attachment(attachment, &mut entry);
+        }
     });
 
+    // The main image is represented as a thumbnail, with the banner image being a link as there is no equivalent in our model
+    if_some_then(ji.image, |uri| {
+        entry.media.push(MediaObject {
+            thumbnails: vec![MediaThumbnail::new(Image::new(uri))],
+            ..Default::default()
+        })
+    });
+
+    if_some_then(ji.banner_image, |uri| entry.links.push(rel_link(uri, "banner")));
+
+    entry.json_extensions = ji.extensions;
+
     // Per the JSON Feed spec, "the only place HTML is allowed in this format is in content_html";
     // as such when sanitizing, we will *only* inspect entry.content.
     // it's "text/html".
-    if parser.sanitize_content {
+    if context.parser.sanitize_content {
         if let Some(c) = entry.content.as_mut() {
             c.sanitize()
         }
@@ -162,48 +395,222 @@ fn handle_item(parser: &Parser, ji: JsonItem) -> Entry {
     entry
 }
 
-#[derive(Debug, Deserialize)]
-struct JsonFeed {
-    pub version: String,
-    pub title: String,
+// Deserialises the top-level object of a JSON Feed, failing as soon as the items exceed the maximum number of entries
+// (rather than after the whole document has been read into memory)
+struct Document<'a> {
+    context: &'a Context<'a>,
+    exceeded: &'a Cell<bool>,
+}
+
+impl<'de> DeserializeSeed<'de> for Document<'_> {
+    type Value = JsonFeed;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de> Visitor<'de> for Document<'_> {
+    type Value = JsonFeed;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a JSON Feed object")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        // The feed level fields are collected, with the items read individually
+        let mut fields = Map::new();
+        let mut items = Vec::new();
+        while let Some(key) = map.next_key::<String>()? {
+            if key == "items" {
+                items = map.next_value_seed(Items {
+                    context: self.context,
+                    exceeded: self.exceeded,
+                })?;
+            } else {
+                fields.insert(key, map.next_value()?);
+            }
+        }
+
+        let mut jf: JsonFeed = serde_json::from_value(Value::Object(fields)).map_err(de::Error::custom)?;
+        jf.items = items;
+        Ok(jf)
+    }
+}
+
+// Deserialises the items array, stopping at the first item beyond the maximum number of entries
+// Any other value (e.g. null) is treated as an empty array, recording the value that was coerced
+struct Items<'a> {
+    context: &'a Context<'a>,
+    exceeded: &'a Cell<bool>,
+}
+
+impl Items<'_> {
+    fn coerce<E>(self, value: Value) -> Result<Vec<Value>, E> {
+        coerced(self.context, "/items".into(), &value.to_string());
+        Ok(Vec::new())
+    }
+}
+
+impl<'de> DeserializeSeed<'de> for Items<'_> {
+    type Value = Vec<Value>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_any(self)
+    }
+}
+
+impl<'de> Visitor<'de> for Items<'_> {
+    type Value = Vec<Value>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("an array of items")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut items = Vec::new();
+        while let Some(item) = seq.next_element()? {
+            if Limits::check(self.context.parser.limits.max_entries, items.len() + 1, Limit::Entries).is_err() {
+                self.exceeded.set(true);
+                return Err(de::Error::custom(Limit::Entries));
+            }
+            items.push(item);
+        }
+        Ok(items)
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E> {
+        self.coerce(Value::Null)
+    }
+
+    fn visit_bool<E>(self, v: bool) -> Result<Self::Value, E> {
+        self.coerce(v.into())
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E> {
+        self.coerce(v.into())
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E> {
+        self.coerce(v.into())
+    }
+
+    fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E> {
+        self.coerce(v.into())
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> {
+        self.coerce(v.into())
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+        let value = Value::deserialize(MapAccessDeserializer::new(map))?;
+        self.coerce(value)
+    }
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub(crate) struct JsonFeed {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub home_page_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub feed_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub language: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub icon: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub favicon: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub author: Option<JsonAuthor>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub authors: Option<Vec<JsonAuthor>>,
-    pub items: Vec<JsonItem>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expired: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hubs: Option<Vec<JsonHub>>,
+    // Deserialised individually, so items that are not objects can be skipped
+    #[serde(default)]
+    pub items: Vec<Value>,
+    // Extensions (e.g. "_microblog") and any other members we do not otherwise handle
+    #[serde(flatten)]
+    pub extensions: BTreeMap<String, Value>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub(crate) struct JsonAttachment {
+    // Required, but attachments without a URL are tolerated (and dropped)
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mime_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size_in_bytes: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration_in_seconds: Option<f64>,
 }
 
-#[derive(Debug, Deserialize)]
-struct JsonAttachment {
-    url: String,
-    mime_type: String,
-    title: Option<String>,
-    size_in_bytes: Option<u64>,
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub(crate) struct JsonAuthor {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
 }
 
-#[derive(Debug, Deserialize)]
-struct JsonAuthor {
-    name: Option<String>,
-    url: Option<String>,
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub(crate) struct JsonHub {
+    // Both required, but tolerated when missing
+    #[serde(rename = "type")]
+    pub hub_type: Option<String>,
+    pub url: Option<String>,
 }
 
-#[derive(Debug, Deserialize)]
-struct JsonItem {
-    pub id: Option<String>,
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub(crate) struct JsonItem {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub external_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub content_html: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub content_text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub summary: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub banner_image: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub date_published: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub date_modified: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub author: Option<JsonAuthor>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub authors: Option<Vec<JsonAuthor>>,
-    pub tags: Option<Vec<String>>,
+    // A list of strings, though other values are converted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub attachments: Option<Vec<JsonAttachment>>,
+    // Extensions (e.g. "_podcast") and any other members we do not otherwise handle
+    #[serde(flatten)]
+    pub extensions: BTreeMap<String, Value>,
 }
@@ -0,0 +1,193 @@
+use std::io::BufRead;
+
+use serde::de::Error;
+use serde_json::{Map, Value};
+
+use crate::parser::json::JsonFeed;
+use crate::parser::{ParseFeedError, ParseFeedResult};
+
+/// Incrementally reads a JSON Feed document, deserialising the items one at a time
+///
+/// The top-level object is scanned by hand so that only a single item (or feed level field) is held in memory at once,
+/// with serde used to deserialise each of these values.
+pub(crate) struct JsonStream<R: BufRead> {
+    reader: R,
+    // Feed level fields seen so far
+    fields: Map<String, Value>,
+    state: State,
+    // Number of items read so far
+    items: usize,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum State {
+    // Reading the fields of the feed (before or after the items)
+    Fields,
+    // Reading the members of the "items" array
+    Items,
+    // Reached the end of the top-level object
+    Done,
+}
+
+impl<R: BufRead> JsonStream<R> {
+    /// Reads the feed level fields up to the start of the items
+    pub(crate) fn new(reader: R) -> ParseFeedResult<JsonStream<R>> {
+        let mut stream = JsonStream {
+            reader,
+            fields: Map::new(),
+            state: State::Fields,
+            items: 0,
+        };
+
+        // Skip anything before the start of the object (e.g. a BOM)
+        while let Some(b) = stream.peek()? {
+            stream.bump();
+            if b == b'{' {
+                stream.read_fields()?;
+                return Ok(stream);
+            }
+        }
+
+        Err(syntax_error("expected a JSON object"))
+    }
+
+    /// Deserialises the feed level fields seen so far
+    pub(crate) fn header(&self) -> ParseFeedResult<JsonFeed> {
+        let mut object = self.fields.clone();
+        object.insert("items".into(), Value::Array(Vec::new()));
+
+        Ok(serde_json::from_value(Value::Object(object))?)
+    }
+
+    /// Returns the next item with its index, or None when the items (and any trailing feed level fields) have been consumed
+    pub(crate) fn next_item(&mut self) -> ParseFeedResult<Option<(usize, Value)>> {
+        if self.state != State::Items {
+            return Ok(None);
+        }
+
+        self.skip_whitespace()?;
+        if self.peek()? == Some(b',') {
+            self.bump();
+            self.skip_whitespace()?;
+        }
+
+        if self.peek()? == Some(b']') {
+            // Read any fields following the items
+            self.bump();
+            self.state = State::Fields;
+            self.read_fields()?;
+            return Ok(None);
+        }
+
+        let raw = self.read_value()?;
+        let item = serde_json::from_slice(&raw)?;
+        self.items += 1;
+        Ok(Some((self.items - 1, item)))
+    }
+
+    // Reads the fields of the top-level object until the start of the items array, or the end of the object
+    fn read_fields(&mut self) -> ParseFeedResult<()> {
+        loop {
+            self.skip_whitespace()?;
+            match self.peek()? {
+                Some(b',') => self.bump(),
+
+                Some(b'}') => {
+                    self.bump();
+                    self.state = State::Done;
+                    return Ok(());
+                }
+
+                Some(b'"') => {
+                    let key: String = serde_json::from_slice(&self.read_value()?)?;
+                    self.skip_whitespace()?;
+                    self.expect(b':')?;
+                    self.skip_whitespace()?;
+
+                    // Items are streamed, everything else is retained for the header
+                    if key == "items" && self.peek()? == Some(b'[') {
+                        self.bump();
+                        self.state = State::Items;
+                        return Ok(());
+                    }
+                    let value = serde_json::from_slice(&self.read_value()?)?;
+                    self.fields.insert(key, value);
+                }
+
+                _ => return Err(syntax_error("expected a field of the JSON object")),
+            }
+        }
+    }
+
+    // Reads the raw bytes of the next JSON value (object, array, string or literal)
+    fn read_value(&mut self) -> ParseFeedResult<Vec<u8>> {
+        let mut raw = Vec::new();
+        let mut depth = 0;
+        let mut in_string = false;
+        let mut escaped = false;
+
+        while let Some(b) = self.peek()? {
+            if in_string {
+                if escaped {
+                    escaped = false;
+                } else if b == b'\\' {
+                    escaped = true;
+                } else if b == b'"' {
+                    in_string = false;
+                }
+            } else {
+                match b {
+                    b'"' => in_string = true,
+                    b'{' | b'[' => depth += 1,
+                    b'}' | b']' | b',' if depth == 0 => break,
+                    b'}' | b']' => depth -= 1,
+                    _ if depth == 0 && b.is_ascii_whitespace() => break,
+                    _ => {}
+                }
+            }
+
+            raw.push(b);
+            self.bump();
+
+            // A string, object or array at the top level is terminated by its closing character
+            if depth == 0 && !in_string && matches!(b, b'"' | b'}' | b']') {
+                break;
+            }
+        }
+
+        Ok(raw)
+    }
+
+    // Consumes the expected character
+    fn expect(&mut self, expected: u8) -> ParseFeedResult<()> {
+        if self.peek()? == Some(expected) {
+            self.bump();
+            Ok(())
+        } else {
+            Err(syntax_error(&format!("expected '{}'", expected as char)))
+        }
+    }
+
+    fn skip_whitespace(&mut self) -> ParseFeedResult<()> {
+        while let Some(b) = self.peek()? {
+            if !b.is_ascii_whitespace() {
+                break;
+            }
+            self.bump();
+        }
+        Ok(())
+    }
+
+    fn peek(&mut self) -> ParseFeedResult<Option<u8>> {
+        Ok(self.reader.fill_buf()?.first().copied())
+    }
+
+    fn bump(&mut self) {
+        self.reader.consume(1);
+    }
+}
+
+// Reports malformed JSON in the same manner as serde
+fn syntax_error(msg: &str) -> ParseFeedError {
+    ParseFeedError::JsonSerde(serde_json::Error::custom(msg))
+}
@@ -1,5 +1,11 @@
-use crate::model::{Content, Entry, Feed, FeedType, Image, Link, Person, Text};
-use crate::parser;
+use std::time::Duration;
+
+use serde_json::{json, Value};
+
+use crate::model::{Content, Entry, Feed, FeedType, Image, Link, MediaContent, MediaObject, MediaThumbnail, Person, Text};
+use crate::parser::json::stream::JsonStream;
+use crate::parser::json::JsonFeed;
+use crate::parser::{self, ParseFeedResult, WarningKind};
 use crate::util::test;
 
 // Verify we can parse a more complete feed
@@ -15,7 +21,7 @@ fn test_example_1() {
         .updated(actual.updated)        // not in test content
         .title(Text::new("Daring Fireball".into()))
         .link(Link::new("https://daringfireball.net/", None))
-        .link(Link::new("https://daringfireball.net/feeds/json", None))
+        .link(Link::new("https://daringfireball.net/feeds/json", None).rel("self"))
         .author(Person::new("John Gruber")
             .uri("https://twitter.com/gruber"))
         .logo(Image::new("https://daringfireball.net/graphics/apple-touch-icon.png".into()))
@@ -65,9 +71,10 @@ fn test_spec_1() {
         .title(Text::new("JSON Feed".into()))
         .description(Text::new("JSON Feed is a pragmatic syndication format for blogs, microblogs, and other time-based content.".into()))
         .link(Link::new("https://jsonfeed.org/", None))
-        .link(Link::new("https://jsonfeed.org/feed.json", None))
+        .link(Link::new("https://jsonfeed.org/feed.json", None).rel("self"))
         .author(Person::new("Brent Simmons and Manton Reece")
             .uri("https://jsonfeed.org/"))
+        .json_extension("user_comment", json!("This feed allows you to read the posts from this site in any feed reader that supports the JSON Feed format. To add this feed to your reader, copy the following URL — https://jsonfeed.org/feed.json — and add it your reader."))
         .entry(Entry::default()
             .updated(actual.entries[0].updated)             // not in test content
             .id("https://jsonfeed.org/2017/05/17/announcing_json_feed")
@@ -130,3 +137,269 @@ fn test_elastic_v1_1() {
     let entry = entries.next().unwrap();
     assert_eq!(entry.authors, vec!(Person::new("Fake Author 3"), Person::new("Fake Author 4")));
 }
+
+// Verify that extensions and unknown members are preserved for the feed and items
+#[test]
+fn test_extensions() {
+    let json = r#"{
+        "version": "https://jsonfeed.org/version/1.1",
+        "title": "feed",
+        "_microblog": {"about": "https://micro.blog/about/", "username": "manton"},
+        "items": [
+            {"id": "1", "_podcast": {"duration": 3600, "explicit": false}, "unknown": [1, 2]},
+            {"id": "2"}
+        ]
+    }"#;
+
+    let check = |feed: &Feed, entries: &[Entry]| {
+        assert_eq!(feed.json_extensions.len(), 1);
+        assert_eq!(feed.json_extensions["_microblog"]["username"], "manton");
+
+        assert_eq!(entries[0].json_extensions["_podcast"], json!({"duration": 3600, "explicit": false}));
+        assert_eq!(entries[0].json_extensions["unknown"], json!([1, 2]));
+        assert!(entries[1].json_extensions.is_empty());
+    };
+    let feed = parser::parse(json.as_bytes()).unwrap();
+    check(&feed, &feed.entries);
+
+    // The streaming parser preserves the same
+    let parser = parser::Builder::new().build();
+    let stream = parser.parse_streaming(json.as_bytes()).unwrap();
+    let feed = stream.feed().clone();
+    let entries: Vec<Entry> = stream.map(|entry| entry.unwrap()).collect();
+    check(&feed, &entries);
+}
+
+// Verify the JSON Feed 1.1 fields are mapped into the model
+#[test]
+fn test_v1_1_fields() {
+    let json = r#"{
+        "version": "https://jsonfeed.org/version/1.1",
+        "title": "Podcast",
+        "home_page_url": "https://example.com/",
+        "next_url": "https://example.com/feed.json?page=2",
+        "expired": true,
+        "hubs": [{"type": "WebSub", "url": "https://hub.example.com/"}],
+        "items": [{
+            "id": "1",
+            "language": "fr-CA",
+            "image": "https://example.com/episode.jpg",
+            "banner_image": "https://example.com/banner.jpg",
+            "attachments": [{
+                "url": "https://example.com/episode.mp3",
+                "mime_type": "audio/mpeg",
+                "title": "Episode",
+                "size_in_bytes": 1234,
+                "duration_in_seconds": 90.5
+            }]
+        }]
+    }"#;
+    let actual = parser::parse(json.as_bytes()).unwrap();
+
+    assert!(actual.expired);
+    let mut hub = Link::new("https://hub.example.com/", None).rel("hub");
+    hub.title = Some("WebSub".into());
+    let expected_links = vec![
+        Link::new("https://example.com/", None),
+        Link::new("https://example.com/feed.json?page=2", None).rel("next"),
+        hub,
+    ];
+    assert_eq!(actual.links, expected_links);
+
+    let entry = &actual.entries[0];
+    assert_eq!(entry.language.as_deref(), Some("fr-CA"));
+    let expected_links = vec![
+        Link::new("https://example.com/episode.mp3", None)
+            .media_type("audio/mpeg")
+            .title("Episode")
+            .length(1234),
+        Link::new("https://example.com/banner.jpg", None).rel("banner"),
+    ];
+    assert_eq!(entry.links, expected_links);
+
+    let duration = Duration::from_millis(90500);
+    let expected_media = vec![
+        MediaObject::default().title("Episode").duration(duration).content(
+            MediaContent::new()
+                .url("https://example.com/episode.mp3")
+                .content_type("audio/mpeg")
+                .size(1234)
+                .duration(duration),
+        ),
+        MediaObject::default().thumbnail(MediaThumbnail::new(Image::new("https://example.com/episode.jpg".into()))),
+    ];
+    assert_eq!(entry.media, expected_media);
+}
+
+// Verify that common deviations from the spec are tolerated, recording the values that were coerced
+#[test]
+fn test_deviations() {
+    let json = r#"{
+        "version": "1.0",
+        "home_page_url": null,
+        "items": [
+            {"id": 42, "title": null, "content_text": "numeric"},
+            "not an item",
+            {"id": true, "attachments": [{"url": "https://example.com/file"}]},
+            null,
+            {"id": "3", "tags": "not a list"},
+            {"id": "4", "summary": {"not": "text"}}
+        ]
+    }"#;
+
+    // Items that are not objects are always skipped, but invalid items fail the feed unless parsing leniently
+    let parser = parser::Builder::new().build();
+    assert!(parser.parse(json.as_bytes()).is_err());
+
+    let parser = parser::Builder::new().lenient(true).build();
+    let (feed, warnings) = parser.parse_with_report(json.as_bytes()).unwrap();
+    assert!(feed.title.is_none());
+    let ids: Vec<&str> = feed.entries.iter().map(|entry| entry.id.as_str()).collect();
+    assert_eq!(ids, vec!["42", "true", "3"]);
+    assert_eq!(feed.entries[1].links[0].media_type, None);
+    assert_eq!(feed.entries[2].categories[0].term, "not a list");
+
+    // Skipped items are recorded with the item, or the error if the item is invalid
+    let warnings: Vec<(WarningKind, &str)> = warnings.iter().map(|warning| (warning.kind.clone(), warning.path.as_str())).collect();
+    assert_eq!(
+        warnings,
+        vec![
+            (WarningKind::CoercedValue, "/version"),
+            (WarningKind::CoercedValue, "/title"),
+            (WarningKind::CoercedValue, "/items/0/id"),
+            (WarningKind::SkippedEntry, "/items/1"),
+            (WarningKind::CoercedValue, "/items/2/id"),
+            (WarningKind::SkippedEntry, "/items/3"),
+            (WarningKind::CoercedValue, "/items/4/tags"),
+            (WarningKind::SkippedEntry, "/items/5"),
+        ]
+    );
+    let (_, warnings) = parser.parse_with_report(json.as_bytes()).unwrap();
+    let values: Vec<&str> = warnings.iter().take(5).map(|warning| warning.value.as_str()).collect();
+    assert_eq!(values, vec!["1.0", "", "42", "\"not an item\"", "true"]);
+
+    // The streaming parser skips the same items
+    let ids: Vec<String> = parser.parse_streaming(json.as_bytes()).unwrap().map(|entry| entry.unwrap().id).collect();
+    assert_eq!(ids, vec!["42", "true", "3"]);
+
+    // The version may be absent, but other versions are still unsupported
+    let (_, warnings) = parser.parse_with_report(r#"{"title": "t", "items": []}"#.as_bytes()).unwrap();
+    assert_eq!(warnings[0].kind, WarningKind::CoercedValue);
+    assert_eq!(warnings[0].path, "/version");
+    assert!(parser
+        .parse(r#"{"version": "https://jsonfeed.org/version/2", "items": []}"#.as_bytes())
+        .is_err());
+}
+
+// Verify that values of the wrong type (or missing) are coerced or dropped, rather than failing the feed
+#[test]
+fn test_coerced_values() {
+    let parser = parser::Builder::new().build();
+    let warnings = |json: &str| -> Vec<(String, String)> {
+        let (_, warnings) = parser.parse_with_report(json.as_bytes()).unwrap();
+        warnings.into_iter().map(|warning| (warning.path, warning.value)).collect()
+    };
+
+    // Items that are not a list are treated as empty, by both parsers
+    let json = r#"{"version": "https://jsonfeed.org/version/1.1", "title": "t", "items": null}"#;
+    assert!(parser.parse(json.as_bytes()).unwrap().entries.is_empty());
+    assert_eq!(warnings(json), vec![("/items".into(), "null".into())]);
+    assert_eq!(parser.parse_streaming(json.as_bytes()).unwrap().count(), 0);
+
+    // Attachments without a URL are dropped
+    let json = r#"{"version": "https://jsonfeed.org/version/1.1", "title": "t", "items": [{"id": "1", "attachments": [
+        {"url": null, "mime_type": "audio/mpeg"},
+        {"mime_type": "audio/mpeg"},
+        {"url": "https://example.com/1.mp3", "mime_type": "audio/mpeg"}
+    ]}]}"#;
+    let feed = parser.parse(json.as_bytes()).unwrap();
+    assert_eq!(feed.entries[0].links.len(), 1);
+    assert_eq!(feed.entries[0].links[0].href, "https://example.com/1.mp3");
+    assert_eq!(
+        warnings(json),
+        vec![
+            ("/items/0/attachments/0/url".into(), "".into()),
+            ("/items/0/attachments/1/url".into(), "".into())
+        ]
+    );
+
+    // Hubs without a type are retained, but hubs without a URL are dropped
+    let json =
+        r#"{"version": "https://jsonfeed.org/version/1.1", "title": "t", "hubs": [{"url": "https://example.com/hub"}, {"type": "WebSub"}], "items": []}"#;
+    let feed = parser.parse(json.as_bytes()).unwrap();
+    assert_eq!(feed.links.len(), 1);
+    assert_eq!(feed.links[0].href, "https://example.com/hub");
+    assert_eq!(feed.links[0].title, None);
+    assert_eq!(warnings(json), vec![("/hubs/0/type".into(), "".into()), ("/hubs/1/url".into(), "".into())]);
+
+    // Scalar tags are converted to strings, with other values dropped
+    let json = r#"{"version": "https://jsonfeed.org/version/1.1", "title": "t", "items": [{"id": "1", "tags": ["news", 42, true, null, {"a": 1}]}]}"#;
+    let feed = parser.parse(json.as_bytes()).unwrap();
+    let tags: Vec<&str> = feed.entries[0].categories.iter().map(|category| category.term.as_str()).collect();
+    assert_eq!(tags, vec!["news", "42", "true"]);
+    assert_eq!(
+        warnings(json),
+        vec![
+            ("/items/0/tags/1".into(), "42".into()),
+            ("/items/0/tags/2".into(), "true".into()),
+            ("/items/0/tags/3".into(), "null".into()),
+            ("/items/0/tags/4".into(), "{\"a\":1}".into())
+        ]
+    );
+}
+
+// Reads every item (and the feed level fields) of a document with the streaming reader
+fn stream_document(json: &str) -> ParseFeedResult<(Vec<Value>, JsonFeed)> {
+    let mut stream = JsonStream::new(json.as_bytes())?;
+    let mut items = Vec::new();
+    while let Some((index, item)) = stream.next_item()? {
+        assert_eq!(index, items.len());
+        items.push(item);
+    }
+    Ok((items, stream.header()?))
+}
+
+// Verify the streaming reader delimits values containing quotes, braces and brackets within strings, and nested values
+#[test]
+fn test_stream_values() {
+    let json = r#"{
+        "title": "a \"quoted\" {title}] with \\",
+        "items": [
+            {"id": "1", "title": "brace } bracket ] comma , and \\\" escapes \\\\"},
+            {"id": 2, "content_text": "[{\"x\": 1}]", "tags": ["a", ["b", {"c": "}"}]], "_ext": {"n": -1.5e3, "b": true, "z": null}}
+        ],
+        "description": "after \"items\" ]}",
+        "expired": true,
+        "_count": 12
+    }"#;
+
+    let (items, header) = stream_document(json).unwrap();
+    assert_eq!(
+        items,
+        vec![
+            json!({"id": "1", "title": "brace } bracket ] comma , and \\\" escapes \\\\"}),
+            json!({"id": 2, "content_text": "[{\"x\": 1}]", "tags": ["a", ["b", {"c": "}"}]], "_ext": {"n": -1.5e3, "b": true, "z": null}}),
+        ]
+    );
+    assert_eq!(header.title.as_deref(), Some("a \"quoted\" {title}] with \\"));
+    assert_eq!(header.description.as_deref(), Some("after \"items\" ]}"));
+    assert_eq!(header.expired, Some(true));
+    assert_eq!(header.extensions.get("_count"), Some(&json!(12)));
+
+    // The entries are the same as parsing the document as a whole
+    let parser = parser::Builder::new().build();
+    let parsed = parser.parse(json.as_bytes()).unwrap();
+    let streamed = parser.parse_streaming(json.as_bytes()).unwrap();
+    assert_eq!(streamed.collect::<ParseFeedResult<Vec<_>>>().unwrap(), parsed.entries);
+}
+
+// Verify a document truncated at any point is reported as an error by the streaming reader
+#[test]
+fn test_stream_truncated() {
+    let json = r#"{"title": "t \"}\"", "items": [{"id": "1", "tags": ["]"]}, {"id": 2.5}, true], "expired": false, "_n": 10}"#;
+    assert!(stream_document(json).is_ok());
+
+    for end in 0..json.len() {
+        assert!(stream_document(&json[..end]).is_err(), "truncated at {}: {}", end, &json[..end]);
+    }
+}
@@ -0,0 +1,175 @@
+use std::cell::Cell;
+use std::fmt;
+use std::io::{self, BufRead, Read};
+#[cfg(feature = "async")]
+use std::pin::Pin;
+use std::rc::Rc;
+#[cfg(feature = "async")]
+use std::task::{Context, Poll};
+
+#[cfg(feature = "async")]
+use tokio::io::{AsyncBufRead, AsyncRead, ReadBuf};
+
+use crate::parser::{ParseErrorKind, ParseFeedError, ParseFeedResult};
+
+/// A limit on the resources consumed when parsing, configured through the `Builder`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Limit {
+    /// Depth of nested XML elements
+    Depth,
+    /// Length of a text node in XML content
+    TextLength,
+    /// Number of entries in the feed
+    Entries,
+    /// Size of the source
+    Bytes,
+    /// Number of attributes on an XML element
+    Attributes,
+}
+
+impl fmt::Display for Limit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Limit::Depth => f.write_str("maximum depth"),
+            Limit::TextLength => f.write_str("maximum text length"),
+            Limit::Entries => f.write_str("maximum number of entries"),
+            Limit::Bytes => f.write_str("maximum size"),
+            Limit::Attributes => f.write_str("maximum number of attributes"),
+        }
+    }
+}
+
+/// The configured limits (no limit is applied unless set)
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct Limits {
+    pub max_depth: Option<usize>,
+    pub max_text_length: Option<usize>,
+    pub max_entries: Option<usize>,
+    pub max_bytes: Option<u64>,
+    pub max_attributes: Option<usize>,
+}
+
+impl Limits {
+    /// Checks the value is within the limit, if set
+    pub(crate) fn check(max: Option<usize>, value: usize, limit: Limit) -> Result<(), Limit> {
+        match max {
+            Some(max) if value > max => Err(limit),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Wraps a source, failing once more than the maximum number of bytes have been read
+pub(crate) struct SizeLimited<R> {
+    inner: R,
+    remaining: u64,
+    exceeded: Rc<Cell<bool>>,
+}
+
+impl<R> SizeLimited<R> {
+    /// Wraps the source, returning a check to apply to the result of parsing it
+    pub(crate) fn new(inner: R, max_bytes: Option<u64>) -> (SizeLimited<R>, SizeCheck) {
+        let exceeded = Rc::new(Cell::new(false));
+        let limited = SizeLimited {
+            inner,
+            remaining: max_bytes.unwrap_or(u64::MAX),
+            exceeded: exceeded.clone(),
+        };
+        (limited, SizeCheck(exceeded))
+    }
+}
+
+// Restricts the available content to the remaining allowance, failing if the source has more content once it is exhausted
+fn limit<'b>(exceeded: &Cell<bool>, remaining: u64, buf: &'b [u8]) -> io::Result<&'b [u8]> {
+    if remaining == 0 && !buf.is_empty() {
+        exceeded.set(true);
+        return Err(io::Error::other("maximum size exceeded"));
+    }
+    let allowed = usize::try_from(remaining).unwrap_or(usize::MAX);
+    Ok(&buf[..buf.len().min(allowed)])
+}
+
+impl<R: BufRead> Read for SizeLimited<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let count = {
+            let available = self.fill_buf()?;
+            let count = available.len().min(buf.len());
+            buf[..count].copy_from_slice(&available[..count]);
+            count
+        };
+        self.consume(count);
+        Ok(count)
+    }
+}
+
+impl<R: BufRead> BufRead for SizeLimited<R> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        let buf = self.inner.fill_buf()?;
+        limit(&self.exceeded, self.remaining, buf)
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.inner.consume(amt);
+        self.remaining -= amt as u64;
+    }
+}
+
+#[cfg(feature = "async")]
+impl<R: AsyncBufRead + Unpin> AsyncRead for SizeLimited<R> {
+    fn poll_read(mut self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &mut ReadBuf<'_>) -> Poll<io::Result<()>> {
+        let count = match self.as_mut().poll_fill_buf(cx) {
+            Poll::Ready(Ok(available)) => {
+                let count = available.len().min(buf.remaining());
+                buf.put_slice(&available[..count]);
+                count
+            }
+            Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+            Poll::Pending => return Poll::Pending,
+        };
+        self.consume(count);
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[cfg(feature = "async")]
+impl<R: AsyncBufRead + Unpin> AsyncBufRead for SizeLimited<R> {
+    fn poll_fill_buf(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<&[u8]>> {
+        let this = self.get_mut();
+        match Pin::new(&mut this.inner).poll_fill_buf(cx) {
+            Poll::Ready(Ok(buf)) => Poll::Ready(limit(&this.exceeded, this.remaining, buf)),
+            other => other,
+        }
+    }
+
+    fn consume(self: Pin<&mut Self>, amt: usize) {
+        let this = self.get_mut();
+        Pin::new(&mut this.inner).consume(amt);
+        this.remaining -= amt as u64;
+    }
+}
+
+/// Replaces the error arising from a source exceeding its maximum size with the corresponding limit
+pub(crate) struct SizeCheck(Rc<Cell<bool>>);
+
+impl SizeCheck {
+    /// Applies the check to the result of reading or parsing the source
+    ///
+    /// Content beyond the limit may not have been needed to produce the result (e.g. trailing markup), but the source is rejected regardless.
+    pub(crate) fn apply<T, E: Into<ParseFeedError>>(&self, result: Result<T, E>) -> ParseFeedResult<T> {
+        match result {
+            Ok(_) if self.0.get() => Err(ParseFeedError::ParseError(ParseErrorKind::LimitExceeded(Limit::Bytes), None)),
+            Ok(value) => Ok(value),
+            Err(e) => Err(self.error(e.into())),
+        }
+    }
+
+    /// Replaces the error if it was caused by the source exceeding its maximum size, retaining the position
+    pub(crate) fn error(&self, e: ParseFeedError) -> ParseFeedError {
+        if self.0.get() {
+            let position = e.position().cloned();
+            ParseFeedError::ParseError(ParseErrorKind::LimitExceeded(Limit::Bytes), position)
+        } else {
+            e
+        }
+    }
+}
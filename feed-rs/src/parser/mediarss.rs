@@ -3,14 +3,92 @@ use std::time::Duration;
 
 use mediatype::{names, MediaTypeBuf};
 
-use crate::model::{Image, MediaCommunity, MediaContent, MediaCredit, MediaObject, MediaRating, MediaText, MediaThumbnail, Text};
+use crate::model::{
+    Category, Entry, Feed, Image, Link, MediaCommunity, MediaContent, MediaCopyright, MediaCredit, MediaEmbed, MediaHash, MediaLicense, MediaLocation,
+    MediaObject, MediaPlayer, MediaPrice, MediaRating, MediaRelationship, MediaRestriction, MediaScene, MediaState, MediaStatus, MediaText, MediaThumbnail,
+    Text,
+};
 use crate::parser::util::{if_ok_then_some, if_some_then, parse_npt};
-use crate::parser::{util, ParseErrorKind, ParseFeedError, ParseFeedResult};
+use crate::parser::{util, ParseErrorKind, ParseFeedError, ParseFeedResult, WarningKind};
 use crate::xml::{Element, NS};
 
-// TODO When an element appears at a shallow level, such as <channel> or <item>, it means that the element should be applied to every media object within its scope.
+// Namespaces of the elements holding the coordinates of a "media:location"
+const GEORSS_NS: &str = "http://www.georss.org/georss";
+const GML_NS: &str = "http://www.opengis.net/gml";
+
+// When an element appears at a shallow level, such as <channel> or <item>, it means that the element should be applied to every media object within its scope.
+// Elements at the channel level are retained on the feed, and only applied to the media of each entry on request (see inherit_channel_media())
 // TODO Duplicated elements appearing at deeper levels of the document tree have higher priority over other levels. For example, <media:content> level elements are favored over <item> level elements. The priority level is listed from strongest to weakest: <media:content>, <media:group>, <item>, <channel>.
 
+/// Process the mediarss elements at channel level, updating the Feed object as required
+pub(crate) fn handle_media_channel_element<R: BufRead>(element: Element<R>, feed: &mut Feed) -> ParseFeedResult<()> {
+    match element.ns_and_tag() {
+        // The rating applies to the feed as a whole, taking precedence over "itunes:explicit"
+        (NS::MediaRSS, "rating") => if_some_then(handle_media_rating(element), |rating| feed.rating = Some(rating)),
+
+        // Other elements populate the channel media object, which is only retained if it has content
+        _ => {
+            let mut media_obj = feed.media.take().unwrap_or_default();
+            handle_media_element(element, &mut media_obj)?;
+            feed.media = Some(media_obj).filter(MediaObject::has_content);
+        }
+    }
+
+    Ok(())
+}
+
+/// Applies the channel level media elements to the media of the entry, as the defaults for the fields without a value
+///
+/// Only the fields describing the media as a whole are inherited (e.g. thumbnails, credits and copyright), rather than those describing a particular item.
+/// Entries without any media receive a media object holding just the inherited fields.
+pub(crate) fn inherit_channel_media(channel: Option<&MediaObject>, rating: Option<&MediaRating>, entry: &mut Entry) {
+    let added = entry.media.is_empty();
+    if added {
+        entry.media.push(MediaObject::default());
+    }
+
+    for media_obj in &mut entry.media {
+        if let Some(channel) = channel {
+            inherit_vec(&mut media_obj.thumbnails, &channel.thumbnails);
+            inherit_vec(&mut media_obj.credits, &channel.credits);
+            inherit_vec(&mut media_obj.keywords, &channel.keywords);
+            inherit_vec(&mut media_obj.categories, &channel.categories);
+            inherit_vec(&mut media_obj.licenses, &channel.licenses);
+            inherit_vec(&mut media_obj.restrictions, &channel.restrictions);
+            inherit_vec(&mut media_obj.prices, &channel.prices);
+            inherit_option(&mut media_obj.community, &channel.community);
+            inherit_option(&mut media_obj.status, &channel.status);
+            inherit_option(&mut media_obj.copyright, &channel.copyright);
+        }
+
+        // The rating of the feed applies to each content item without its own rating
+        if let Some(rating) = rating {
+            media_obj.content.iter_mut().filter(|content| content.rating.is_none()).for_each(|content| {
+                content.rating = Some(rating.clone());
+            });
+        }
+    }
+
+    // Nothing was inherited by an entry without media
+    if added && !entry.media[0].has_content() {
+        entry.media.clear();
+    }
+}
+
+// Assigns the channel value if the field does not have one
+fn inherit_option<T: Clone>(field: &mut Option<T>, channel: &Option<T>) {
+    if field.is_none() {
+        field.clone_from(channel);
+    }
+}
+
+// Assigns the channel values if the field does not have any
+fn inherit_vec<T: Clone>(field: &mut Vec<T>, channel: &[T]) {
+    if field.is_empty() {
+        field.extend_from_slice(channel);
+    }
+}
+
 /// Handles the top-level "media:group", a collection of mediarss elements.
 pub(crate) fn handle_media_group<R: BufRead>(element: Element<R>) -> ParseFeedResult<Option<MediaObject>> {
     let mut media_obj = MediaObject::default();
@@ -49,6 +127,47 @@ pub(crate) fn handle_media_element<R: BufRead>(element: Element<R>, media_obj: &
 
         (NS::MediaRSS, "rating") => rating = handle_media_rating(element),
 
+        (NS::MediaRSS, "keywords") => if_some_then(element.child_as_text(), |text| {
+            let keywords = text.split(',').map(str::trim).filter(|keyword| !keyword.is_empty());
+            media_obj.keywords.extend(keywords.map(str::to_string))
+        }),
+
+        (NS::MediaRSS, "category") => if_some_then(handle_media_category(element), |category| media_obj.categories.push(category)),
+
+        (NS::MediaRSS, "hash") => if_some_then(handle_media_hash(element), |hash| media_obj.hashes.push(hash)),
+
+        (NS::MediaRSS, "player") => if_some_then(handle_media_player(element), |player| media_obj.player = Some(player)),
+
+        (NS::MediaRSS, "embed") => media_obj.embed = Some(handle_media_embed(element)?),
+
+        (NS::MediaRSS, "license") => if_some_then(handle_media_license(element), |license| media_obj.licenses.push(license)),
+
+        (NS::MediaRSS, "restriction") => if_some_then(handle_media_restriction(element), |restriction| media_obj.restrictions.push(restriction)),
+
+        (NS::MediaRSS, "price") => media_obj.prices.push(handle_media_price(element)),
+
+        (NS::MediaRSS, "peerLink") => if_some_then(handle_media_link(element), |link| media_obj.peer_links.push(link)),
+
+        (NS::MediaRSS, "subTitle") => if_some_then(handle_media_link(element), |link| media_obj.subtitles.push(link)),
+
+        (NS::MediaRSS, "location") => media_obj.locations.push(handle_media_location(element)?),
+
+        (NS::MediaRSS, "scenes") => handle_media_scenes(element, media_obj)?,
+
+        (NS::MediaRSS, "status") => if_some_then(handle_media_status(element), |status| media_obj.status = Some(status)),
+
+        (NS::MediaRSS, "copyright") => if_some_then(handle_media_copyright(element), |copyright| media_obj.copyright = Some(copyright)),
+
+        (NS::MediaRSS, "backLinks") => {
+            for child in element.children() {
+                let child = child?;
+                if child.ns_and_tag() == (NS::MediaRSS, "backLink") {
+                    let url = child.child_as_text().and_then(|text| util::parse_uri(text.trim(), child.xml_base.as_ref()));
+                    if_some_then(url, |url| media_obj.back_links.push(url));
+                }
+            }
+        }
+
         // Nothing required for unknown elements
         _ => {}
     }
@@ -65,6 +184,16 @@ pub(crate) fn handle_media_element<R: BufRead>(element: Element<R>, media_obj: &
     Ok(())
 }
 
+// Handles the "media:category" element
+fn handle_media_category<R: BufRead>(element: Element<R>) -> Option<Category> {
+    element.child_as_text().map(|term| {
+        let mut category = Category::new(term.trim());
+        category.scheme = element.attr_value("scheme");
+        category.label = element.attr_value("label");
+        category
+    })
+}
+
 // Handle "media:community"
 fn handle_media_community<R: BufRead>(element: Element<R>) -> ParseFeedResult<Option<MediaCommunity>> {
     let mut community = MediaCommunity::new();
@@ -114,14 +243,18 @@ fn handle_media_content<R: BufRead>(element: Element<R>, media_obj: &mut MediaOb
         match attr.name.as_str() {
             "url" => content.url = util::parse_uri(&attr.value, element.xml_base.as_ref()),
 
-            "type" => if_ok_then_some(attr.value.parse::<MediaTypeBuf>(), |v| content.content_type = v),
+            "type" => if_some_then(util::parse_attr(&element, WarningKind::UnknownMimeType, attr), |v| {
+                content.content_type = Some(v)
+            }),
 
-            "width" => if_ok_then_some(attr.value.parse::<u32>(), |v| content.width = v),
-            "height" => if_ok_then_some(attr.value.parse::<u32>(), |v| content.height = v),
+            "width" => if_some_then(util::parse_attr(&element, WarningKind::InvalidNumber, attr), |v| content.width = Some(v)),
+            "height" => if_some_then(util::parse_attr(&element, WarningKind::InvalidNumber, attr), |v| content.height = Some(v)),
 
-            "fileSize" => if_ok_then_some(attr.value.parse::<u64>(), |v| content.size = v),
+            "fileSize" => if_some_then(util::parse_attr(&element, WarningKind::InvalidNumber, attr), |v| content.size = Some(v)),
 
-            "duration" => if_ok_then_some(attr.value.parse::<u64>(), |v| content.duration = v.map(Duration::from_secs)),
+            "duration" => if_some_then(util::parse_attr(&element, WarningKind::InvalidNumber, attr), |v| {
+                content.duration = Some(Duration::from_secs(v))
+            }),
 
             // Nothing required for unknown attributes
             _ => {}
@@ -132,31 +265,14 @@ fn handle_media_content<R: BufRead>(element: Element<R>, media_obj: &mut MediaOb
     for child in element.children() {
         let child = child?;
         match child.ns_and_tag() {
-            (NS::MediaRSS, "player") => {
-                // According to the spec at https://www.rssboard.org/media-rss#media-content
-                // "url should specify the direct URL to the media object. If not included, a <media:player> element must be specified."
-                // So if we find a player element here, and it has a URL then we assign it
-                if let Some(player_url) = child.attr_value("url") {
-                    let player_url = util::parse_uri(&player_url, element.xml_base.as_ref());
-                    if player_url.is_some() {
-                        content.url = player_url;
-                    }
-                }
-            }
+            (NS::MediaRSS, "player") => content.player = handle_media_player(child),
 
             (NS::MediaRSS, "rating") => content.rating = handle_media_rating(child),
 
             // These elements are modelled as fields on the parent MediaObject, but only set if the parent field does not already have a value
-            (NS::MediaRSS, "title") => {
-                if media_obj.title.is_none() {
-                    media_obj.title = handle_text(child)?
-                }
-            }
-            (NS::MediaRSS, "description") => {
-                if media_obj.description.is_none() {
-                    media_obj.description = handle_text(child)?
-                }
-            }
+            (NS::MediaRSS, "title") if media_obj.title.is_none() => media_obj.title = handle_text(child)?,
+            (NS::MediaRSS, "description") if media_obj.description.is_none() => media_obj.description = handle_text(child)?,
+            (NS::MediaRSS, "title") | (NS::MediaRSS, "description") => {}
 
             // These elements are accumulated in the corresponding field of the parent MediaObject
             (NS::MediaRSS, "text") => if_some_then(handle_media_text(child), |text| media_obj.texts.push(text)),
@@ -170,6 +286,13 @@ fn handle_media_content<R: BufRead>(element: Element<R>, media_obj: &mut MediaOb
         }
     }
 
+    // According to the spec at https://www.rssboard.org/media-rss#media-content
+    // "url should specify the direct URL to the media object. If not included, a <media:player> element must be specified."
+    // So if we have a player but no URL, then we use the URL of the player
+    if content.url.is_none() {
+        content.url = content.player.as_ref().map(|player| player.url.clone());
+    }
+
     // If we found a URL from the content element or the player, we consider this valid
     if content.url.is_some() {
         // Emit this parsed content
@@ -179,11 +302,137 @@ fn handle_media_content<R: BufRead>(element: Element<R>, media_obj: &mut MediaOb
     Ok(())
 }
 
+// Handles the "media:copyright" element
+fn handle_media_copyright<R: BufRead>(element: Element<R>) -> Option<MediaCopyright> {
+    element.child_as_text().map(|notice| MediaCopyright {
+        notice,
+        url: element.attr_value("url").and_then(|url| util::parse_uri(&url, element.xml_base.as_ref())),
+    })
+}
+
 // Handles the "media:credit" element
 fn handle_media_credit<R: BufRead>(element: Element<R>) -> Option<MediaCredit> {
     element.child_as_text().map(MediaCredit::new)
 }
 
+// Handles the "media:embed" element, with the parameters in child "media:param" elements
+fn handle_media_embed<R: BufRead>(element: Element<R>) -> ParseFeedResult<MediaEmbed> {
+    let mut embed = MediaEmbed::default();
+    for attr in &element.attributes {
+        match attr.name.as_str() {
+            "url" => embed.url = util::parse_uri(&attr.value, element.xml_base.as_ref()),
+
+            "width" => embed.width = util::parse_attr(&element, WarningKind::InvalidNumber, attr),
+            "height" => embed.height = util::parse_attr(&element, WarningKind::InvalidNumber, attr),
+
+            // Nothing required for unknown attributes
+            _ => {}
+        }
+    }
+
+    for child in element.children() {
+        let child = child?;
+        if child.ns_and_tag() == (NS::MediaRSS, "param") {
+            if let (Some(name), Some(value)) = (child.attr_value("name"), child.child_as_text()) {
+                embed.params.insert(name, value);
+            }
+        }
+    }
+
+    Ok(embed)
+}
+
+// Handles the "media:hash" element
+fn handle_media_hash<R: BufRead>(element: Element<R>) -> Option<MediaHash> {
+    element.child_as_text().map(|value| MediaHash {
+        // Algorithm is "md5" by default
+        algo: element.attr_value("algo").unwrap_or_else(|| "md5".into()),
+        value: value.trim().to_string(),
+    })
+}
+
+// Handles the "media:license" element
+fn handle_media_license<R: BufRead>(element: Element<R>) -> Option<MediaLicense> {
+    let content_type = element.attributes.iter().find(|attr| attr.name == "type");
+    let license = MediaLicense {
+        name: element.child_as_text(),
+        content_type: content_type.and_then(|attr| util::parse_attr(&element, WarningKind::UnknownMimeType, attr)),
+        href: element.attr_value("href").and_then(|href| util::parse_uri(&href, element.xml_base.as_ref())),
+    };
+
+    // Need either the name or a link to the license
+    if license.name.is_some() || license.href.is_some() {
+        Some(license)
+    } else {
+        None
+    }
+}
+
+// Handles the "media:peerLink" and "media:subTitle" elements, which are links with a type (and language for subtitles)
+fn handle_media_link<R: BufRead>(element: Element<R>) -> Option<Link> {
+    element.attr_value("href").map(|href| {
+        let mut link = Link::new(href, element.xml_base.as_ref());
+        link.media_type = element.attr_value("type");
+        link.href_lang = element.attr_value("lang");
+        link
+    })
+}
+
+// Handles the "media:location" element, taking the coordinates from a "georss:point" or "gml:pos" within it
+fn handle_media_location<R: BufRead>(element: Element<R>) -> ParseFeedResult<MediaLocation> {
+    let mut location = MediaLocation {
+        description: element.attr_value("description"),
+        start_time: element.attr_value("start").and_then(|start| parse_npt(&start)),
+        end_time: element.attr_value("end").and_then(|end| parse_npt(&end)),
+        latitude: None,
+        longitude: None,
+    };
+
+    if let Some((latitude, longitude)) = find_coordinates(element)? {
+        location.latitude = Some(latitude);
+        location.longitude = Some(longitude);
+    }
+
+    Ok(location)
+}
+
+// Handles the "media:player" element
+fn handle_media_player<R: BufRead>(element: Element<R>) -> Option<MediaPlayer> {
+    let mut url = None;
+    let mut width = None;
+    let mut height = None;
+    for attr in &element.attributes {
+        match attr.name.as_str() {
+            "url" => url = util::parse_uri(&attr.value, element.xml_base.as_ref()),
+
+            "width" => width = util::parse_attr(&element, WarningKind::InvalidNumber, attr),
+            "height" => height = util::parse_attr(&element, WarningKind::InvalidNumber, attr),
+
+            // Nothing required for unknown attributes
+            _ => {}
+        }
+    }
+
+    url.map(|url| MediaPlayer { url, height, width })
+}
+
+// Handles the "media:price" element
+fn handle_media_price<R: BufRead>(element: Element<R>) -> MediaPrice {
+    let mut price = MediaPrice::default();
+    for attr in &element.attributes {
+        match attr.name.as_str() {
+            "type" => price.price_type = Some(attr.value.clone()),
+            "price" => price.price = util::parse_attr(&element, WarningKind::InvalidNumber, attr),
+            "info" => price.info = util::parse_uri(&attr.value, element.xml_base.as_ref()),
+            "currency" => price.currency = Some(attr.value.clone()),
+
+            // Nothing required for unknown attributes
+            _ => {}
+        }
+    }
+    price
+}
+
 // Handles the "media:rating" element
 fn handle_media_rating<R: BufRead>(element: Element<R>) -> Option<MediaRating> {
     // Schema is "urn:simple" by default
@@ -192,6 +441,65 @@ fn handle_media_rating<R: BufRead>(element: Element<R>) -> Option<MediaRating> {
     element.child_as_text().map(|rating| MediaRating::new(rating).urn(scheme.as_str()))
 }
 
+// Handles the "media:restriction" element, which lists the places separated by whitespace
+fn handle_media_restriction<R: BufRead>(element: Element<R>) -> Option<MediaRestriction> {
+    let relationship = match element.attr_value("relationship")?.to_lowercase().as_str() {
+        "allow" => MediaRelationship::Allow,
+        "deny" => MediaRelationship::Deny,
+        _ => return None,
+    };
+
+    let values = element.child_as_text().map(|text| text.split_whitespace().map(str::to_string).collect());
+    Some(MediaRestriction {
+        relationship,
+        restriction_type: element.attr_value("type"),
+        values: values.unwrap_or_default(),
+    })
+}
+
+// Handles the "media:scenes" element, adding each "media:scene" to the media object
+fn handle_media_scenes<R: BufRead>(element: Element<R>, media_obj: &mut MediaObject) -> ParseFeedResult<()> {
+    for child in element.children() {
+        let child = child?;
+        if child.ns_and_tag() != (NS::MediaRSS, "scene") {
+            continue;
+        }
+
+        // The spec shows the scene elements without a namespace prefix, so we match on the name alone
+        let mut scene = MediaScene::default();
+        for field in child.children() {
+            let field = field?;
+            match field.ns_and_tag().1 {
+                "sceneTitle" => scene.title = field.child_as_text(),
+                "sceneDescription" => scene.description = field.child_as_text(),
+                "sceneStartTime" => scene.start_time = field.child_as_text().and_then(|text| parse_npt(&text)),
+                "sceneEndTime" => scene.end_time = field.child_as_text().and_then(|text| parse_npt(&text)),
+
+                // Nothing required for unknown elements
+                _ => {}
+            }
+        }
+        media_obj.scenes.push(scene);
+    }
+
+    Ok(())
+}
+
+// Handles the "media:status" element
+fn handle_media_status<R: BufRead>(element: Element<R>) -> Option<MediaStatus> {
+    let state = match element.attr_value("state")?.to_lowercase().as_str() {
+        "active" => MediaState::Active,
+        "blocked" => MediaState::Blocked,
+        "deleted" => MediaState::Deleted,
+        _ => return None,
+    };
+
+    Some(MediaStatus {
+        state,
+        reason: element.attr_value("reason"),
+    })
+}
+
 // Handles the "media:text" element
 fn handle_media_text<R: BufRead>(element: Element<R>) -> Option<MediaText> {
     let mut start_time = None;
@@ -239,8 +547,8 @@ fn handle_media_thumbnail<R: BufRead>(element: Element<R>) -> Option<MediaThumbn
         match attr.name.as_str() {
             "url" => url = Some(attr.value.clone()),
 
-            "width" => if_ok_then_some(attr.value.parse::<u32>(), |v| width = v),
-            "height" => if_ok_then_some(attr.value.parse::<u32>(), |v| height = v),
+            "width" => if_some_then(util::parse_attr(&element, WarningKind::InvalidNumber, attr), |v| width = Some(v)),
+            "height" => if_some_then(util::parse_attr(&element, WarningKind::InvalidNumber, attr), |v| height = Some(v)),
 
             "time" => if_some_then(parse_npt(&attr.value), |npt| time = Some(npt)),
 
@@ -274,7 +582,7 @@ fn handle_text<R: BufRead>(element: Element<R>) -> ParseFeedResult<Option<Text>>
         "html" => Ok(MediaTypeBuf::new(names::TEXT, names::HTML)),
 
         // Unknown content type
-        _ => Err(ParseFeedError::ParseError(ParseErrorKind::UnknownMimeType(type_attr.into()))),
+        _ => Err(ParseFeedError::ParseError(ParseErrorKind::UnknownMimeType(type_attr.into()), None)),
     }?;
 
     element
@@ -285,5 +593,32 @@ fn handle_text<R: BufRead>(element: Element<R>) -> ParseFeedResult<Option<Text>>
             Some(text)
         })
         // Need the text for a text element
-        .ok_or(ParseFeedError::ParseError(ParseErrorKind::MissingContent("text")))
+        .ok_or(ParseFeedError::ParseError(ParseErrorKind::MissingContent("text"), None))
+}
+
+// Finds the coordinates in a "georss:point" or "gml:pos" element, both of which contain the latitude and longitude separated by whitespace
+fn find_coordinates<R: BufRead>(element: Element<R>) -> ParseFeedResult<Option<(f64, f64)>> {
+    for child in element.children() {
+        let child = child?;
+        let coordinates = match (child.namespace_uri.as_deref(), child.name.as_str()) {
+            (Some(GEORSS_NS), "point") | (Some(GML_NS), "pos") => child.child_as_text().and_then(|text| {
+                let mut parts = text.split_whitespace().map(|part| part.parse::<f64>().ok());
+                match (parts.next().flatten(), parts.next().flatten()) {
+                    (Some(latitude), Some(longitude)) => Some((latitude, longitude)),
+                    _ => None,
+                }
+            }),
+
+            // e.g. "georss:where" contains "gml:Point" which contains "gml:pos"
+            (Some(GEORSS_NS | GML_NS), _) => find_coordinates(child)?,
+
+            _ => None,
+        };
+
+        if coordinates.is_some() {
+            return Ok(coordinates);
+        }
+    }
+
+    Ok(None)
 }
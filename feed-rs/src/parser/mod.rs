@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::error::Error;
 use std::fmt;
 use std::fmt::Debug;
@@ -6,37 +7,65 @@ use std::io::{BufRead, BufReader, Read};
 
 use chrono::{DateTime, Utc};
 use siphasher::sip128::{Hasher128, SipHasher};
+#[cfg(feature = "async")]
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncReadExt};
 
 use crate::model;
-use crate::parser::util::{IdGenerator, TimestampParser};
+use crate::parser::limits::{Limits, SizeLimited};
+use crate::parser::util::{EntryErrorHandler, IdGenerator, TimestampParser};
 use crate::xml;
-use crate::xml::NS;
+use crate::xml::{Element, NS};
 
 mod atom;
-mod json;
 mod rss0;
 mod rss1;
 mod rss2;
 
 pub(crate) mod itunes;
+pub(crate) mod json;
 pub(crate) mod mediarss;
+pub(crate) mod podcast;
 pub(crate) mod util;
 
+pub(crate) mod limits;
+pub use limits::Limit;
+
+mod stream;
+pub use stream::FeedStream;
+
 pub type ParseFeedResult<T> = Result<T, ParseFeedError>;
 
 /// An error returned when parsing a feed from a source fails
 #[derive(Debug)]
 pub enum ParseFeedError {
-    // TODO add line number/position
-    ParseError(ParseErrorKind),
+    // Content of the feed could not be interpreted, with the position within XML content if known
+    ParseError(ParseErrorKind, Option<Position>),
     // IO error
     IoError(std::io::Error),
     // Underlying issue with JSON (poorly formatted etc.)
     JsonSerde(serde_json::error::Error),
     // Unsupported version of the JSON feed
     JsonUnsupportedVersion(String),
-    // Underlying issue with XML (poorly formatted etc.)
-    XmlReader(xml::XmlError),
+    // Underlying issue with XML (poorly formatted etc.), with the position if known
+    XmlReader(xml::XmlError, Option<Position>),
+}
+
+impl ParseFeedError {
+    /// The position within the source at which the error occurred (if known)
+    pub fn position(&self) -> Option<&Position> {
+        match self {
+            ParseFeedError::ParseError(_, position) | ParseFeedError::XmlReader(_, position) => position.as_ref(),
+            _ => None,
+        }
+    }
+
+    // Records the position at which the error occurred, if relevant to the error and not already known
+    pub(crate) fn at<F: FnOnce() -> Position>(mut self, position: F) -> Self {
+        if let ParseFeedError::ParseError(_, found @ None) | ParseFeedError::XmlReader(_, found @ None) = &mut self {
+            *found = Some(position());
+        }
+        self
+    }
 }
 
 impl From<serde_json::error::Error> for ParseFeedError {
@@ -53,18 +82,23 @@ impl From<std::io::Error> for ParseFeedError {
 
 impl From<xml::XmlError> for ParseFeedError {
     fn from(err: xml::XmlError) -> Self {
-        ParseFeedError::XmlReader(err)
+        match err {
+            xml::XmlError::LimitExceeded { limit } => ParseFeedError::ParseError(ParseErrorKind::LimitExceeded(limit), None),
+            err => ParseFeedError::XmlReader(err, None),
+        }
     }
 }
 
 impl fmt::Display for ParseFeedError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            ParseFeedError::ParseError(pe) => write!(f, "unable to parse feed: {}", pe),
+            ParseFeedError::ParseError(pe, Some(position)) => write!(f, "unable to parse feed: {} at {}", pe, position),
+            ParseFeedError::ParseError(pe, None) => write!(f, "unable to parse feed: {}", pe),
             ParseFeedError::IoError(ie) => write!(f, "unable to read feed: {}", ie),
             ParseFeedError::JsonSerde(je) => write!(f, "unable to parse JSON: {}", je),
             ParseFeedError::JsonUnsupportedVersion(version) => write!(f, "unsupported version: {}", version),
-            ParseFeedError::XmlReader(xe) => write!(f, "unable to parse XML: {}", xe),
+            ParseFeedError::XmlReader(xe, Some(position)) => write!(f, "unable to parse XML: {} at {}", xe, position),
+            ParseFeedError::XmlReader(xe, None) => write!(f, "unable to parse XML: {}", xe),
         }
     }
 }
@@ -74,12 +108,79 @@ impl Error for ParseFeedError {
         match self {
             ParseFeedError::IoError(ie) => Some(ie),
             ParseFeedError::JsonSerde(je) => Some(je),
-            ParseFeedError::XmlReader(xe) => Some(xe),
+            ParseFeedError::XmlReader(xe, _) => Some(xe),
             _ => None,
         }
     }
 }
 
+/// Location within XML content at which parsing failed
+#[derive(Clone, Debug, PartialEq)]
+pub struct Position {
+    /// Offset from the start of the content, in bytes
+    pub offset: u64,
+    /// Line number, starting at 1
+    pub line: u64,
+    /// Column within the line, in bytes and starting at 1
+    pub column: u64,
+    /// Path to the element being parsed (e.g. "/feed/entry[17]/content"), with the position amongst siblings of the same name when repeated
+    pub path: String,
+}
+
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {} (byte {}) in {}", self.line, self.column, self.offset, self.path)
+    }
+}
+
+/// Content that could not be interpreted, and was ignored when parsing the feed
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseWarning {
+    /// The nature of the problem
+    pub kind: WarningKind,
+    /// Path to the element (with "/@name" for an attribute) or JSON member (as a JSON Pointer) containing the value
+    pub path: String,
+    /// The value as found in the source
+    pub value: String,
+}
+
+impl fmt::Display for ParseWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} \"{}\" in {}", self.kind, self.value, self.path)
+    }
+}
+
+/// Categories of content that could not be interpreted
+#[derive(Clone, Debug, PartialEq)]
+pub enum WarningKind {
+    /// A timestamp that is not in any of the recognised formats
+    InvalidTimestamp,
+    /// A number that could not be parsed, or is outside the range allowed by the specification
+    InvalidNumber,
+    /// A MIME type that could not be parsed
+    UnknownMimeType,
+    /// An entry that was skipped as it could not be parsed (in lenient mode), with the error as the value
+    /// (or for JSON Feed, an item that is not an object, with the item as the value)
+    SkippedEntry,
+    /// A value that was missing or of an unexpected type, and was converted or dropped (e.g. a numeric JSON Feed item ID)
+    CoercedValue,
+    /// XML that ended prematurely when recovering (e.g. a truncated download), closing the open elements, with the cause as the value
+    TruncatedContent,
+}
+
+impl fmt::Display for WarningKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WarningKind::InvalidTimestamp => f.write_str("invalid timestamp"),
+            WarningKind::InvalidNumber => f.write_str("invalid number"),
+            WarningKind::UnknownMimeType => f.write_str("unknown MIME type"),
+            WarningKind::SkippedEntry => f.write_str("skipped entry"),
+            WarningKind::CoercedValue => f.write_str("coerced value"),
+            WarningKind::TruncatedContent => f.write_str("truncated content"),
+        }
+    }
+}
+
 /// Underlying cause of the parse failure
 #[derive(Debug)]
 pub enum ParseErrorKind {
@@ -89,6 +190,8 @@ pub enum ParseErrorKind {
     UnknownMimeType(String),
     /// Required content within the source was not found e.g. the XML child text element for a "content" element
     MissingContent(&'static str),
+    /// The source exceeds one of the limits configured on the parser
+    LimitExceeded(Limit),
 }
 
 impl fmt::Display for ParseErrorKind {
@@ -97,6 +200,7 @@ impl fmt::Display for ParseErrorKind {
             ParseErrorKind::NoFeedRoot => f.write_str("no root element"),
             ParseErrorKind::UnknownMimeType(mime) => write!(f, "unsupported content type {}", mime),
             ParseErrorKind::MissingContent(elem) => write!(f, "missing content element {}", elem),
+            ParseErrorKind::LimitExceeded(limit) => write!(f, "{} exceeded", limit),
         }
     }
 }
@@ -104,7 +208,15 @@ impl fmt::Display for ParseErrorKind {
 /// Parser for various feed formats
 pub struct Parser {
     base_uri: Option<String>,
+    entry_error_handler: Option<Box<EntryErrorHandler>>,
+    html_entities: bool,
     id_generator: Box<IdGenerator>,
+    inherit_media: bool,
+    lenient: bool,
+    limits: Limits,
+    normalize_names: bool,
+    prefixes: HashMap<String, String>,
+    recover_xml: bool,
     sanitize_content: bool,
     timestamp_parser: Box<TimestampParser>,
 }
@@ -141,33 +253,205 @@ impl Parser {
     ///
     /// ```
     pub fn parse<R: Read>(&self, source: R) -> ParseFeedResult<model::Feed> {
+        self.parse_source(source, false).map(|(feed, _)| feed)
+    }
+
+    /// Parse the input into our model, reporting content that could not be interpreted
+    ///
+    /// The parser silently ignores invalid content (e.g. timestamps in an unrecognised format, or a non-numeric "ttl") so the rest of the feed remains usable.
+    /// This reports each such value, with the path to its location in the source, which is useful to diagnose problems with a feed.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - A source of content such as a string, file etc.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed_rs::parser::{self, WarningKind};
+    /// let xml = r#"
+    /// <rss version="2.0">
+    ///   <channel>
+    ///     <title>RSS Title</title>
+    ///     <ttl>an hour</ttl>
+    ///   </channel>
+    /// </rss>
+    /// "#;
+    /// let parser = parser::Builder::new().build();
+    /// let (feed, warnings) = parser.parse_with_report(xml.as_bytes()).unwrap();
+    /// assert!(feed.ttl.is_none());
+    /// assert_eq!(warnings[0].kind, WarningKind::InvalidNumber);
+    /// assert_eq!(warnings[0].path, "/rss/channel/ttl");
+    /// assert_eq!(warnings[0].value, "an hour");
+    /// ```
+    pub fn parse_with_report<R: Read>(&self, source: R) -> ParseFeedResult<(model::Feed, Vec<ParseWarning>)> {
+        self.parse_source(source, true)
+    }
+
+    /// Parse the input asynchronously (Atom, a flavour of RSS or JSON Feed) into our model
+    ///
+    /// XML content is tokenised as it arrives from the source, so the executor is not blocked while waiting for the remainder of the feed.
+    /// Once the source is exhausted, the feed is parsed in the same manner as `parse`.
+    ///
+    /// The whole document is held in memory before parsing begins (as the tokenised events of XML, or the bytes of JSON),
+    /// so memory use grows with the size of the feed rather than that of a single entry. Configure `Builder::max_bytes` to bound it,
+    /// or read large feeds on a blocking thread with `parse_streaming`.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - An asynchronous source of content such as a file, HTTP response body etc.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed_rs::parser;
+    /// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+    /// let xml = r#"
+    /// <feed>
+    ///    <title type="text">sample feed</title>
+    ///    <updated>2005-07-31T12:29:29Z</updated>
+    ///    <id>feed1</id>
+    ///    <entry>
+    ///        <title>sample entry</title>
+    ///        <id>entry1</id>
+    ///    </entry>
+    /// </feed>
+    /// "#;
+    /// let parser = parser::Builder::new().build();
+    /// let feed = parser.parse_async(xml.as_bytes()).await.unwrap();
+    /// assert_eq!(feed.id, "feed1");
+    /// # });
+    /// ```
+    #[cfg(feature = "async")]
+    pub async fn parse_async<R: AsyncBufRead + Unpin>(&self, mut source: R) -> ParseFeedResult<model::Feed> {
+        // Peek to determine the type of content
+        let first = root_char(source.fill_buf().await?, self.recover_xml);
+        let (mut source, size_check) = SizeLimited::new(source, self.limits.max_bytes);
+
+        let result = match first {
+            Some('<') => match xml::ElementSource::from_async_reader(source, self.base_uri.as_deref(), self.xml_config()).await {
+                Ok(element_source) => self.parse_elements(element_source, false),
+                Err(e) => Err(e.into()),
+            },
+
+            Some('{') => {
+                // JSON is parsed as a whole, so is read before handing off to the parser
+                let mut buffer = Vec::new();
+                match source.read_to_end(&mut buffer).await {
+                    Ok(_) => self.parse_json(buffer.as_slice(), false),
+                    Err(e) => Err(e.into()),
+                }
+            }
+
+            _ => Err(ParseFeedError::ParseError(ParseErrorKind::NoFeedRoot, None)),
+        };
+
+        // Post processing as required
+        self.post_process(size_check.apply(result)).map(|(feed, _)| feed)
+    }
+
+    /// Parse the input incrementally, returning a stream of entries rather than a complete feed
+    ///
+    /// This is suited to large feeds (e.g. archives or podcasts with an extensive back catalogue) where holding every entry in memory is undesirable.
+    /// The feed level fields are available from the stream as soon as it is created, with each entry parsed on demand as the stream is iterated.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - A source of content such as a string, file etc.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed_rs::parser;
+    /// let xml = r#"
+    /// <feed xmlns="http://www.w3.org/2005/Atom">
+    ///    <title type="text">sample feed</title>
+    ///    <id>feed1</id>
+    ///    <entry>
+    ///        <title>sample entry</title>
+    ///        <id>entry1</id>
+    ///    </entry>
+    /// </feed>
+    /// "#;
+    /// let parser = parser::Builder::new().build();
+    /// let stream = parser.parse_streaming(xml.as_bytes()).unwrap();
+    /// assert_eq!(stream.feed().id, "feed1");
+    ///
+    /// for entry in stream {
+    ///     let entry = entry.unwrap();
+    ///     assert_eq!(entry.id, "entry1");
+    /// }
+    /// ```
+    pub fn parse_streaming<R: Read>(&self, source: R) -> ParseFeedResult<FeedStream<'_, R>> {
+        let mut input = BufReader::new(source);
+        let first = first_char(&mut input, self.recover_xml)?;
+        let input = SizeLimited::new(input, self.limits.max_bytes);
+
+        match first {
+            Some('<') => FeedStream::xml(self, input),
+
+            Some('{') => FeedStream::json(self, input),
+
+            _ => Err(ParseFeedError::ParseError(ParseErrorKind::NoFeedRoot, None)),
+        }
+    }
+
+    // Parses the input, collecting the warnings if requested
+    fn parse_source<R: Read>(&self, source: R, report: bool) -> ParseFeedResult<(model::Feed, Vec<ParseWarning>)> {
         // Buffer the reader for performance (e.g. when streaming from a network) and so we can peek to determine the type of content
         let mut input = BufReader::new(source);
+        let first = first_char(&mut input, self.recover_xml)?;
+        let (input, size_check) = SizeLimited::new(input, self.limits.max_bytes);
 
         // Determine whether this is XML or JSON and call the appropriate parser
-        input.fill_buf()?;
-        let first_char = input.buffer().iter().find(|b| **b == b'<' || **b == b'{').map(|b| *b as char);
-        let result = match first_char {
-            Some('<') => self.parse_xml(input),
+        let result = match first {
+            Some('<') => self.parse_xml(input, report),
 
-            Some('{') => self.parse_json(input),
+            Some('{') => self.parse_json(input, report),
 
-            _ => Err(ParseFeedError::ParseError(ParseErrorKind::NoFeedRoot)),
+            _ => Err(ParseFeedError::ParseError(ParseErrorKind::NoFeedRoot, None)),
         };
 
         // Post processing as required
-        if let Ok(mut feed) = result {
-            assign_missing_ids(&self.id_generator, &mut feed, self.base_uri.as_deref());
+        self.post_process(size_check.apply(result))
+    }
+
+    // Handles JSON content
+    fn parse_json<R: BufRead>(&self, source: R, report: bool) -> ParseFeedResult<(model::Feed, Vec<ParseWarning>)> {
+        let context = json::Context::new(self, report);
+        json::parse(&context, source).map(|feed| (feed, context.take_warnings()))
+    }
 
-            Ok(feed)
-        } else {
-            result
+    // Parses an entry with the supplied handler
+    // In lenient mode a malformed entry is skipped (reporting the error to the registered handler) rather than failing the whole feed
+    pub(crate) fn isolate_entry<'a, R, F>(&self, element: Element<'a, R>, handler: F) -> ParseFeedResult<Option<model::Entry>>
+    where
+        R: BufRead,
+        F: FnOnce(&Parser, Element<'a, R>) -> ParseFeedResult<Option<model::Entry>>,
+    {
+        let source = element.source();
+
+        // Fail before parsing an entry beyond the limit
+        self.check_entries(source.count_entry())?;
+
+        match handler(self, element) {
+            // Errors in the XML itself (or exceeding a limit) cannot be isolated to the entry
+            Err(e @ ParseFeedError::ParseError(ParseErrorKind::LimitExceeded(_), _)) => Err(e),
+            Err(e @ ParseFeedError::ParseError(..)) if self.lenient => {
+                let e = e.at(|| source.position());
+                source.warn(WarningKind::SkippedEntry, None, &e.to_string());
+                if let Some(entry_error_handler) = &self.entry_error_handler {
+                    entry_error_handler(&e);
+                }
+                Ok(None)
+            }
+            result => result,
         }
     }
 
-    // Handles JSON content
-    fn parse_json<R: BufRead>(&self, source: R) -> ParseFeedResult<model::Feed> {
-        json::parse(self, source)
+    // Checks the number of entries is within the limit
+    pub(crate) fn check_entries(&self, count: usize) -> ParseFeedResult<()> {
+        Limits::check(self.limits.max_entries, count, Limit::Entries).map_err(|limit| ParseFeedError::ParseError(ParseErrorKind::LimitExceeded(limit), None))
     }
 
     // Parses timestamps with the configured parser (internal, or supplied via the builder)
@@ -176,9 +460,36 @@ impl Parser {
     }
 
     // Handles XML content
-    fn parse_xml<R: BufRead>(&self, source: R) -> ParseFeedResult<model::Feed> {
+    fn parse_xml<R: BufRead>(&self, source: R, report: bool) -> ParseFeedResult<(model::Feed, Vec<ParseWarning>)> {
         // Set up the source of XML elements from the input
-        let element_source = xml::ElementSource::new(source, self.base_uri.as_deref())?;
+        let element_source = xml::ElementSource::with_config(source, self.base_uri.as_deref(), self.xml_config())?;
+        self.parse_elements(element_source, report)
+    }
+
+    // Options for interpreting XML content
+    pub(crate) fn xml_config(&self) -> xml::XmlConfig {
+        xml::XmlConfig {
+            html_entities: self.html_entities,
+            limits: self.limits,
+            normalize_names: self.normalize_names,
+            prefixes: self.prefixes.clone(),
+            recover: self.recover_xml,
+        }
+    }
+
+    // Parses the elements, recording the position of any failure and collecting the warnings if requested
+    fn parse_elements<R: BufRead>(&self, element_source: xml::ElementSource<R>, report: bool) -> ParseFeedResult<(model::Feed, Vec<ParseWarning>)> {
+        if report {
+            element_source.collect_warnings();
+        }
+
+        let result = self.dispatch_xml(&element_source).map_err(|e| e.at(|| element_source.position()));
+
+        result.map(|feed| (feed, element_source.take_warnings()))
+    }
+
+    // Dispatches to the parser for the format indicated by the root element
+    fn dispatch_xml<R: BufRead>(&self, element_source: &xml::ElementSource<R>) -> ParseFeedResult<model::Feed> {
         if let Ok(Some(root)) = element_source.root() {
             // Dispatch to the correct parser
             let version = root.attr_value("version");
@@ -208,7 +519,19 @@ impl Parser {
         }
 
         // Couldn't find a recognised feed within the provided XML stream
-        Err(ParseFeedError::ParseError(ParseErrorKind::NoFeedRoot))
+        Err(ParseFeedError::ParseError(ParseErrorKind::NoFeedRoot, None))
+    }
+
+    // Assigns IDs to the feed and entries as required, and applies the channel level media to the entries if requested
+    fn post_process(&self, result: ParseFeedResult<(model::Feed, Vec<ParseWarning>)>) -> ParseFeedResult<(model::Feed, Vec<ParseWarning>)> {
+        let (mut feed, warnings) = result?;
+        assign_missing_ids(&self.id_generator, &mut feed, self.base_uri.as_deref());
+        if self.inherit_media {
+            for entry in &mut feed.entries {
+                mediarss::inherit_channel_media(feed.media.as_ref(), feed.rating.as_ref(), entry);
+            }
+        }
+        Ok((feed, warnings))
     }
 }
 
@@ -222,7 +545,15 @@ pub fn parse<R: Read>(source: R) -> ParseFeedResult<model::Feed> {
 /// Builder to create instances of `FeedParser`
 pub struct Builder {
     base_uri: Option<String>,
+    entry_error_handler: Option<Box<EntryErrorHandler>>,
+    html_entities: bool,
     id_generator: Box<IdGenerator>,
+    inherit_media: bool,
+    lenient: bool,
+    limits: Limits,
+    normalize_names: Option<bool>,
+    prefixes: HashMap<String, String>,
+    recover_xml: bool,
     sanitize_content: bool,
     timestamp_parser: Box<TimestampParser>,
 }
@@ -243,12 +574,58 @@ impl Builder {
     pub fn build(self) -> Parser {
         Parser {
             base_uri: self.base_uri,
+            entry_error_handler: self.entry_error_handler,
+            html_entities: self.html_entities,
             id_generator: self.id_generator,
+            inherit_media: self.inherit_media,
+            lenient: self.lenient,
+            limits: self.limits,
+            normalize_names: self.normalize_names.unwrap_or(self.lenient),
+            prefixes: self.prefixes,
+            recover_xml: self.recover_xml,
             sanitize_content: self.sanitize_content,
             timestamp_parser: self.timestamp_parser,
         }
     }
 
+    /// Registers a handler for the errors encountered in entries that are skipped when parsing leniently (see `lenient`)
+    ///
+    /// The errors include the position of the entry within the source.
+    pub fn entry_error_handler<F>(mut self, handler: F) -> Self
+    where
+        F: Fn(&ParseFeedError) + 'static,
+    {
+        self.entry_error_handler = Some(Box::new(handler));
+        self
+    }
+
+    /// Registers the flag for resolving the named entities defined by HTML (e.g. `&nbsp;` or `&eacute;`) in XML content (enabled by default)
+    ///
+    /// Such entities are not defined by XML, but are common in feeds produced from HTML templates.
+    /// When disabled, only the entities predefined by XML are resolved and others are retained as literal text.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed_rs::parser;
+    /// let xml = r#"
+    /// <rss version="2.0"><channel>
+    ///    <title>caf&eacute;&nbsp;&mdash;&nbsp;menu</title>
+    /// </channel></rss>
+    /// "#;
+    ///
+    /// let feed = parser::parse(xml.as_bytes()).unwrap();
+    /// assert_eq!(feed.title.unwrap().content, "caf\u{e9}\u{a0}\u{2014}\u{a0}menu");
+    ///
+    /// let parser = parser::Builder::new().html_entities(false).build();
+    /// let feed = parser.parse(xml.as_bytes()).unwrap();
+    /// assert_eq!(feed.title.unwrap().content, "caf&eacute;&nbsp;&mdash;&nbsp;menu");
+    /// ```
+    pub fn html_entities(mut self, flag: bool) -> Self {
+        self.html_entities = flag;
+        self
+    }
+
     /// Registers an ID generator
     pub fn id_generator<F>(mut self, generator: F) -> Self
     where
@@ -276,6 +653,218 @@ impl Builder {
         })
     }
 
+    /// Registers the flag for applying the Media RSS elements at the channel level to the media of each entry (disabled by default)
+    ///
+    /// The Media RSS spec states that elements at the channel level apply to every media object within the feed.
+    /// When enabled, the media of each entry inherits the channel thumbnails, credits, keywords, categories, licenses, restrictions, prices, community, status and copyright
+    /// where it has none of its own, and the rating of the feed is applied to content without a rating.
+    /// Entries without any media receive a media object holding the inherited values.
+    ///
+    /// The channel level elements are always available from `Feed::media`.
+    /// When streaming, only the channel level elements preceding an entry are applied to it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed_rs::parser;
+    /// let xml = r#"
+    /// <rss version="2.0" xmlns:media="http://search.yahoo.com/mrss/"><channel>
+    ///    <media:thumbnail url="https://example.com/show.jpg"/>
+    ///    <media:copyright>2024 Example</media:copyright>
+    ///    <item>
+    ///        <title>episode 1</title>
+    ///        <enclosure url="https://example.com/1.mp3" type="audio/mpeg" length="1234"/>
+    ///    </item>
+    ///    <item>
+    ///        <title>episode 2</title>
+    ///        <media:thumbnail url="https://example.com/2.jpg"/>
+    ///    </item>
+    /// </channel></rss>
+    /// "#;
+    ///
+    /// let feed = parser::parse(xml.as_bytes()).unwrap();
+    /// assert_eq!(feed.media.unwrap().thumbnails[0].image.uri, "https://example.com/show.jpg");
+    /// assert!(feed.entries[0].media[0].thumbnails.is_empty());
+    ///
+    /// let parser = parser::Builder::new().inherit_media(true).build();
+    /// let feed = parser.parse(xml.as_bytes()).unwrap();
+    /// let media = &feed.entries[0].media[0];
+    /// assert_eq!(media.thumbnails[0].image.uri, "https://example.com/show.jpg");
+    /// assert_eq!(media.copyright.as_ref().unwrap().notice, "2024 Example");
+    /// assert_eq!(feed.entries[1].media[0].thumbnails[0].image.uri, "https://example.com/2.jpg");
+    /// ```
+    pub fn inherit_media(mut self, flag: bool) -> Self {
+        self.inherit_media = flag;
+        self
+    }
+
+    /// Registers the flag for lenient parsing, where a malformed entry is skipped rather than failing the whole feed
+    ///
+    /// Errors in the XML itself (e.g. an unclosed tag) still fail the feed, as the content following the error cannot be relied upon (see `recover_xml`).
+    /// Skipped entries may be recorded with an `entry_error_handler`.
+    /// Element names are also matched regardless of case, unless disabled with `normalize_names`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cell::RefCell;
+    /// use std::rc::Rc;
+    /// use feed_rs::parser;
+    /// let xml = r#"
+    /// <feed xmlns="http://www.w3.org/2005/Atom">
+    ///    <entry><id>entry1</id></entry>
+    ///    <entry><id>entry2</id><content type="unknown">body</content></entry>
+    /// </feed>
+    /// "#;
+    ///
+    /// let errors = Rc::new(RefCell::new(Vec::new()));
+    /// let recorded = errors.clone();
+    /// let parser = parser::Builder::new()
+    ///     .lenient(true)
+    ///     .entry_error_handler(move |e| recorded.borrow_mut().push(e.to_string()))
+    ///     .build();
+    ///
+    /// let feed = parser.parse(xml.as_bytes()).unwrap();
+    /// assert_eq!(feed.entries.len(), 1);
+    /// assert_eq!(errors.borrow().len(), 1);
+    /// ```
+    pub fn lenient(mut self, flag: bool) -> Self {
+        self.lenient = flag;
+        self
+    }
+
+    /// Maximum number of attributes on an XML element (unlimited by default)
+    ///
+    /// This and the other limits guard against pathological input when parsing feeds from untrusted sources.
+    /// Parsing fails with `ParseErrorKind::LimitExceeded` as soon as a limit is exceeded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed_rs::parser::{self, Limit, ParseErrorKind, ParseFeedError};
+    /// let xml = "<rss version=\"2.0\"><channel><item/><item/><item/></channel></rss>";
+    ///
+    /// let parser = parser::Builder::new().max_attributes(16).max_bytes(1 << 20).max_depth(32).max_entries(2).build();
+    /// let error = parser.parse(xml.as_bytes()).unwrap_err();
+    /// assert!(matches!(error, ParseFeedError::ParseError(ParseErrorKind::LimitExceeded(Limit::Entries), _)));
+    /// ```
+    pub fn max_attributes(mut self, count: usize) -> Self {
+        self.limits.max_attributes = Some(count);
+        self
+    }
+
+    /// Maximum size of the source in bytes (unlimited by default)
+    pub fn max_bytes(mut self, count: u64) -> Self {
+        self.limits.max_bytes = Some(count);
+        self
+    }
+
+    /// Maximum depth of nested XML elements, with the root element at depth 1 (unlimited by default)
+    pub fn max_depth(mut self, depth: usize) -> Self {
+        self.limits.max_depth = Some(depth);
+        self
+    }
+
+    /// Maximum number of entries in the feed (unlimited by default)
+    pub fn max_entries(mut self, count: usize) -> Self {
+        self.limits.max_entries = Some(count);
+        self
+    }
+
+    /// Maximum length in bytes of a text node in XML content (unlimited by default)
+    pub fn max_text_length(mut self, length: usize) -> Self {
+        self.limits.max_text_length = Some(length);
+        self
+    }
+
+    /// Registers the flag for matching the names of elements regardless of case (enabled by default when parsing leniently)
+    ///
+    /// Element names in XML are case-sensitive, but variations such as `<pubdate>` or `<Description>` are common in RSS feeds.
+    /// Only the elements of the supported namespaces are matched in this manner.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed_rs::parser;
+    /// let xml = r#"
+    /// <rss version="2.0"><channel>
+    ///    <Title>feed</Title>
+    ///    <item><title>entry</title><pubdate>Wed, 22 Jan 2020 10:58:02 GMT</pubdate></item>
+    /// </channel></rss>
+    /// "#;
+    ///
+    /// let feed = parser::parse(xml.as_bytes()).unwrap();
+    /// assert!(feed.title.is_none());
+    ///
+    /// let parser = parser::Builder::new().normalize_names(true).build();
+    /// let feed = parser.parse(xml.as_bytes()).unwrap();
+    /// assert_eq!(feed.title.unwrap().content, "feed");
+    /// assert!(feed.entries[0].published.is_some());
+    /// ```
+    pub fn normalize_names(mut self, flag: bool) -> Self {
+        self.normalize_names = Some(flag);
+        self
+    }
+
+    /// Registers the namespace for a prefix that feeds use without declaring it
+    ///
+    /// The prefixes conventionally used by the supported namespaces (e.g. `media`, `itunes`, `podcast`, `dc`, `content` and `atom`) are recognised without being registered.
+    /// Prefixes declared by the feed itself take precedence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed_rs::parser;
+    /// let xml = r#"
+    /// <rss version="2.0"><channel>
+    ///    <item>
+    ///        <title>episode</title>
+    ///        <dc:creator>Alice</dc:creator>
+    ///        <yahoo:thumbnail url="https://example.com/thumbnail.jpg"/>
+    ///    </item>
+    /// </channel></rss>
+    /// "#;
+    ///
+    /// let parser = parser::Builder::new().prefix_alias("yahoo", "http://search.yahoo.com/mrss/").build();
+    /// let feed = parser.parse(xml.as_bytes()).unwrap();
+    /// let entry = &feed.entries[0];
+    /// assert_eq!(entry.authors[0].name, "Alice");
+    /// assert_eq!(entry.media[0].thumbnails[0].image.uri, "https://example.com/thumbnail.jpg");
+    /// ```
+    pub fn prefix_alias<S: AsRef<str>>(mut self, prefix: S, namespace_uri: S) -> Self {
+        self.prefixes.insert(prefix.as_ref().to_string(), namespace_uri.as_ref().to_string());
+        self
+    }
+
+    /// Registers the flag for recovering from malformed XML, returning as much of the feed as can be read rather than failing (disabled by default)
+    ///
+    /// When recovering:
+    /// * content preceding the XML declaration or root element (e.g. warnings printed by the server) is skipped
+    /// * bare ampersands (e.g. in unescaped URLs) are retained as text
+    /// * elements left open (e.g. `<br>` in a description) are closed by the end tag of their parent, and unmatched end tags are dropped
+    /// * content ending prematurely (e.g. a truncated download) closes any open elements, which is reported as a `WarningKind::TruncatedContent` warning
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed_rs::parser;
+    /// let xml = r#"<rss version="2.0"><channel>
+    ///    <title>Fish & Chips</title>
+    ///    <item><title>first</title><description>line<br>line</description></item>
+    ///    <item><title>second"#;
+    ///
+    /// assert!(parser::parse(xml.as_bytes()).is_err());
+    ///
+    /// let parser = parser::Builder::new().recover_xml(true).build();
+    /// let feed = parser.parse(xml.as_bytes()).unwrap();
+    /// assert_eq!(feed.title.unwrap().content, "Fish & Chips");
+    /// assert_eq!(feed.entries.len(), 2);
+    /// ```
+    pub fn recover_xml(mut self, flag: bool) -> Self {
+        self.recover_xml = flag;
+        self
+    }
+
     /// Registers the flag for sanitizing content when the "sanitize" feature
     /// is available
     pub fn sanitize_content(mut self, flag: bool) -> Self {
@@ -298,13 +887,50 @@ impl Default for Builder {
     fn default() -> Self {
         Builder {
             base_uri: None,
+            entry_error_handler: None,
+            html_entities: true,
             id_generator: Box::new(generate_id),
+            inherit_media: false,
+            lenient: false,
+            limits: Limits::default(),
+            normalize_names: None,
+            prefixes: HashMap::new(),
+            recover_xml: false,
             sanitize_content: true,
             timestamp_parser: Box::new(util::parse_timestamp_lenient),
         }
     }
 }
 
+// Finds the first character of the content so we can determine whether it is XML or JSON
+pub(crate) fn first_char<R: Read>(input: &mut BufReader<R>, recover: bool) -> ParseFeedResult<Option<char>> {
+    Ok(root_char(input.fill_buf()?, recover))
+}
+
+// Finds the character starting the document in the buffer
+// When recovering, content preceding it (e.g. warnings printed by the server) that may contain either character is skipped,
+// with the first of either character used where there is no plausible start of an XML document or JSON object
+fn root_char(buf: &[u8], recover: bool) -> Option<char> {
+    let first = || buf.iter().position(|b| *b == b'<' || *b == b'{');
+    if !recover {
+        return first().map(|i| buf[i] as char);
+    }
+
+    let is_object = |i: usize| buf[i] == b'{' && buf[i + 1..].iter().find(|b| !b.is_ascii_whitespace()).is_none_or(|b| *b == b'"' || *b == b'}');
+
+    let content = buf.iter().position(|b| !b.is_ascii_whitespace() && !b"\xEF\xBB\xBF".contains(b));
+    if let Some(start) = content {
+        if buf[start..].starts_with(b"<?") || buf[start..].starts_with(b"<!") {
+            return Some('<');
+        }
+    }
+
+    (0..buf.len())
+        .find(|i| xml::is_root(&buf[*i..]) != Some(false) || is_object(*i))
+        .or_else(first)
+        .map(|i| buf[i] as char)
+}
+
 // Assigns IDs to missing feed + entries as required
 fn assign_missing_ids(id_generator: &IdGenerator, feed: &mut model::Feed, uri: Option<&str>) {
     if feed.id.is_empty() {
@@ -0,0 +1,281 @@
+use std::io::BufRead;
+use std::time::Duration;
+
+use crate::model::{
+    Entry, Feed, MediaObject, PodcastAlternateEnclosure, PodcastChannel, PodcastChapters, PodcastEpisode, PodcastFunding, PodcastIntegrity, PodcastItem,
+    PodcastLocked, PodcastMedia, PodcastPerson, PodcastSeason, PodcastSoundbite, PodcastSource, PodcastTranscript, PodcastValue, PodcastValueRecipient,
+};
+use crate::parser::util::{self, if_some_then};
+use crate::parser::{ParseFeedResult, WarningKind};
+use crate::xml::{Element, NS};
+
+// Process <podcast> elements at channel level updating the Feed object as required
+pub(crate) fn handle_podcast_channel_element<R: BufRead>(element: Element<R>, feed: &mut Feed) -> ParseFeedResult<()> {
+    match element.ns_and_tag() {
+        (NS::Podcast, "guid") => if_some_then(element.child_as_text(), |guid| podcast_channel(feed).guid = Some(guid)),
+
+        (NS::Podcast, "locked") => if_some_then(handle_locked(element), |locked| podcast_channel(feed).locked = Some(locked)),
+
+        (NS::Podcast, "funding") => if_some_then(handle_funding(element), |funding| podcast_channel(feed).funding.push(funding)),
+
+        (NS::Podcast, "person") => if_some_then(handle_person(element), |person| podcast_channel(feed).persons.push(person)),
+
+        (NS::Podcast, "value") => if_some_then(handle_value(element)?, |value| podcast_channel(feed).value.push(value)),
+
+        // Nothing required for unknown elements
+        _ => {}
+    }
+
+    Ok(())
+}
+
+// Process <podcast> elements at item level
+//
+// Elements describing the media of the item are added to the default MediaObject, the remainder to the entry
+pub(crate) fn handle_podcast_item_element<R: BufRead>(element: Element<R>, entry: &mut Entry, media_obj: &mut MediaObject) -> ParseFeedResult<()> {
+    match element.ns_and_tag() {
+        (NS::Podcast, "season") => if_some_then(handle_season(element), |season| podcast_item(entry).season = Some(season)),
+
+        (NS::Podcast, "episode") => if_some_then(handle_episode(element), |episode| podcast_item(entry).episode = Some(episode)),
+
+        (NS::Podcast, "person") => if_some_then(handle_person(element), |person| podcast_item(entry).persons.push(person)),
+
+        (NS::Podcast, "value") => if_some_then(handle_value(element)?, |value| podcast_item(entry).value.push(value)),
+
+        (NS::Podcast, "transcript") => if_some_then(handle_transcript(element), |transcript| podcast_media(media_obj).transcripts.push(transcript)),
+
+        (NS::Podcast, "chapters") => if_some_then(handle_chapters(element), |chapters| podcast_media(media_obj).chapters = Some(chapters)),
+
+        (NS::Podcast, "soundbite") => if_some_then(handle_soundbite(element), |soundbite| podcast_media(media_obj).soundbites.push(soundbite)),
+
+        (NS::Podcast, "alternateEnclosure") => {
+            let enclosure = handle_alternate_enclosure(element)?;
+            podcast_media(media_obj).alternate_enclosures.push(enclosure);
+        }
+
+        // Nothing required for unknown elements
+        _ => {}
+    }
+
+    Ok(())
+}
+
+// Handles <podcast:alternateEnclosure>
+fn handle_alternate_enclosure<R: BufRead>(element: Element<R>) -> ParseFeedResult<PodcastAlternateEnclosure> {
+    let mut enclosure = PodcastAlternateEnclosure::default();
+
+    for attr in &element.attributes {
+        match attr.name.as_str() {
+            "type" => enclosure.content_type = util::parse_attr(&element, WarningKind::UnknownMimeType, attr),
+            "length" => enclosure.length = util::parse_attr(&element, WarningKind::InvalidNumber, attr),
+            "bitrate" => enclosure.bitrate = util::parse_attr(&element, WarningKind::InvalidNumber, attr),
+            "height" => enclosure.height = util::parse_attr(&element, WarningKind::InvalidNumber, attr),
+            "lang" => enclosure.language = Some(attr.value.clone()),
+            "title" => enclosure.title = Some(attr.value.clone()),
+            "rel" => enclosure.rel = Some(attr.value.clone()),
+            "codecs" => enclosure.codecs = Some(attr.value.clone()),
+            "default" => enclosure.default = parse_bool(&attr.value),
+
+            // Nothing required for unknown attributes
+            _ => {}
+        }
+    }
+
+    for child in element.children() {
+        let child = child?;
+        match child.ns_and_tag() {
+            (NS::Podcast, "source") => if_some_then(child.attr_value("uri"), |uri| {
+                let content_type = child.attributes.iter().find(|attr| attr.name == "contentType");
+                let content_type = content_type.and_then(|attr| util::parse_attr(&child, WarningKind::UnknownMimeType, attr));
+                enclosure.sources.push(PodcastSource { uri, content_type })
+            }),
+
+            (NS::Podcast, "integrity") => {
+                if let (Some(integrity_type), Some(value)) = (child.attr_value("type"), child.attr_value("value")) {
+                    enclosure.integrity = Some(PodcastIntegrity { integrity_type, value })
+                }
+            }
+
+            // Nothing required for unknown elements
+            _ => {}
+        }
+    }
+
+    Ok(enclosure)
+}
+
+// Handles <podcast:chapters>
+fn handle_chapters<R: BufRead>(element: Element<R>) -> Option<PodcastChapters> {
+    let url = element.attr_value("url").and_then(|url| util::parse_uri(&url, element.xml_base.as_ref()))?;
+    let content_type = element.attributes.iter().find(|attr| attr.name == "type");
+    let content_type = content_type.and_then(|attr| util::parse_attr(&element, WarningKind::UnknownMimeType, attr));
+    Some(PodcastChapters { url, content_type })
+}
+
+// Handles <podcast:episode>
+fn handle_episode<R: BufRead>(element: Element<R>) -> Option<PodcastEpisode> {
+    let text = element.child_as_text()?;
+    let number = util::parse_text(&element, WarningKind::InvalidNumber, text.trim())?;
+    Some(PodcastEpisode {
+        number,
+        display: element.attr_value("display"),
+    })
+}
+
+// Handles <podcast:funding>
+fn handle_funding<R: BufRead>(element: Element<R>) -> Option<PodcastFunding> {
+    let url = element.attr_value("url").and_then(|url| util::parse_uri(&url, element.xml_base.as_ref()))?;
+    Some(PodcastFunding {
+        url,
+        label: element.child_as_text(),
+    })
+}
+
+// Handles <podcast:locked>, which contains "yes" or "no"
+fn handle_locked<R: BufRead>(element: Element<R>) -> Option<PodcastLocked> {
+    element.child_as_text().map(|text| PodcastLocked {
+        locked: text.trim().eq_ignore_ascii_case("yes"),
+        owner: element.attr_value("owner"),
+    })
+}
+
+// Handles <podcast:person>
+fn handle_person<R: BufRead>(element: Element<R>) -> Option<PodcastPerson> {
+    element.child_as_text().map(|name| PodcastPerson {
+        name,
+        role: element.attr_value("role"),
+        group: element.attr_value("group"),
+        img: element.attr_value("img").and_then(|img| util::parse_uri(&img, element.xml_base.as_ref())),
+        href: element.attr_value("href").and_then(|href| util::parse_uri(&href, element.xml_base.as_ref())),
+    })
+}
+
+// Handles <podcast:season>
+fn handle_season<R: BufRead>(element: Element<R>) -> Option<PodcastSeason> {
+    let text = element.child_as_text()?;
+    let number = util::parse_text(&element, WarningKind::InvalidNumber, text.trim())?;
+    Some(PodcastSeason {
+        number,
+        name: element.attr_value("name"),
+    })
+}
+
+// Handles <podcast:soundbite>, where the start time and duration are in seconds
+fn handle_soundbite<R: BufRead>(element: Element<R>) -> Option<PodcastSoundbite> {
+    let mut start_time = None;
+    let mut duration = None;
+    for attr in &element.attributes {
+        match attr.name.as_str() {
+            "startTime" => start_time = util::parse_attr(&element, WarningKind::InvalidNumber, attr).and_then(|secs| Duration::try_from_secs_f64(secs).ok()),
+            "duration" => duration = util::parse_attr(&element, WarningKind::InvalidNumber, attr).and_then(|secs| Duration::try_from_secs_f64(secs).ok()),
+
+            // Nothing required for unknown attributes
+            _ => {}
+        }
+    }
+
+    if let (Some(start_time), Some(duration)) = (start_time, duration) {
+        Some(PodcastSoundbite {
+            start_time,
+            duration,
+            title: element.child_as_text(),
+        })
+    } else {
+        None
+    }
+}
+
+// Handles <podcast:transcript>
+fn handle_transcript<R: BufRead>(element: Element<R>) -> Option<PodcastTranscript> {
+    let url = element.attr_value("url").and_then(|url| util::parse_uri(&url, element.xml_base.as_ref()))?;
+    let content_type = element.attributes.iter().find(|attr| attr.name == "type");
+    let content_type = content_type.and_then(|attr| util::parse_attr(&element, WarningKind::UnknownMimeType, attr));
+    Some(PodcastTranscript {
+        url,
+        content_type,
+        language: element.attr_value("language"),
+        rel: element.attr_value("rel"),
+    })
+}
+
+// Handles <podcast:value> and the contained <podcast:valueRecipient> elements
+fn handle_value<R: BufRead>(element: Element<R>) -> ParseFeedResult<Option<PodcastValue>> {
+    let (value_type, method) = match (element.attr_value("type"), element.attr_value("method")) {
+        (Some(value_type), Some(method)) => (value_type, method),
+        _ => return Ok(None),
+    };
+    let mut value = PodcastValue {
+        value_type,
+        method,
+        suggested: element.attr_value("suggested"),
+        recipients: Vec::new(),
+    };
+
+    for child in element.children() {
+        let child = child?;
+        if child.ns_and_tag() == (NS::Podcast, "valueRecipient") {
+            if_some_then(handle_value_recipient(child), |recipient| value.recipients.push(recipient));
+        }
+    }
+
+    Ok(Some(value))
+}
+
+// Handles <podcast:valueRecipient>
+fn handle_value_recipient<R: BufRead>(element: Element<R>) -> Option<PodcastValueRecipient> {
+    let mut name = None;
+    let mut custom_key = None;
+    let mut custom_value = None;
+    let mut recipient_type = None;
+    let mut address = None;
+    let mut split = None;
+    let mut fee = false;
+    for attr in &element.attributes {
+        match attr.name.as_str() {
+            "name" => name = Some(attr.value.clone()),
+            "customKey" => custom_key = Some(attr.value.clone()),
+            "customValue" => custom_value = Some(attr.value.clone()),
+            "type" => recipient_type = Some(attr.value.clone()),
+            "address" => address = Some(attr.value.clone()),
+            "split" => split = util::parse_attr(&element, WarningKind::InvalidNumber, attr),
+            "fee" => fee = parse_bool(&attr.value),
+
+            // Nothing required for unknown attributes
+            _ => {}
+        }
+    }
+
+    if let (Some(recipient_type), Some(address), Some(split)) = (recipient_type, address, split) {
+        Some(PodcastValueRecipient {
+            name,
+            custom_key,
+            custom_value,
+            recipient_type,
+            address,
+            split,
+            fee,
+        })
+    } else {
+        None
+    }
+}
+
+// Boolean attributes are "true" or "false"
+fn parse_bool(value: &str) -> bool {
+    value.trim().eq_ignore_ascii_case("true")
+}
+
+// Podcast fields of the feed, created on first use
+fn podcast_channel(feed: &mut Feed) -> &mut PodcastChannel {
+    feed.podcast.get_or_insert_with(Default::default)
+}
+
+// Podcast fields of the entry, created on first use
+fn podcast_item(entry: &mut Entry) -> &mut PodcastItem {
+    entry.podcast.get_or_insert_with(Default::default)
+}
+
+// Podcast fields of the media object, created on first use
+fn podcast_media(media_obj: &mut MediaObject) -> &mut PodcastMedia {
+    media_obj.podcast.get_or_insert_with(Default::default)
+}
@@ -15,20 +15,36 @@ pub(crate) fn parse<R: BufRead>(parser: &Parser, root: Element<R>) -> ParseFeedR
     for child in root.children() {
         let child = child?;
         match child.ns_and_tag() {
-            (NS::RSS, "channel") => handle_channel(parser, &mut feed, child)?,
+            (NS::RSS, "item") => if_some_then(parser.isolate_entry(child, handle_item)?, |entry| feed.entries.push(entry)),
 
-            (NS::RSS, "image") => feed.logo = handle_image(child)?,
-
-            (NS::RSS, "item") => if_some_then(handle_item(parser, child)?, |entry| feed.entries.push(entry)),
-
-            // Nothing required for unknown elements
-            _ => {}
+            _ => handle_root_element(parser, &mut feed, child)?,
         }
     }
 
+    if parser.sanitize_content {
+        util::sanitize_feed(&mut feed);
+    }
+
     Ok(feed)
 }
 
+// Handles the children of the root element other than the items
+pub(crate) fn handle_root_element<R: BufRead>(parser: &Parser, feed: &mut Feed, child: Element<R>) -> ParseFeedResult<()> {
+    match child.ns_and_tag() {
+        (NS::RSS, "channel") => handle_channel(parser, feed, child)?,
+
+        (NS::RSS, "image") => feed.logo = handle_image(child)?,
+
+        // Elements in unsupported namespaces are retained as extensions
+        (NS::Unknown, _) => util::handle_extension(child, &mut feed.extensions)?,
+
+        // Nothing required for other elements
+        _ => {}
+    }
+
+    Ok(())
+}
+
 // Handles the <channel> element
 fn handle_channel<R: BufRead>(parser: &Parser, feed: &mut Feed, channel: Element<R>) -> ParseFeedResult<()> {
     for child in channel.children() {
@@ -48,20 +64,11 @@ fn handle_channel<R: BufRead>(parser: &Parser, feed: &mut Feed, channel: Element
 
             (NS::DublinCore, "rights") => feed.rights = util::handle_text(child),
 
-            // Nothing required for unknown elements
-            _ => {}
-        }
-    }
+            // Elements in unsupported namespaces are retained as extensions
+            (NS::Unknown, _) => util::handle_extension(child, &mut feed.extensions)?,
 
-    if parser.sanitize_content {
-        if let Some(t) = feed.description.as_mut() {
-            t.sanitize()
-        }
-        if let Some(t) = feed.rights.as_mut() {
-            t.sanitize()
-        }
-        if let Some(t) = feed.title.as_mut() {
-            t.sanitize()
+            // Nothing required for other elements
+            _ => {}
         }
     }
 
@@ -91,7 +98,7 @@ fn handle_image<R: BufRead>(element: Element<R>) -> ParseFeedResult<Option<Image
 }
 
 // Handles <item>
-fn handle_item<R: BufRead>(parser: &Parser, element: Element<R>) -> ParseFeedResult<Option<Entry>> {
+pub(crate) fn handle_item<R: BufRead>(parser: &Parser, element: Element<R>) -> ParseFeedResult<Option<Entry>> {
     let mut entry = Entry::default();
 
     // Per https://www.w3.org/wiki/RssContent:
@@ -114,15 +121,14 @@ fn handle_item<R: BufRead>(parser: &Parser, element: Element<R>) -> ParseFeedRes
 
             (NS::DublinCore, "date") => entry.published = util::handle_timestamp(parser, child),
 
-            (NS::DublinCore, "description") => {
-                if entry.summary.is_none() {
-                    entry.summary = util::handle_text(child)
-                }
-            }
+            (NS::DublinCore, "description") if entry.summary.is_none() => entry.summary = util::handle_text(child),
 
             (NS::DublinCore, "rights") => entry.rights = util::handle_text(child),
 
-            // Nothing required for unknown elements
+            // Elements in unsupported namespaces are retained as extensions
+            (NS::Unknown, _) => util::handle_extension(child, &mut entry.extensions)?,
+
+            // Nothing required for other elements
             _ => {}
         }
     }
@@ -1,6 +1,6 @@
 use mediatype::{names, MediaType};
 
-use crate::model::{Entry, Feed, FeedType, Image, Link, Person, Text};
+use crate::model::{Entry, Extension, Feed, FeedType, Image, Link, Person, Text};
 use crate::parser;
 use crate::util::test;
 
@@ -124,6 +124,12 @@ fn test_spec_2() {
         .updated(actual.updated) // not present in the test data
         .author(Person::new("Rael Dornfest (mailto:rael@oreilly.com)"))
         .rights(Text::new("Copyright © 2000 O'Reilly & Associates, Inc.".into()))
+        .extension("http://purl.org/rss/1.0/modules/syndication/", Extension::new("updatePeriod").value("hourly"))
+        .extension("http://purl.org/rss/1.0/modules/syndication/", Extension::new("updateFrequency").value("2"))
+        .extension(
+            "http://purl.org/rss/1.0/modules/syndication/",
+            Extension::new("updateBase").value("2000-01-01T12:00+00:00"),
+        )
         .entry(
             Entry::default()
                 .id("acf7c86547d5d594af6d8f3327e84b06") // hash of the link
@@ -134,7 +140,10 @@ fn test_spec_2() {
                     "XML is placing increasingly heavy loads on the existing technical\n            infrastructure of the Internet.".into(),
                 ))
                 .author(Person::new("Simon St.Laurent (mailto:simonstl@simonstl.com)"))
-                .rights(Text::new("Copyright © 2000 O'Reilly & Associates, Inc.".into())),
+                .rights(Text::new("Copyright © 2000 O'Reilly & Associates, Inc.".into()))
+                .extension("http://purl.org/rss/1.0/modules/company/", Extension::new("name").value("XML.com"))
+                .extension("http://purl.org/rss/1.0/modules/company/", Extension::new("market").value("NASDAQ"))
+                .extension("http://purl.org/rss/1.0/modules/company/", Extension::new("symbol").value("XML")),
         );
 
     // Check
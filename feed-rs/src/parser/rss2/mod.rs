@@ -4,11 +4,12 @@ use mediatype::{names, MediaTypeBuf};
 
 use crate::model::{Category, Content, Entry, Feed, FeedType, Generator, Image, Link, MediaContent, MediaObject, Person};
 use crate::parser::itunes::{handle_itunes_channel_element, handle_itunes_item_element};
-use crate::parser::mediarss::handle_media_element;
-use crate::parser::util::{if_ok_then_some, if_some_then};
+use crate::parser::mediarss::{handle_media_channel_element, handle_media_element};
+use crate::parser::podcast::{handle_podcast_channel_element, handle_podcast_item_element};
+use crate::parser::util::if_some_then;
 use crate::parser::{atom, Parser};
 use crate::parser::{mediarss, util};
-use crate::parser::{ParseErrorKind, ParseFeedError, ParseFeedResult};
+use crate::parser::{ParseErrorKind, ParseFeedError, ParseFeedResult, WarningKind};
 use crate::xml::{Element, NS};
 
 #[cfg(test)]
@@ -24,7 +25,7 @@ pub(crate) fn parse<R: BufRead>(parser: &Parser, root: Element<R>) -> ParseFeedR
     if let Some(channel) = found_channel {
         handle_channel(parser, channel?)
     } else {
-        Err(ParseFeedError::ParseError(ParseErrorKind::NoFeedRoot))
+        Err(ParseFeedError::ParseError(ParseErrorKind::NoFeedRoot, None))
     }
 }
 
@@ -35,57 +36,69 @@ fn handle_channel<R: BufRead>(parser: &Parser, channel: Element<R>) -> ParseFeed
     for child in channel.children() {
         let child = child?;
         match child.ns_and_tag() {
-            (NS::RSS, "title") => feed.title = util::handle_text(child),
+            (NS::RSS, "item") => if_some_then(parser.isolate_entry(child, handle_item)?, |item| feed.entries.push(item)),
 
-            (NS::RSS, "link") => if_some_then(util::handle_link(child), |link| feed.links.push(link)),
+            _ => handle_channel_element(parser, &mut feed, child)?,
+        }
+    }
 
-            (NS::Atom, "link") => if_some_then(atom::handle_link(child), |link| feed.links.push(link)),
+    if parser.sanitize_content {
+        util::sanitize_feed(&mut feed);
+    }
 
-            (NS::RSS, "description") => feed.description = util::handle_text(child),
+    Ok(feed)
+}
 
-            (NS::RSS, "language") => feed.language = child.child_as_text().map(|text| text.to_lowercase()),
+// Handles the children of <channel> other than the items
+pub(crate) fn handle_channel_element<R: BufRead>(parser: &Parser, feed: &mut Feed, child: Element<R>) -> ParseFeedResult<()> {
+    match child.ns_and_tag() {
+        (NS::RSS, "title") => feed.title = util::handle_text(child),
 
-            (NS::RSS, "copyright") => feed.rights = util::handle_text(child),
+        (NS::RSS, "link") => if_some_then(util::handle_link(child), |link| feed.links.push(link)),
 
-            (NS::RSS, "managingEditor") => if_some_then(handle_contact("managingEditor", child), |person| feed.contributors.push(person)),
+        (NS::Atom, "link") => if_some_then(atom::handle_link(child), |link| feed.links.push(link)),
 
-            (NS::RSS, "webMaster") => if_some_then(handle_contact("webMaster", child), |person| feed.contributors.push(person)),
+        (NS::Atom, "contributor") => if_some_then(atom::handle_person(child)?, |person| feed.contributors.push(person)),
 
-            (NS::RSS, "pubDate") => feed.published = util::handle_timestamp(parser, child),
+        (NS::RSS, "description") => feed.description = util::handle_text(child),
 
-            // Some feeds have "updated" instead of "lastBuildDate"
-            (NS::RSS, "lastBuildDate") | (NS::RSS, "updated") => feed.updated = util::handle_timestamp(parser, child),
+        (NS::RSS, "language") => feed.language = child.child_as_text().map(|text| text.to_lowercase()),
 
-            (NS::RSS, "category") => if_some_then(handle_category(child), |category| feed.categories.push(category)),
+        (NS::RSS, "copyright") => feed.rights = util::handle_text(child),
 
-            (NS::RSS, "generator") => feed.generator = handle_generator(child),
+        (NS::RSS, "managingEditor") => if_some_then(handle_contact("managingEditor", child), |person| feed.contributors.push(person)),
 
-            (NS::RSS, "ttl") => if_some_then(child.child_as_text(), |text| if_ok_then_some(text.parse::<u32>(), |ttl| feed.ttl = ttl)),
+        (NS::RSS, "webMaster") => if_some_then(handle_contact("webMaster", child), |person| feed.contributors.push(person)),
 
-            (NS::RSS, "image") => feed.logo = handle_image(child)?,
+        (NS::RSS, "pubDate") => feed.published = util::handle_timestamp(parser, child),
 
-            (NS::RSS, "item") => if_some_then(handle_item(parser, child)?, |item| feed.entries.push(item)),
+        // Some feeds have "updated" instead of "lastBuildDate"
+        (NS::RSS, "lastBuildDate") | (NS::RSS, "updated") => feed.updated = util::handle_timestamp(parser, child),
 
-            (NS::Itunes, _) => handle_itunes_channel_element(child, &mut feed)?,
+        (NS::RSS, "category") => if_some_then(handle_category(child), |category| feed.categories.push(category)),
 
-            // Nothing required for unknown elements
-            _ => {}
-        }
-    }
+        (NS::RSS, "generator") => feed.generator = handle_generator(child),
 
-    if parser.sanitize_content {
-        if let Some(t) = feed.description.as_mut() {
-            t.sanitize()
-        }
-        if let Some(t) = feed.rights.as_mut() {
-            t.sanitize()
-        }
-        if let Some(t) = feed.title.as_mut() {
-            t.sanitize()
-        }
+        (NS::RSS, "ttl") => if_some_then(child.child_as_text(), |text| {
+            if_some_then(util::parse_text(&child, WarningKind::InvalidNumber, &text), |ttl| feed.ttl = Some(ttl))
+        }),
+
+        (NS::RSS, "image") => feed.logo = handle_image(child)?,
+
+        (NS::MediaRSS, _) => handle_media_channel_element(child, feed)?,
+
+        (NS::Itunes, _) => handle_itunes_channel_element(child, feed)?,
+
+        (NS::Podcast, _) => handle_podcast_channel_element(child, feed)?,
+
+        // Elements in unsupported namespaces are retained as extensions
+        (NS::Unknown, _) => util::handle_extension(child, &mut feed.extensions)?,
+
+        // Nothing required for other elements
+        _ => {}
     }
 
-    Ok(feed)
+    Ok(())
 }
 
 // Handles <category>
@@ -129,8 +142,10 @@ fn handle_enclosure<R: BufRead>(element: Element<R>, media_obj: &mut MediaObject
         let tag_name = attr.name.as_str();
         match tag_name {
             "url" => content.url = util::parse_uri(&attr.value, element.xml_base.as_ref()),
-            "length" => content.size = attr.value.parse::<u64>().ok(),
-            "type" => if_ok_then_some(attr.value.parse::<MediaTypeBuf>(), |mime| content.content_type = mime),
+            "length" => content.size = util::parse_attr(&element, WarningKind::InvalidNumber, attr),
+            "type" => if_some_then(util::parse_attr(&element, WarningKind::UnknownMimeType, attr), |mime| {
+                content.content_type = Some(mime)
+            }),
 
             // Nothing required for unknown elements
             _ => {}
@@ -157,17 +172,21 @@ fn handle_image<R: BufRead>(element: Element<R>) -> ParseFeedResult<Option<Image
             (NS::RSS, "link") => if_some_then(child.child_as_text(), |uri| image.link = Some(Link::new(uri, element.xml_base.as_ref()))),
 
             (NS::RSS, "width") => if_some_then(child.child_as_text(), |width| {
-                if let Ok(width) = width.parse::<u32>() {
-                    if width > 0 && width <= 144 {
-                        image.width = Some(width)
+                if let Some(value) = util::parse_text::<u32, _>(&child, WarningKind::InvalidNumber, &width) {
+                    if value > 0 && value <= 144 {
+                        image.width = Some(value)
+                    } else {
+                        child.warn(WarningKind::InvalidNumber, &width);
                     }
                 }
             }),
 
             (NS::RSS, "height") => if_some_then(child.child_as_text(), |height| {
-                if let Ok(height) = height.parse::<u32>() {
-                    if height > 0 && height <= 400 {
-                        image.height = Some(height)
+                if let Some(value) = util::parse_text::<u32, _>(&child, WarningKind::InvalidNumber, &height) {
+                    if value > 0 && value <= 400 {
+                        image.height = Some(value)
+                    } else {
+                        child.warn(WarningKind::InvalidNumber, &height);
                     }
                 }
             }),
@@ -212,8 +231,9 @@ fn handle_content_encoded<R: BufRead>(element: Element<R>) -> ParseFeedResult<Op
 // * "enclosure" is treated as if it was a MediaRSS MediaContent element and wrapped in a MediaObject
 // * "content:encoded" is mapped to the content field of an Entry
 // * MediaRSS elements without a parent group are added to a default MediaObject
-// * Itunes elements are added to the default MediaObject
-fn handle_item<R: BufRead>(parser: &Parser, element: Element<R>) -> ParseFeedResult<Option<Entry>> {
+// * Itunes elements are added to the default MediaObject, other than those that describe the entry (e.g. the episode number)
+// * Podcasting 2.0 elements describing the media (e.g. transcripts) are added to the default MediaObject, the remainder to the entry
+pub(crate) fn handle_item<R: BufRead>(parser: &Parser, element: Element<R>) -> ParseFeedResult<Option<Entry>> {
     let mut entry = Entry::default();
 
     // Create a default media object e.g. MediaRSS elements that are not within a "<media:group>", enclosures etc
@@ -242,8 +262,8 @@ fn handle_item<R: BufRead>(parser: &Parser, element: Element<R>) -> ParseFeedRes
 
             (NS::DublinCore, "creator") => if_some_then(child.children_as_string().ok().flatten(), |name| entry.authors.push(Person::new(&name))),
 
-            // Itunes elements populate the default MediaObject
-            (NS::Itunes, _) => handle_itunes_item_element(child, &mut media_obj)?,
+            // Itunes elements populate the default MediaObject, other than those that describe the entry
+            (NS::Itunes, _) => handle_itunes_item_element(child, &mut entry, &mut media_obj)?,
 
             // MediaRSS group creates a new object for this group of elements
             (NS::MediaRSS, "group") => if_some_then(mediarss::handle_media_group(child)?, |obj| entry.media.push(obj)),
@@ -251,7 +271,13 @@ fn handle_item<R: BufRead>(parser: &Parser, element: Element<R>) -> ParseFeedRes
             // MediaRSS tags that are not grouped are parsed into the default object
             (NS::MediaRSS, _) => handle_media_element(child, &mut media_obj)?,
 
-            // Nothing required for unknown elements
+            // Podcasting 2.0 elements describing the media populate the default MediaObject, the remainder the entry
+            (NS::Podcast, _) => handle_podcast_item_element(child, &mut entry, &mut media_obj)?,
+
+            // Elements in unsupported namespaces are retained as extensions
+            (NS::Unknown, _) => util::handle_extension(child, &mut entry.extensions)?,
+
+            // Nothing required for other elements
             _ => {}
         }
     }
@@ -110,6 +110,10 @@ fn test_example_3() {
             .updated_parsed("Fri, 02 Aug 2019 15:35:34 +0000")
             .media(MediaObject::default()
                 .thumbnail(MediaThumbnail::new(Image::new("https://media.newyorker.com/photos/5d4211a4ba8a9c0009a57cfd/master/pass/Chotiner-ReaganRacismNaftali-3.jpg".into()).width(2560).height(1819)))
+                .keyword("Ronald Reagan")
+                .keyword("Richard Nixon")
+                .keyword("Racism")
+                .keyword("History")
             )
         );
 
@@ -138,6 +142,9 @@ fn test_example_4() {
         .updated_parsed("Tue, 06 Aug 2019 05:01:15 +0000")
         .language("en-us")
         .generator(Generator::new("https://wordpress.org/?v=5.1.1"))
+        .extension("http://purl.org/rss/1.0/modules/syndication/", Extension::new("updatePeriod").value("hourly"))
+        .extension("http://purl.org/rss/1.0/modules/syndication/", Extension::new("updateFrequency").value("1"))
+        .extension("com-wordpress:feed-additions:1", Extension::new("site").value("76661723"))
         .entry(Entry::default()
             .title(Text::new("Minor earthquake, 3.5 mag was detected near Aris in Greece".into()))
             .author(Person::new("admin"))
@@ -151,7 +158,11 @@ fn test_example_4() {
             .content(Content::default()
                 .body("<p><img class='size-full alignleft' title='Earthquake location 37.102S, 21.9072W' alt='Earthquake location 37.102S, 21.9072W' src='http://www.earthquakenewstoday.com/wp-content/uploads/35_20.jpg' width='146' height='146' />A minor earthquake with magnitude 3.5 (ml/mb) was detected on Tuesday, 8 kilometers (5 miles) from Aris in Greece.Exact location of event, depth 10 km, 21.9072&deg; East, 37.102&deg; North. </p>")
                 .content_type("text/html"))
-            .updated(actual.updated));
+            .updated(actual.updated)
+            .extension("http://wellformedweb.org/CommentAPI/", Extension::new("commentRss")
+                .value("http://www.earthquakenewstoday.com/2019/08/06/minor-earthquake-3-5-mag-was-detected-near-aris-in-greece/feed/"))
+            .extension("http://purl.org/rss/1.0/modules/slash/", Extension::new("comments").value("0"))
+            .extension("com-wordpress:feed-additions:1", Extension::new("post-id").value("149732")));
 
     // Check
     assert_eq!(actual, expected);
@@ -188,6 +199,12 @@ fn test_example_5() {
                 .width(32)
                 .height(32),
         )
+        .extension("http://purl.org/rss/1.0/modules/syndication/", Extension::new("updatePeriod").value("hourly"))
+        .extension("http://purl.org/rss/1.0/modules/syndication/", Extension::new("updateFrequency").value("1"))
+        .extension(
+            "http://rssnamespace.org/feedburner/ext/1.0",
+            Extension::new("info").attr("uri", "arstechnica/index"),
+        )
         .entry(
             Entry::default()
                 .title(Text::new(
@@ -284,6 +301,18 @@ fn test_spec_1() {
         .contributor(Person::new("managingEditor").email("dave@userland.com"))
         .contributor(Person::new("webMaster").email("dave@userland.com"))
         .ttl(40)
+        .extension(
+            "http://backend.userland.com/blogChannelModule",
+            Extension::new("blogRoll").value("http://radio.weblogs.com/0001015/userland/scriptingNewsLeftLinks.opml"),
+        )
+        .extension(
+            "http://backend.userland.com/blogChannelModule",
+            Extension::new("mySubscriptions").value("http://radio.weblogs.com/0001015/gems/mySubscriptions.opml"),
+        )
+        .extension(
+            "http://backend.userland.com/blogChannelModule",
+            Extension::new("blink").value("http://diveintomark.org/"),
+        )
         .entry(
             Entry::default()
                 .summary(Text::html(
@@ -391,6 +420,13 @@ fn test_spiegel() {
             .title("SPIEGEL Update – Die Nachrichten")
             .link("https://www.spiegel.de/thema/spiegel-update/")
         )
+        .itunes(ItunesMeta {
+            podcast_type: Some(ItunesPodcastType::Episodic),
+            new_feed_url: Some(Url::parse("https://www.omnycontent.com/d/playlist/5ac1e950-45c7-4eb7-87c0-aa0f018441b8/bb17ca27-51f4-4349-bc1e-abc00102c975/4c18e072-24d2-4d60-9a42-abc00102c97e/podcast.rss").unwrap()),
+            owner: Some(Person::new("SPIEGEL Update – Die Nachrichten").email("charlotte.meyer-hamme@spiegel.de")),
+            subtitle: Some("Die wichtigsten Nachrichten des Tages".into()),
+            ..Default::default()
+        })
         .entry(
             Entry::default()
                 .title(Text::new("07.02. – die Wochenvorschau: Lockdown-Verlängerung, Kriegsverbrecher vor Gericht, Super Bowl, Karneval".into()))
@@ -400,6 +436,10 @@ fn test_spiegel() {
                 .published("2021-02-06T23:01:00Z")
                 .updated_parsed("2021-02-06T23:01:00Z")
                 .id("c7e3cca2-665e-4bc4-bcac-acc6011b9fa2")
+                .itunes(ItunesMeta {
+                    episode_type: Some(ItunesEpisodeType::Full),
+                    ..Default::default()
+                })
                 // <enclosure>, media: and itunes: tags
                 .media(MediaObject::default()
                     .title("07.02. – die Wochenvorschau: Lockdown-Verlängerung, Kriegsverbrecher vor Gericht, Super Bowl, Karneval ")
@@ -407,8 +447,9 @@ fn test_spiegel() {
                     .credit("DER SPIEGEL")
                     .thumbnail(MediaThumbnail::new(Image::new("https://www.omnycontent.com/d/programs/5ac1e950-45c7-4eb7-87c0-aa0f018441b8/bb17ca27-51f4-4349-bc1e-abc00102c975/image.jpg?t=1589902935&size=Large".into())))
                     .content(MediaContent::new()
-                        .url("https://omny.fm/shows/spiegel-update-die-nachrichten/07-02-die-wochenvorschau-lockdown-verl-ngerung-kri/embed")
+                        .url("https://traffic.omny.fm/d/clips/5ac1e950-45c7-4eb7-87c0-aa0f018441b8/bb17ca27-51f4-4349-bc1e-abc00102c975/c7e3cca2-665e-4bc4-bcac-acc6011b9fa2/audio.mp3?utm_source=Podcast&in_playlist=4c18e072-24d2-4d60-9a42-abc00102c97e&t=1612652510")
                         .content_type("audio/mpeg")
+                        .player("https://omny.fm/shows/spiegel-update-die-nachrichten/07-02-die-wochenvorschau-lockdown-verl-ngerung-kri/embed")
                     )
                     .content(MediaContent::new()
                         .url("https://www.omnycontent.com/d/programs/5ac1e950-45c7-4eb7-87c0-aa0f018441b8/bb17ca27-51f4-4349-bc1e-abc00102c975/image.jpg?t=1589902935&size=Large")
@@ -421,6 +462,7 @@ fn test_spiegel() {
                     )
                     .duration(Duration::from_secs(312))
                 )
+                .extension("https://omny.fm/rss-extensions", Extension::new("clipId").value("c7e3cca2-665e-4bc4-bcac-acc6011b9fa2"))
         );
 
     // Check
@@ -467,6 +509,32 @@ fn test_bbc() {
         )
         .rights(Text::new("(C) BBC 2021".into()))
         .published("Thu, 25 Feb 2021 10:15:00 +0000")
+        .rating(MediaRating::new("nonadult".into()).urn("urn:simple"))
+        .itunes(ItunesMeta {
+            new_feed_url: Some(Url::parse("https://podcasts.files.bbci.co.uk/b006qykl.rss").unwrap()),
+            owner: Some(Person::new("BBC").email("RadioMusic.Support@bbc.co.uk")),
+            ..Default::default()
+        })
+        .extension(
+            "http://bbc.co.uk/2009/01/ppgRss",
+            Extension::new("network").attr("id", "radio4").attr("name", "BBC Radio 4"),
+        )
+        .extension(
+            "http://bbc.co.uk/2009/01/ppgRss",
+            Extension::new("seriesDetails").attr("frequency", "weekly").attr("daysLive", "-1"),
+        )
+        .extension(
+            "http://bbc.co.uk/2009/01/ppgRss",
+            Extension::new("systemRef").attr("systemId", "pid.brand").attr("key", "b006qykl"),
+        )
+        .extension(
+            "http://bbc.co.uk/2009/01/ppgRss",
+            Extension::new("systemRef").attr("systemId", "pid.genre").attr("key", "C00060"),
+        )
+        .extension(
+            "http://bbc.co.uk/2009/01/ppgRss",
+            Extension::new("systemRef").attr("systemId", "pid.format").attr("key", "PT004"),
+        )
         .entry(
             Entry::default()
                 .title(Text::new("Marcus Aurelius".into()))
@@ -475,6 +543,11 @@ fn test_bbc() {
                 .updated_parsed("Thu, 25 Feb 2021 10:15:00 +0000")
                 .id("urn:bbc:podcast:m000sjxt")
                 .link(Link::new("http://www.bbc.co.uk/programmes/m000sjxt", None))
+                .itunes(ItunesMeta {
+                    explicit: Some(false),
+                    subtitle: Some("Melvyn Bragg and guests discuss the life and meditations of 'the last good Roman emperor'.".into()),
+                    ..Default::default()
+                })
                 // <enclosure>,  media: and itunes: tags
                 .media(
                     MediaObject::default()
@@ -494,6 +567,27 @@ fn test_bbc() {
                                 .duration(Duration::from_secs(3156)),
                         )
                         .credit("BBC Radio 4"),
+                )
+                .extension("http://bbc.co.uk/2009/01/ppgRss", Extension::new("canonical").value("/programmes/m000sjxt"))
+                .extension(
+                    "http://bbc.co.uk/2009/01/ppgRss",
+                    Extension::new("enclosureLegacy")
+                        .attr(
+                            "url",
+                            "http://open.live.bbc.co.uk/mediaselector/6/redir/version/2.0/mediaset/audio-nondrm-download/proto/http/vpid/p097wt5b.mp3",
+                        )
+                        .attr("length", "50496000")
+                        .attr("type", "audio/mpeg"),
+                )
+                .extension(
+                    "http://bbc.co.uk/2009/01/ppgRss",
+                    Extension::new("enclosureSecure")
+                        .attr(
+                            "url",
+                            "https://open.live.bbc.co.uk/mediaselector/6/redir/version/2.0/mediaset/audio-nondrm-download/proto/https/vpid/p097wt5b.mp3",
+                        )
+                        .attr("length", "50496000")
+                        .attr("type", "audio/mpeg"),
                 ),
         );
 
@@ -535,6 +629,14 @@ fn test_ch9() {
         .updated_parsed("Sat, 27 Feb 2021 06:55:01 GMT")
         .author(Person::new("Microsoft"))
         .generator(Generator::new("Rev9"))
+        .extension("http://channel9.msdn.com", Extension::new("totalResults").value("374"))
+        .extension("http://channel9.msdn.com", Extension::new("pageSize").value("25"))
+        .extension("http://channel9.msdn.com", Extension::new("pageCount").value("15"))
+        .extension("http://www.google.com/schemas/play-podcasts/1.0", Extension::new("author").value("Microsoft"))
+        .extension(
+            "http://www.google.com/schemas/play-podcasts/1.0",
+            Extension::new("email").value("ch9@microsoft.com"),
+        )
         .entry(
             Entry::default()
                 .title(Text::new("Troubleshoot AKS cluster issues with AKS Diagnostics and AKS Periscope".into()))
@@ -627,6 +729,12 @@ fn test_ch9() {
                                 .content_type("video/mp4"),
                         )
                         .credit("Scott Hanselman, Rob Caron"),
+                )
+                .extension("http://purl.org/rss/1.0/modules/slash/", Extension::new("comments").value("0"))
+                .extension(
+                    "http://wellformedweb.org/CommentAPI/",
+                    Extension::new("commentRss")
+                        .value("https://channel9.msdn.com/Shows/Azure-Friday/Troubleshoot-AKS-cluster-issues-with-AKS-Diagnostics-and-AKS-Periscope/RSS"),
                 ),
         );
 
@@ -808,3 +916,411 @@ fn test_media_content_player() {
     let content_url = entry.media[0].content[0].url.as_ref().unwrap();
     assert_eq!("https://player.vimeo.com/video/1013595996?h=b1b80eff69", content_url.as_str());
 }
+
+// Verifies the elements of the Podcasting 2.0 namespace are parsed into the model
+#[test]
+fn test_podcasting_2() {
+    let test_data = test::fixture_as_string("rss2/rss_2.0_podcasting_2.xml");
+    let actual = parser::parse(test_data.as_bytes()).unwrap();
+
+    // Channel level elements are added to the feed
+    let podcast = actual.podcast.as_ref().expect("feed has podcast elements");
+    assert_eq!(podcast.guid.as_deref(), Some("917393e3-1b1e-5cef-ace4-edaa54e1f810"));
+    assert_eq!(
+        podcast.locked,
+        Some(PodcastLocked {
+            locked: true,
+            owner: Some("podcastowner@example.com".into())
+        })
+    );
+    assert_eq!(
+        podcast.funding,
+        vec![PodcastFunding {
+            url: Url::parse("https://www.example.com/donations").unwrap(),
+            label: Some("Support the show!".into())
+        }]
+    );
+    assert_eq!(
+        podcast.persons,
+        vec![PodcastPerson {
+            name: "John Smith".into(),
+            role: None,
+            group: None,
+            img: Some(Url::parse("https://example.com/images/johnsmith.jpg").unwrap()),
+            href: Some(Url::parse("https://example.com/johnsmith/blog").unwrap()),
+        }]
+    );
+    let value = &podcast.value[0];
+    assert_eq!((value.value_type.as_str(), value.method.as_str()), ("lightning", "keysend"));
+    assert_eq!(value.suggested.as_deref(), Some("0.00000005000"));
+    assert_eq!(
+        value.recipients.iter().map(|r| (r.split, r.fee)).collect::<Vec<_>>(),
+        vec![(95, false), (5, true)]
+    );
+    assert_eq!(value.recipients[1].name.as_deref(), Some("Podcastindex.org"));
+
+    // Item level elements are added to the entry
+    let entry = &actual.entries[0];
+    let podcast = entry.podcast.as_ref().expect("entry has podcast elements");
+    assert_eq!(
+        podcast.season,
+        Some(PodcastSeason {
+            number: 1,
+            name: Some("Race for the Whitehouse 2020".into())
+        })
+    );
+    assert_eq!(
+        podcast.episode,
+        Some(PodcastEpisode {
+            number: 3.0,
+            display: Some("Ch.3".into())
+        })
+    );
+    assert_eq!(podcast.persons.len(), 2);
+    assert_eq!(podcast.persons[1].group.as_deref(), Some("writing"));
+    assert_eq!(podcast.persons[1].role.as_deref(), Some("guest"));
+
+    // ...while those describing the media are added to the media object along with the enclosure
+    assert_eq!(entry.media.len(), 1);
+    let media = entry.media[0].podcast.as_ref().expect("media has podcast elements");
+    assert_eq!(
+        media.transcripts[0],
+        PodcastTranscript {
+            url: Url::parse("https://example.com/episode1/transcript.vtt").unwrap(),
+            content_type: Some("text/vtt".parse().unwrap()),
+            language: Some("es".into()),
+            rel: Some("captions".into()),
+        }
+    );
+    assert_eq!(media.transcripts.len(), 2);
+    assert_eq!(
+        media.chapters,
+        Some(PodcastChapters {
+            url: Url::parse("https://example.com/episode1/chapters.json").unwrap(),
+            content_type: Some("application/json+chapters".parse().unwrap()),
+        })
+    );
+    assert_eq!(
+        media.soundbites,
+        vec![PodcastSoundbite {
+            start_time: Duration::from_millis(1_234_500),
+            duration: Duration::from_millis(42_250),
+            title: Some("Why the Podcast Namespace Matters".into()),
+        }]
+    );
+    let enclosure = &media.alternate_enclosures[0];
+    assert_eq!(enclosure.content_type, Some("audio/opus".parse().unwrap()));
+    assert_eq!((enclosure.length, enclosure.bitrate), (Some(32_400_000), Some(96_000.5)));
+    assert!(enclosure.default);
+    assert_eq!(
+        enclosure.sources,
+        vec![
+            PodcastSource {
+                uri: "https://example.com/file-0.opus".into(),
+                content_type: None
+            },
+            PodcastSource {
+                uri: "ipfs://someRandomHighBitrateOpusFile".into(),
+                content_type: Some("audio/ogg".parse().unwrap())
+            },
+        ]
+    );
+    assert_eq!(enclosure.integrity.as_ref().map(|i| i.integrity_type.as_str()), Some("sri"));
+}
+
+// Verifies the iTunes tags used by podcast directories are parsed into the model
+#[test]
+fn test_itunes_meta() {
+    let test_data = test::fixture_as_string("rss2/rss_2.0_itunes_serial.xml");
+    let (actual, warnings) = parser::Builder::new().build().parse_with_report(test_data.as_bytes()).unwrap();
+
+    assert_eq!(
+        actual.itunes,
+        Some(ItunesMeta {
+            podcast_type: Some(ItunesPodcastType::Serial),
+            new_feed_url: Some(Url::parse("https://example.com/hiking-treks/feed.xml").unwrap()),
+            complete: Some(true),
+            block: Some(false),
+            keywords: vec!["hiking".into(), "outdoors".into(), "nature".into()],
+            subtitle: Some("Tips for safe hiking".into()),
+            ..Default::default()
+        })
+    );
+
+    let entries = actual.entries.iter().map(|entry| entry.itunes.clone().unwrap()).collect::<Vec<_>>();
+    assert_eq!(
+        entries,
+        vec![
+            ItunesMeta {
+                season: Some(1),
+                episode_type: Some(ItunesEpisodeType::Trailer),
+                explicit: Some(false),
+                block: Some(true),
+                ..Default::default()
+            },
+            ItunesMeta {
+                episode: Some(1),
+                season: Some(1),
+                episode_type: Some(ItunesEpisodeType::Full),
+                explicit: Some(true),
+                keywords: vec!["boots".into(), "gear".into()],
+                ..Default::default()
+            },
+            // The invalid episode number is skipped with a warning
+            ItunesMeta {
+                episode_type: Some(ItunesEpisodeType::Bonus),
+                ..Default::default()
+            },
+        ]
+    );
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].path, "/rss/channel/item[3]/episode");
+    assert_eq!(warnings[0].value, "one");
+}
+
+// Verifies the optional elements of the Media RSS spec are parsed into the media object
+#[test]
+fn test_mediarss_elements() {
+    let test_data = test::fixture_as_string("rss2/rss_2.0_mediarss_full.xml");
+    let actual = parser::parse(test_data.as_bytes()).unwrap();
+    let media = &actual.entries[0].media[0];
+
+    // The player is retained on the content, with the direct URL taking precedence
+    let content = &media.content[0];
+    assert_eq!(content.url.as_ref().unwrap().as_str(), "https://www.example.com/videos/1.mp4");
+    assert_eq!(
+        content.player,
+        Some(MediaPlayer {
+            url: Url::parse("https://www.example.com/player?id=1").unwrap(),
+            height: Some(360),
+            width: Some(640),
+        })
+    );
+    assert_eq!(
+        media.hashes,
+        vec![MediaHash {
+            algo: "sha-1".into(),
+            value: "dfdec888b72151965a34b4b59031290a".into()
+        }]
+    );
+
+    assert_eq!(media.keywords, vec!["kitty", "cat", "big dog", "yarn", "fluffy"]);
+    assert_eq!(
+        media.categories,
+        vec![
+            Category::new("music/artist/album/song").scheme("http://search.yahoo.com/mrss/category_schema"),
+            Category::new("ace").scheme("urn:flickr:tags").label("Ace Ventura"),
+        ]
+    );
+
+    let embed = media.embed.as_ref().unwrap();
+    assert_eq!((embed.width, embed.height), (Some(512), Some(323)));
+    assert_eq!(embed.params["type"], "application/x-shockwave-flash");
+    assert_eq!(embed.params["allowScriptAccess"], "always");
+
+    assert_eq!(
+        media.licenses,
+        vec![MediaLicense {
+            name: Some("Creative Commons Attribution 3.0 United States License".into()),
+            content_type: Some("text/html".parse().unwrap()),
+            href: Some(Url::parse("http://creativecommons.org/licenses/by/3.0/us/").unwrap()),
+        }]
+    );
+    assert_eq!(
+        media.restrictions,
+        vec![MediaRestriction {
+            relationship: MediaRelationship::Allow,
+            restriction_type: Some("country".into()),
+            values: vec!["au".into(), "us".into()],
+        }]
+    );
+    assert_eq!(
+        media.prices,
+        vec![
+            MediaPrice {
+                price_type: Some("rent".into()),
+                price: Some(19.99),
+                info: None,
+                currency: Some("EUR".into()),
+            },
+            MediaPrice {
+                price_type: Some("subscription".into()),
+                info: Some(Url::parse("http://www.dummy.jp/subscription_info").unwrap()),
+                ..Default::default()
+            },
+        ]
+    );
+
+    assert_eq!(
+        media.peer_links,
+        vec![Link::new("http://www.example.org/sampleFile.torrent", None).media_type("application/x-bittorrent")]
+    );
+    let subtitle = &media.subtitles[0];
+    assert_eq!(subtitle.href, "http://www.example.org/subtitle.smil");
+    assert_eq!(subtitle.href_lang.as_deref(), Some("en-us"));
+
+    assert_eq!(
+        media.locations,
+        vec![MediaLocation {
+            description: Some("My house".into()),
+            start_time: Some(Duration::from_secs(1)),
+            end_time: Some(Duration::from_secs(60)),
+            latitude: Some(35.669998),
+            longitude: Some(139.770004),
+        }]
+    );
+    assert_eq!(
+        media.scenes,
+        vec![
+            MediaScene {
+                title: Some("sceneTitle1".into()),
+                description: Some("sceneDesc1".into()),
+                start_time: Some(Duration::from_secs(15)),
+                end_time: Some(Duration::from_secs(45)),
+            },
+            MediaScene {
+                title: Some("sceneTitle2".into()),
+                ..Default::default()
+            },
+        ]
+    );
+
+    assert_eq!(
+        media.status,
+        Some(MediaStatus {
+            state: MediaState::Blocked,
+            reason: Some("http://www.reasonforblocking.com".into())
+        })
+    );
+    assert_eq!(
+        media.copyright,
+        Some(MediaCopyright {
+            notice: "2005 FooBar Media".into(),
+            url: Some(Url::parse("http://blah.com/additional-info.html").unwrap()),
+        })
+    );
+    assert_eq!(
+        media.back_links,
+        vec![
+            Url::parse("http://www.example.org/index.html").unwrap(),
+            Url::parse("http://www.example.org/video1.html").unwrap()
+        ]
+    );
+}
+
+// Verify the channel level media is retained on the feed, and only inherited by the entries on request
+#[test]
+fn test_channel_media() {
+    let test_data = test::fixture_as_string("rss2/rss_2.0_nightvale.xml");
+    let actual = parser::parse(test_data.as_bytes()).unwrap();
+    let channel = actual.media.as_ref().unwrap();
+    assert_eq!(
+        channel.thumbnails[0].image.uri,
+        "https://f.prxu.org/126/images/bc7d203a-c0dd-46ec-956b-03bad13ba85e/nightvalelogo-web4.jpg"
+    );
+    assert_eq!(channel.keywords.len(), 12);
+    assert_eq!(
+        channel.categories,
+        vec![Category::new("Fiction").scheme("http://www.itunes.com/dtds/podcast-1.0.dtd")]
+    );
+    assert_eq!(channel.copyright.as_ref().unwrap().notice, "2016, Night Vale Presents");
+    assert!(actual.entries[0].media[0].copyright.is_none());
+
+    // Fields of the entry media take precedence over those of the channel
+    let parser = parser::Builder::new().inherit_media(true).build();
+    let actual = parser.parse(test_data.as_bytes()).unwrap();
+    let media = &actual.entries[0].media[0];
+    assert_eq!(media.copyright, channel.copyright);
+    assert_eq!(media.keywords, channel.keywords);
+    assert_eq!(media.categories, channel.categories);
+    assert_eq!(media.thumbnails.len(), 1);
+    assert_ne!(media.thumbnails, channel.thumbnails);
+
+    // The rating of the channel applies to the content of each entry
+    let test_data = test::fixture_as_string("rss2/rss_2.0_bbc.xml");
+    let actual = parser.parse(test_data.as_bytes()).unwrap();
+    assert!(actual.media.is_none());
+    let rating = MediaRating::new("nonadult".into()).urn("urn:simple");
+    assert_eq!(actual.rating.as_ref(), Some(&rating));
+    assert!(actual.entries[0].media[0]
+        .content
+        .iter()
+        .all(|content| content.rating.as_ref() == Some(&rating)));
+}
+
+// Verify a channel whose only media elements are credits retains them, and passes them on to the entries
+#[test]
+fn test_channel_media_credits() {
+    let test_data = r#"<rss version="2.0" xmlns:media="http://search.yahoo.com/mrss/">
+        <channel>
+            <title>Credits</title>
+            <media:credit role="producer" scheme="urn:ebu">Jane Doe</media:credit>
+            <item>
+                <title>Episode 1</title>
+            </item>
+        </channel>
+    </rss>"#;
+    let actual = parser::parse(test_data.as_bytes()).unwrap();
+    let channel = actual.media.as_ref().unwrap();
+    assert_eq!(channel.credits.len(), 1);
+    assert_eq!(channel.credits[0].entity, "Jane Doe");
+    assert!(actual.entries[0].media.is_empty());
+
+    let parser = parser::Builder::new().inherit_media(true).build();
+    let actual = parser.parse(test_data.as_bytes()).unwrap();
+    assert_eq!(actual.entries[0].media[0].credits, channel.credits);
+}
+
+// Verify Atom contributors within the channel are added to those of the feed
+#[test]
+fn test_atom_contributor() {
+    let test_data = r#"<rss version="2.0" xmlns:atom="http://www.w3.org/2005/Atom">
+        <channel>
+            <title>Contributors</title>
+            <managingEditor>editor@example.com</managingEditor>
+            <atom:contributor>
+                <atom:name>John</atom:name>
+                <atom:uri>https://example.com/john</atom:uri>
+                <atom:email>john@example.com</atom:email>
+            </atom:contributor>
+        </channel>
+    </rss>"#;
+    let actual = parser::parse(test_data.as_bytes()).unwrap();
+    assert_eq!(
+        actual.contributors,
+        vec![
+            Person::new("managingEditor").email("editor@example.com"),
+            Person::new("John").uri("https://example.com/john").email("john@example.com")
+        ]
+    );
+}
+
+// Verify the coordinates of a media location are only taken from "georss:point" or "gml:pos"
+#[test]
+fn test_media_location_coordinates() {
+    let test_data = r#"<rss version="2.0" xmlns:media="http://search.yahoo.com/mrss/" xmlns:georss="http://www.georss.org/georss"
+            xmlns:gml="http://www.opengis.net/gml" xmlns:x="urn:example:x">
+        <channel>
+            <item>
+                <media:location description="Elsewhere">
+                    <x:point>1 2</x:point>
+                    <x:where><gml:pos>3 4</gml:pos></x:where>
+                    <georss:where><gml:Point><x:pos>5 6</x:pos><gml:pos>7 8</gml:pos></gml:Point></georss:where>
+                </media:location>
+                <media:location description="Point">
+                    <georss:point>9 10</georss:point>
+                </media:location>
+                <media:location description="Nowhere">
+                    <point>11 12</point>
+                </media:location>
+            </item>
+        </channel>
+    </rss>"#;
+    let actual = parser::parse(test_data.as_bytes()).unwrap();
+    let coordinates: Vec<_> = actual.entries[0].media[0]
+        .locations
+        .iter()
+        .map(|location| (location.latitude, location.longitude))
+        .collect();
+    assert_eq!(coordinates, vec![(Some(7.0), Some(8.0)), (Some(9.0), Some(10.0)), (None, None)]);
+}
@@ -0,0 +1,271 @@
+use std::io::{BufReader, Read};
+
+use crate::model::{Entry, Feed, FeedType};
+use crate::parser::json::stream::JsonStream;
+use crate::parser::limits::{SizeCheck, SizeLimited};
+use crate::parser::{atom, json, mediarss, rss1, rss2, util, ParseErrorKind, ParseFeedError, ParseFeedResult, Parser};
+use crate::xml::{ElementSource, NS};
+
+#[cfg(test)]
+mod tests;
+
+/// A feed that is parsed incrementally, yielding one entry at a time
+///
+/// The feed level fields (title, links etc.) preceding the first entry are parsed when the stream is created and are available from `feed()`.
+/// The entries are then parsed as the stream is iterated, so only a single entry is held in memory at a time.
+/// Any feed level fields that follow the entries are applied to the feed as they are encountered, so will only be visible once the stream is exhausted.
+///
+/// As entries are not retained, `feed().entries` is always empty.
+pub struct FeedStream<'a, R: Read> {
+    parser: &'a Parser,
+    feed: Feed,
+    source: StreamSource<R>,
+    // Identifies errors caused by the source exceeding its maximum size
+    size_check: SizeCheck,
+    // The first entry is read while looking for the end of the feed level fields
+    pending: Option<Entry>,
+    // Set once the feed level fields preceding the first entry have been read
+    header_complete: bool,
+    // Whether feed level fields have been found after the first entry
+    trailing_fields: bool,
+    finished: bool,
+}
+
+// Formats differ in the element containing the entries (and the name of the entries)
+enum StreamSource<R: Read> {
+    Xml { source: Box<ElementSource<Input<R>>>, depth: u32 },
+    Json(JsonStream<Input<R>>),
+}
+
+// The source is buffered, and limited to the maximum size configured on the parser
+type Input<R> = SizeLimited<BufReader<R>>;
+
+impl<'a, R: Read> FeedStream<'a, R> {
+    /// The feed level fields parsed so far
+    pub fn feed(&self) -> &Feed {
+        &self.feed
+    }
+
+    // Creates a stream over a JSON Feed
+    pub(crate) fn json(parser: &'a Parser, (input, size_check): (Input<R>, SizeCheck)) -> ParseFeedResult<FeedStream<'a, R>> {
+        let source = size_check.apply(JsonStream::new(input))?;
+        let feed = json::convert_header(None, source.header()?)?;
+
+        FeedStream::new(parser, feed, StreamSource::Json(source), size_check).complete_header()
+    }
+
+    // Creates a stream over an XML feed, dispatching on the root element in the same manner as the parser
+    pub(crate) fn xml(parser: &'a Parser, (input, size_check): (Input<R>, SizeCheck)) -> ParseFeedResult<FeedStream<'a, R>> {
+        let source = size_check.apply(ElementSource::with_config(input, parser.base_uri.as_deref(), parser.xml_config()))?;
+
+        let (feed, depth, entry) = size_check.apply(FeedStream::<R>::xml_root(parser, &source).map_err(|e| e.at(|| source.position())))?;
+
+        let mut stream = FeedStream::new(
+            parser,
+            feed,
+            StreamSource::Xml {
+                source: Box::new(source),
+                depth,
+            },
+            size_check,
+        );
+        if depth == 0 {
+            // Nothing more to read for an entry document
+            stream.pending = entry;
+            stream.finished = true;
+        }
+        stream.complete_header()
+    }
+
+    // Determines the format of the feed from the root element, returning the depth at which entries are found
+    fn xml_root(parser: &Parser, source: &ElementSource<Input<R>>) -> ParseFeedResult<(Feed, u32, Option<Entry>)> {
+        let root = source.root()?.ok_or(ParseFeedError::ParseError(ParseErrorKind::NoFeedRoot, None))?;
+        let version = root.attr_value("version");
+        match (root.name.as_str(), version.as_deref()) {
+            ("feed", _) => {
+                source.set_default_default_namespace(NS::Atom);
+                let mut feed = Feed::new(FeedType::Atom);
+                feed.language = util::handle_language_attr(&root);
+                Ok((feed, 2, None))
+            }
+            ("entry", _) => {
+                // A single entry, which is read in its entirety
+                source.set_default_default_namespace(NS::Atom);
+                let entry = atom::handle_entry(parser, root)?;
+                Ok((Feed::new(FeedType::Atom), 0, entry))
+            }
+            ("rss", Some("2.0")) | ("rss", Some("0.91")) | ("rss", Some("0.92")) => {
+                source.set_default_default_namespace(NS::RSS);
+                let feed_type = if version.as_deref() == Some("2.0") { FeedType::RSS2 } else { FeedType::RSS0 };
+
+                // The items are within the channel
+                loop {
+                    match source.next_element_at_depth(2)? {
+                        Some(channel) if channel.name == "channel" => break,
+                        Some(_) => continue,
+                        None => return Err(ParseFeedError::ParseError(ParseErrorKind::NoFeedRoot, None)),
+                    }
+                }
+
+                Ok((Feed::new(feed_type), 3, None))
+            }
+            ("RDF", _) => {
+                source.set_default_default_namespace(NS::RSS);
+                Ok((Feed::new(FeedType::RSS1), 2, None))
+            }
+            _ => Err(ParseFeedError::ParseError(ParseErrorKind::NoFeedRoot, None)),
+        }
+    }
+
+    // Records the position of an error within XML content, and identifies errors caused by exceeding the maximum size
+    fn locate(&self, e: ParseFeedError) -> ParseFeedError {
+        let e = match &self.source {
+            StreamSource::Xml { source, .. } => e.at(|| source.position()),
+            StreamSource::Json(_) => e,
+        };
+        self.size_check.error(e)
+    }
+
+    // Wraps the source, with the feed level fields read by complete_header()
+    fn new(parser: &'a Parser, feed: Feed, source: StreamSource<R>, size_check: SizeCheck) -> FeedStream<'a, R> {
+        FeedStream {
+            parser,
+            feed,
+            source,
+            size_check,
+            pending: None,
+            header_complete: false,
+            trailing_fields: false,
+            finished: false,
+        }
+    }
+
+    // Reads the feed level fields up to the first entry, then applies the same post-processing as the parser
+    fn complete_header(mut self) -> ParseFeedResult<FeedStream<'a, R>> {
+        if self.finished {
+            self.pending = self.pending.take().map(|entry| self.complete_entry(entry));
+        } else {
+            self.pending = self.next_entry().map_err(|e| self.locate(e))?;
+        }
+        self.header_complete = true;
+
+        if self.parser.sanitize_content {
+            util::sanitize_feed(&mut self.feed);
+        }
+        if self.feed.id.is_empty() {
+            self.feed.id = (self.parser.id_generator)(&self.feed.links, &self.feed.title, self.parser.base_uri.as_deref());
+        }
+
+        Ok(self)
+    }
+
+    // Assigns an ID to the entry if it is missing and applies the channel level media (if requested), as the parser does when post-processing a feed
+    fn complete_entry(&self, mut entry: Entry) -> Entry {
+        if entry.id.is_empty() {
+            entry.id = (self.parser.id_generator)(&entry.links, &entry.title, self.parser.base_uri.as_deref());
+        }
+        if self.parser.inherit_media {
+            mediarss::inherit_channel_media(self.feed.media.as_ref(), self.feed.rating.as_ref(), &mut entry);
+        }
+        entry
+    }
+
+    // Reads the next entry, applying any feed level fields encountered before it
+    fn next_entry(&mut self) -> ParseFeedResult<Option<Entry>> {
+        let parser = self.parser;
+        let trailing = self.header_complete;
+        let entry = match &mut self.source {
+            StreamSource::Xml { source, depth } => {
+                let feed_type = self.feed.feed_type.clone();
+                let mut found = None;
+                while let Some(child) = source.next_element_at_depth(*depth)? {
+                    let entry = match (&feed_type, child.ns_and_tag()) {
+                        (FeedType::Atom, (NS::Atom, "entry")) => parser.isolate_entry(child, atom::handle_entry)?,
+                        (FeedType::RSS1, (NS::RSS, "item")) => parser.isolate_entry(child, rss1::handle_item)?,
+                        (FeedType::RSS0, (NS::RSS, "item")) | (FeedType::RSS2, (NS::RSS, "item")) => parser.isolate_entry(child, rss2::handle_item)?,
+
+                        // Feed level fields
+                        (FeedType::Atom, _) => {
+                            atom::handle_feed_element(parser, &mut self.feed, child)?;
+                            self.trailing_fields |= trailing;
+                            continue;
+                        }
+                        (FeedType::RSS1, _) => {
+                            rss1::handle_root_element(parser, &mut self.feed, child)?;
+                            self.trailing_fields |= trailing;
+                            continue;
+                        }
+                        _ => {
+                            rss2::handle_channel_element(parser, &mut self.feed, child)?;
+                            self.trailing_fields |= trailing;
+                            continue;
+                        }
+                    };
+
+                    // Items without sufficient content are dropped by the parsers
+                    if entry.is_some() {
+                        found = entry;
+                        break;
+                    }
+                }
+                found
+            }
+
+            StreamSource::Json(source) => {
+                // Warnings are not collected when streaming
+                let context = json::Context::new(parser, false);
+                loop {
+                    match source.next_item()? {
+                        Some((index, value)) => {
+                            parser.check_entries(index + 1)?;
+
+                            // Items that cannot be parsed may be skipped
+                            if let Some(ji) = json::parse_item(&context, index, value)? {
+                                break Some(json::convert_item(&context, &self.feed.authors, index, ji));
+                            }
+                        }
+                        None => {
+                            // Pick up any fields following the items
+                            let mut feed = json::convert_header(None, source.header()?)?;
+                            feed.id = std::mem::take(&mut self.feed.id);
+                            self.feed = feed;
+                            break None;
+                        }
+                    }
+                }
+            }
+        };
+
+        Ok(entry.map(|entry| self.complete_entry(entry)))
+    }
+}
+
+impl<R: Read> Iterator for FeedStream<'_, R> {
+    type Item = ParseFeedResult<Entry>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(entry) = self.pending.take() {
+            return Some(Ok(entry));
+        }
+        if self.finished {
+            return None;
+        }
+
+        match self.next_entry() {
+            Ok(Some(entry)) => Some(Ok(entry)),
+            Ok(None) => {
+                // Fields following the entries have not been sanitised yet
+                self.finished = true;
+                if self.trailing_fields && self.parser.sanitize_content {
+                    util::sanitize_feed(&mut self.feed);
+                }
+                None
+            }
+            Err(e) => {
+                // The source cannot be relied upon after an error
+                self.finished = true;
+                Some(Err(self.locate(e)))
+            }
+        }
+    }
+}
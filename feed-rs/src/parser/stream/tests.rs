@@ -0,0 +1,135 @@
+use std::fs;
+use std::path::Path;
+
+use crate::model::{Entry, Feed, Link, Text};
+use crate::parser::{self, Limit, ParseErrorKind, ParseFeedError, ParseFeedResult};
+use crate::util::test;
+
+// Deterministic IDs so the streamed entries can be compared with the parsed feed
+fn test_parser() -> parser::Parser {
+    parser::Builder::new()
+        .sanitize_content(false)
+        .id_generator(|links: &[Link], title: &Option<Text>, _uri| {
+            format!("{:?}-{:?}", links.first().map(|link| &link.href), title.as_ref().map(|title| &title.content))
+        })
+        .build()
+}
+
+// Streams the source, returning the feed level fields (after all entries have been consumed) and the entries
+fn stream(source: &[u8]) -> ParseFeedResult<(Feed, Vec<Entry>)> {
+    let parser = test_parser();
+    let mut stream = parser.parse_streaming(source)?;
+    let entries = stream.by_ref().collect::<ParseFeedResult<Vec<Entry>>>()?;
+    Ok((stream.feed().clone(), entries))
+}
+
+fn visit_fixtures(dir: &Path, callback: &dyn Fn(&Path)) {
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            visit_fixtures(&path, callback);
+        } else {
+            let name = path.to_str().unwrap();
+            if (name.ends_with(".xml") || name.ends_with(".json")) && !name.ends_with(".serde.json") {
+                callback(&path);
+            }
+        }
+    }
+}
+
+// Verify that streaming every fixture produces the same result as parsing it
+#[test]
+fn test_fixtures() {
+    visit_fixtures(&test::fixture_dir(), &|path| {
+        let source = fs::read(path).unwrap();
+        let parsed = test_parser().parse(source.as_slice());
+        let streamed = stream(&source);
+
+        match (parsed, streamed) {
+            (Ok(mut expected), Ok((feed, entries))) => {
+                assert_eq!(entries, expected.entries, "entries differ for {:?}", path);
+                expected.entries.clear();
+                assert_eq!(feed, expected, "feed differs for {:?}", path);
+            }
+            (Err(_), Err(_)) => {}
+            (parsed, streamed) => panic!("{:?} parsed as {:?} but streamed as {:?}", path, parsed.is_ok(), streamed.is_ok()),
+        }
+    });
+}
+
+// The feed level fields are available before any of the entries are read
+#[test]
+fn test_header_first() {
+    let source = test::fixture_as_raw("rss2/rss_2.0_example_1.xml");
+    let parser = test_parser();
+    let mut stream = parser.parse_streaming(source.as_slice()).unwrap();
+
+    assert_eq!(stream.feed().title.as_ref().unwrap().content, "RSS Title");
+    assert!(stream.feed().entries.is_empty());
+
+    let entry = stream.next().unwrap().unwrap();
+    assert_eq!(entry.title.unwrap().content, "Example entry");
+    assert!(stream.next().is_none());
+}
+
+// Fields following the items in a JSON Feed are applied once the items have been read
+#[test]
+fn test_json_trailing_fields() {
+    let json = r#"{
+        "version": "https://jsonfeed.org/version/1.1",
+        "items": [
+            { "id": "1", "content_text": "first, with [brackets] and \"quotes\"" },
+            { "id": "2", "content_text": "second" }
+        ],
+        "title": "Trailing title",
+        "authors": [{ "name": "Jane" }]
+    }"#;
+    let parser = test_parser();
+    let mut stream = parser.parse_streaming(json.as_bytes()).unwrap();
+    assert!(stream.feed().title.is_none());
+
+    let ids: Vec<String> = stream.by_ref().map(|entry| entry.unwrap().id).collect();
+    assert_eq!(ids, vec!["1", "2"]);
+    assert_eq!(stream.feed().title.as_ref().unwrap().content, "Trailing title");
+    assert_eq!(stream.feed().authors[0].name, "Jane");
+}
+
+// Malformed content is reported when the affected entry is read
+#[test]
+fn test_errors() {
+    let json = r#"{ "version": "https://jsonfeed.org/version/1.1", "title": "t", "items": [ { "id": "1" }, { "id": 2 ]"#;
+    let parser = test_parser();
+    let mut stream = parser.parse_streaming(json.as_bytes()).unwrap();
+    assert!(stream.next().unwrap().is_ok());
+    assert!(matches!(stream.next(), Some(Err(ParseFeedError::JsonSerde(_)))));
+    assert!(stream.next().is_none());
+
+    let xml = "<rss version=\"2.0\"><channel><title>t</title><item><title>one</title></item><item><title>two</title>";
+    let mut stream = parser.parse_streaming(xml.as_bytes()).unwrap();
+    assert!(stream.next().unwrap().is_ok());
+    assert!(matches!(stream.next(), Some(Err(ParseFeedError::XmlReader(..)))));
+}
+
+// Streams created from the same parser are independent of each other (and of the parser being used for other feeds)
+#[test]
+fn test_concurrent_streams() {
+    let xml = r#"<feed xmlns="http://www.w3.org/2005/Atom"><entry><id>1</id></entry><entry><id>2</id></entry><entry><id>3</id></entry></feed>"#;
+    let parser = parser::Builder::new().max_entries(2).build();
+
+    let mut first = parser.parse_streaming(xml.as_bytes()).unwrap();
+    assert_eq!(first.next().unwrap().unwrap().id, "1");
+
+    // Creating and consuming another stream, or parsing another feed, does not reset the number of entries seen by the first
+    let second: Vec<_> = parser.parse_streaming(xml.as_bytes()).unwrap().collect();
+    assert!(matches!(
+        second[2],
+        Err(ParseFeedError::ParseError(ParseErrorKind::LimitExceeded(Limit::Entries), _))
+    ));
+    assert!(parser.parse(xml.as_bytes()).is_err());
+
+    assert_eq!(first.next().unwrap().unwrap().id, "2");
+    assert!(matches!(
+        first.next(),
+        Some(Err(ParseFeedError::ParseError(ParseErrorKind::LimitExceeded(Limit::Entries), _)))
+    ));
+}
@@ -1,10 +1,12 @@
+use std::cell::RefCell;
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::rc::Rc;
 
 use uuid::Uuid;
 
-use crate::model::Feed;
-use crate::parser;
+use crate::model::{Entry, Extension, Feed, FeedType, RelocationSource};
+use crate::parser::{self, Limit, ParseErrorKind, ParseFeedError, WarningKind};
 use crate::util::test;
 
 // Regression test for the default ID generator
@@ -31,18 +33,505 @@ fn fuzz_parse() {
     assert!(result.is_err());
 }
 
+// Verifies errors report where in the source they occurred
+#[test]
+fn error_position() {
+    let xml = r#"<feed xmlns="http://www.w3.org/2005/Atom">
+    <entry><id>1</id></entry>
+    <entry>
+        <id>2</id>
+        <content src="https://example.com/2" type="text/html">body</content>
+    </entry>
+</feed>"#;
+    let err = parser::parse(xml.as_bytes()).unwrap_err();
+    assert!(matches!(err, ParseFeedError::ParseError(ParseErrorKind::MissingContent(_), _)));
+    let position = err.position().unwrap();
+    assert_eq!((position.line, position.column), (5, 63));
+    assert_eq!(position.path, "/feed/entry[2]/content");
+    assert!(err.to_string().ends_with("at line 5, column 63 (byte 166) in /feed/entry[2]/content"));
+
+    // Malformed XML reports the location of the problem
+    let xml = "<rss version=\"2.0\">\n<channel>\n<item><title>one</title><link";
+    let err = parser::parse(xml.as_bytes()).unwrap_err();
+    assert!(matches!(err, ParseFeedError::XmlReader(..)));
+    let position = err.position().unwrap();
+    assert_eq!((position.offset, position.line, position.column), (54, 3, 25));
+    assert_eq!(position.path, "/rss/channel/item");
+
+    // JSON errors are reported by serde, which includes the line and column in the message
+    assert!(parser::parse("{ ".as_bytes()).unwrap_err().position().is_none());
+}
+
+// Verifies malformed entries are skipped when parsing leniently
+#[test]
+fn lenient_entries() {
+    let xml = r#"<feed xmlns="http://www.w3.org/2005/Atom">
+    <entry><id>1</id></entry>
+    <entry>
+        <id>2</id>
+        <content src="https://example.com/2" type="text/html">body</content>
+        <title>ignored</title>
+    </entry>
+    <entry><id>3</id><content type="not a type">body</content></entry>
+    <entry><id>4</id></entry>
+</feed>"#;
+
+    // By default the feed fails
+    assert!(parser::parse(xml.as_bytes()).is_err());
+
+    // The errors are recorded with the position of the bad entry
+    let errors = Rc::new(RefCell::new(Vec::new()));
+    let recorded = errors.clone();
+    let parser = parser::Builder::new()
+        .lenient(true)
+        .entry_error_handler(move |e| recorded.borrow_mut().push(e.position().unwrap().path.clone()))
+        .build();
+    let feed = parser.parse(xml.as_bytes()).unwrap();
+    let ids: Vec<&str> = feed.entries.iter().map(|entry| entry.id.as_str()).collect();
+    assert_eq!(ids, vec!["1", "4"]);
+    assert_eq!(*errors.borrow(), vec!["/feed/entry[2]/content", "/feed/entry[3]/content"]);
+
+    // Streaming skips the same entries
+    let ids: Vec<String> = parser.parse_streaming(xml.as_bytes()).unwrap().map(|entry| entry.unwrap().id).collect();
+    assert_eq!(ids, vec!["1", "4"]);
+
+    // Malformed XML cannot be isolated to an entry
+    let xml = "<rss version=\"2.0\"><channel><item><title>one</title></item><item><title>two</title>";
+    assert!(parser.parse(xml.as_bytes()).is_err());
+}
+
+#[test]
+fn parse_with_report() {
+    let parser = parser::Builder::new().build();
+
+    // Values that fail to parse are reported with their location
+    let xml = r#"<rss version="2.0"><channel>
+    <ttl>soon</ttl>
+    <image><url>https://example.com/logo.png</url><width>large</width><height>2000</height></image>
+    <item><enclosure url="https://example.com/a.mp3" length="big" type="not a type"/></item>
+</channel></rss>"#;
+    let (feed, warnings) = parser.parse_with_report(xml.as_bytes()).unwrap();
+    assert!(feed.ttl.is_none());
+    let reported: Vec<(WarningKind, &str, &str)> = warnings.iter().map(|w| (w.kind.clone(), w.path.as_str(), w.value.as_str())).collect();
+    assert_eq!(
+        reported,
+        vec![
+            (WarningKind::InvalidNumber, "/rss/channel/ttl", "soon"),
+            (WarningKind::InvalidNumber, "/rss/channel/image/width", "large"),
+            (WarningKind::InvalidNumber, "/rss/channel/image/height", "2000"),
+            (WarningKind::InvalidNumber, "/rss/channel/item/enclosure/@length", "big"),
+            (WarningKind::UnknownMimeType, "/rss/channel/item/enclosure/@type", "not a type"),
+        ]
+    );
+
+    // Timestamps in XML and JSON content
+    let xml = r#"<feed xmlns="http://www.w3.org/2005/Atom"><entry><id>1</id><updated>yesterday</updated></entry></feed>"#;
+    let (_, warnings) = parser.parse_with_report(xml.as_bytes()).unwrap();
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].kind, WarningKind::InvalidTimestamp);
+    assert_eq!(warnings[0].path, "/feed/entry/updated");
+    let json = r#"{"version": "https://jsonfeed.org/version/1.1", "title": "t", "items": [{"id": "1"}, {"id": "2", "date_published": "someday"}]}"#;
+    let (_, warnings) = parser.parse_with_report(json.as_bytes()).unwrap();
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].kind, WarningKind::InvalidTimestamp);
+    assert_eq!(warnings[0].path, "/items/1/date_published");
+    assert_eq!(warnings[0].value, "someday");
+
+    // Entries skipped in lenient mode are reported
+    let xml = r#"<feed xmlns="http://www.w3.org/2005/Atom"><entry><id>1</id><content type="not a type">body</content></entry></feed>"#;
+    let parser = parser::Builder::new().lenient(true).build();
+    let (feed, warnings) = parser.parse_with_report(xml.as_bytes()).unwrap();
+    assert!(feed.entries.is_empty());
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].kind, WarningKind::SkippedEntry);
+    assert_eq!(warnings[0].path, "/feed/entry/content");
+
+    // Warnings are not retained between calls
+    let (_, warnings) = parser.parse_with_report(r#"<feed xmlns="http://www.w3.org/2005/Atom"/>"#.as_bytes()).unwrap();
+    assert!(warnings.is_empty());
+}
+
+// Extracts the limit that was exceeded (with the path if known)
+fn exceeded(result: Result<impl std::fmt::Debug, ParseFeedError>) -> (Limit, Option<String>) {
+    match result.unwrap_err() {
+        ParseFeedError::ParseError(ParseErrorKind::LimitExceeded(limit), position) => (limit, position.map(|p| p.path)),
+        e => panic!("unexpected error {:?}", e),
+    }
+}
+
+#[test]
+fn limits() {
+    let xml = r#"<feed xmlns="http://www.w3.org/2005/Atom">
+    <title>sample feed</title>
+    <entry><id>1</id></entry>
+    <entry><id>2</id><content type="xhtml"><div><p><b>nested</b></p></div></content></entry>
+    <entry><id>3</id><link href="https://example.com/" rel="alternate" type="text/html" hreflang="en"/></entry>
+</feed>"#;
+
+    // Within the limits, the feed is parsed as normal
+    let parser = parser::Builder::new()
+        .max_attributes(4)
+        .max_bytes(xml.len() as u64)
+        .max_depth(6)
+        .max_entries(3)
+        .max_text_length(11)
+        .build();
+    assert_eq!(parser.parse(xml.as_bytes()).unwrap().entries.len(), 3);
+
+    // Each limit fails the feed, even when parsing leniently
+    let parser = parser::Builder::new().lenient(true).max_depth(5).build();
+    assert_eq!(
+        exceeded(parser.parse(xml.as_bytes())),
+        (Limit::Depth, Some("/feed/entry[2]/content/div/p/b".into()))
+    );
+    let parser = parser::Builder::new().lenient(true).max_attributes(3).build();
+    assert_eq!(exceeded(parser.parse(xml.as_bytes())), (Limit::Attributes, Some("/feed/entry[3]/link".into())));
+    let parser = parser::Builder::new().max_text_length(10).build();
+    assert_eq!(exceeded(parser.parse(xml.as_bytes())), (Limit::TextLength, Some("/feed/title".into())));
+    let parser = parser::Builder::new().lenient(true).max_entries(2).build();
+    assert_eq!(exceeded(parser.parse(xml.as_bytes())), (Limit::Entries, Some("/feed/entry[3]".into())));
+    let parser = parser::Builder::new().max_bytes(xml.len() as u64 - 1).build();
+    assert_eq!(exceeded(parser.parse(xml.as_bytes())).0, Limit::Bytes);
+
+    // Streaming yields the entries up to the limit
+    let parser = parser::Builder::new().max_entries(2).build();
+    let entries: Vec<_> = parser.parse_streaming(xml.as_bytes()).unwrap().collect();
+    assert_eq!(entries.len(), 3);
+    assert_eq!(exceeded(entries.into_iter().nth(2).unwrap()).0, Limit::Entries);
+
+    // JSON Feed is limited in size and entries
+    let json = r#"{"version": "https://jsonfeed.org/version/1.1", "title": "t", "items": [{"id": "1"}, {"id": "2"}, {"id": "3"}]}"#;
+    let parser = parser::Builder::new().max_entries(2).build();
+    assert_eq!(exceeded(parser.parse(json.as_bytes())).0, Limit::Entries);
+    let entries: Vec<_> = parser.parse_streaming(json.as_bytes()).unwrap().collect();
+    assert_eq!(exceeded(entries.into_iter().nth(2).unwrap()).0, Limit::Entries);
+    let parser = parser::Builder::new().max_bytes(32).build();
+    assert_eq!(exceeded(parser.parse(json.as_bytes())).0, Limit::Bytes);
+    assert_eq!(exceeded(parser.parse_streaming(json.as_bytes()).map(|_| ())).0, Limit::Bytes);
+
+    // Text read by helpers that ignore errors still reports the limit
+    let rss = r#"<rss version="2.0"><channel><title>a title that is too long</title></channel></rss>"#;
+    let parser = parser::Builder::new().max_text_length(10).build();
+    assert_eq!(exceeded(parser.parse(rss.as_bytes())), (Limit::TextLength, Some("/rss/channel/title".into())));
+}
+
+// A source that never ends, repeating the content following the prefix
+struct Endless {
+    content: Vec<u8>,
+    prefix: usize,
+    position: usize,
+}
+
+impl Endless {
+    fn new(prefix: &str, repeated: &str) -> Endless {
+        Endless {
+            content: [prefix, repeated].concat().into_bytes(),
+            prefix: prefix.len(),
+            position: 0,
+        }
+    }
+}
+
+impl std::io::Read for Endless {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let count = buf.len().min(self.content.len() - self.position);
+        buf[..count].copy_from_slice(&self.content[self.position..self.position + count]);
+        self.position += count;
+        if self.position == self.content.len() {
+            self.position = self.prefix;
+        }
+        Ok(count)
+    }
+}
+
+// Verifies the limits stop the parser before content beyond them is accumulated
+#[test]
+fn limits_endless() {
+    let parser = parser::Builder::new().max_text_length(1024).build();
+    let source = Endless::new(r#"<rss version="2.0"><channel><title>"#, "fish &amp; <![CDATA[chips]]> ");
+    assert_eq!(exceeded(parser.parse(source)), (Limit::TextLength, Some("/rss/channel/title".into())));
+
+    let parser = parser::Builder::new().max_depth(32).build();
+    let source = Endless::new(r#"<rss version="2.0"><channel>"#, "<div>");
+    assert_eq!(exceeded(parser.parse(source)).0, Limit::Depth);
+
+    let parser = parser::Builder::new().max_entries(100).build();
+    let source = Endless::new(r#"{"version": "https://jsonfeed.org/version/1.1", "items": ["#, r#"{"id": "1"}, "#);
+    assert_eq!(exceeded(parser.parse(source)).0, Limit::Entries);
+}
+
+// Verifies HTML named entities are resolved in text and attributes unless disabled
+#[test]
+fn html_entities() {
+    let xml = r#"<rss version="2.0"><channel>
+    <title>Caf&eacute; &amp; Bar&nbsp;&mdash; &#8220;news&#8221; &bogus;</title>
+    <item><title>one</title><category domain="caf&eacute;">caf&eacute;</category></item>
+</channel></rss>"#;
+
+    // Entities unknown to both HTML and XML are retained as-is
+    let feed = parser::parse(xml.as_bytes()).unwrap();
+    assert_eq!(feed.title.unwrap().content, "Caf\u{e9} & Bar\u{a0}\u{2014} \u{201c}news\u{201d} &bogus;");
+    let category = &feed.entries[0].categories[0];
+    assert_eq!(category.term, "caf\u{e9}");
+    assert_eq!(category.scheme.as_deref(), Some("caf\u{e9}"));
+
+    // Streaming resolves entities in the same manner
+    let parser = parser::Builder::new().build();
+    let entry = parser.parse_streaming(xml.as_bytes()).unwrap().next().unwrap().unwrap();
+    assert_eq!(entry.categories[0].term, "caf\u{e9}");
+
+    // When disabled, only the entities predefined by XML are resolved
+    let parser = parser::Builder::new().html_entities(false).build();
+    let feed = parser.parse(xml.as_bytes()).unwrap();
+    assert_eq!(feed.title.unwrap().content, "Caf&eacute; & Bar&nbsp;&mdash; \u{201c}news\u{201d} &bogus;");
+    let category = &feed.entries[0].categories[0];
+    assert_eq!(category.term, "caf&eacute;");
+    assert_eq!(category.scheme.as_deref(), Some("caf&eacute;"));
+}
+
+// Verifies malformed XML is repaired when recovering
+#[test]
+fn recover_xml() {
+    let xml = r#"<br />
+<b>Warning</b>: Undefined variable $feed in <b>/var/www/feed.php</b>
+<?xml version="1.0"?>
+<rss version="2.0"><channel>
+    <title>Fish & Chips</title>
+    <link>https://example.com/?a=1&b=2</link>
+    <item><title>first</title><description>line<br>line</description></p></item>
+    <item><title>second</title><guid>2"#;
+
+    // Each of these fails the feed by default
+    assert!(parser::parse(xml.as_bytes()).is_err());
+
+    let parser = parser::Builder::new().recover_xml(true).build();
+    let feed = parser.parse(xml.as_bytes()).unwrap();
+    assert_eq!(feed.title.unwrap().content, "Fish & Chips");
+    assert_eq!(feed.links[0].href, "https://example.com/?a=1&b=2");
+    assert_eq!(feed.entries.len(), 2);
+    assert_eq!(feed.entries[0].title.as_ref().unwrap().content, "first");
+    assert_eq!(feed.entries[1].id, "2");
+
+    // Ending the content prematurely is reported within the innermost element
+    let (_, warnings) = parser.parse_with_report(xml.as_bytes()).unwrap();
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].kind, WarningKind::TruncatedContent);
+    assert_eq!(warnings[0].path, "/rss/channel/item[2]/guid");
+    let truncated = format!("{}</guid></item><item><title>third</title><description>a <![CDATA[b", xml);
+    let (feed, warnings) = parser.parse_with_report(truncated.as_bytes()).unwrap();
+    assert_eq!(feed.entries.len(), 3);
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].kind, WarningKind::TruncatedContent);
+    assert_eq!(warnings[0].path, "/rss/channel/item[3]/description");
+    assert!(warnings[0].value.contains("CDATA"), "{}", warnings[0].value);
+
+    // Streaming recovers in the same manner
+    let entries: Vec<_> = parser.parse_streaming(xml.as_bytes()).unwrap().collect();
+    assert_eq!(entries.len(), 2);
+    assert!(entries.iter().all(Result::is_ok));
+
+    // Errors in the recovered content report positions within the original source
+    let parser = parser::Builder::new().recover_xml(true).max_depth(4).build();
+    let error = parser.parse(xml.as_bytes()).unwrap_err();
+    let position = error.position().unwrap();
+    assert_eq!((position.line, position.column), (7, 48));
+    assert_eq!(position.path, "/rss/channel/item/description/br");
+}
+
+// Verifies the format is determined by the start of the document, rather than content preceding it when recovering
+#[test]
+fn root_char() {
+    assert_eq!(parser::root_char(b"\xEF\xBB\xBF\n<?xml version=\"1.0\"?><rss/>", true), Some('<'));
+    assert_eq!(parser::root_char(b"<!-- generated --><feed/>", true), Some('<'));
+    assert_eq!(parser::root_char(b" {\"version\": \"https://jsonfeed.org/version/1.1\"}", true), Some('{'));
+
+    // Markup and braces in the content preceding the document are skipped
+    assert_eq!(
+        parser::root_char(b"<b>Warning</b>: a < b\n{\"version\": \"https://jsonfeed.org/version/1.1\"}", true),
+        Some('{')
+    );
+    assert_eq!(parser::root_char(b"Notice: {undefined}\n<?xml version=\"1.0\"?><rss/>", true), Some('<'));
+    assert_eq!(parser::root_char(b"Notice: {undefined}\n<rdf:RDF/>", true), Some('<'));
+
+    // Otherwise the first of either character is used
+    assert_eq!(parser::root_char(b"<html></html>", true), Some('<'));
+    assert_eq!(parser::root_char(b"no document", true), None);
+
+    // Without recovery, the first of either character is always used
+    assert_eq!(parser::root_char(b"Notice: {undefined}\n<rss/>", false), Some('{'));
+    assert_eq!(parser::root_char(b"\xEF\xBB\xBF<?xml version=\"1.0\"?><rss/>", false), Some('<'));
+}
+
+// Verifies well-known prefixes are recognised when the feed does not declare them
+#[test]
+fn undeclared_prefixes() {
+    let xml = r#"<rss version="2.0"><channel>
+    <title>feed</title>
+    <atom:link href="https://example.com/feed.xml" rel="self"/>
+    <item>
+        <title>episode</title>
+        <dc:creator>Alice</dc:creator>
+        <content:encoded>&lt;p&gt;body&lt;/p&gt;</content:encoded>
+        <itunes:duration>60</itunes:duration>
+        <media:thumbnail url="https://example.com/thumbnail.jpg"/>
+        <mrss:title>alias</mrss:title>
+    </item>
+</channel></rss>"#;
+
+    let feed = parser::parse(xml.as_bytes()).unwrap();
+    assert_eq!(feed.links[0].rel.as_deref(), Some("self"));
+    let entry = &feed.entries[0];
+    assert_eq!(entry.authors[0].name, "Alice");
+    assert_eq!(entry.content.as_ref().unwrap().body.as_deref(), Some("<p>body</p>"));
+    assert_eq!(entry.media[0].thumbnails[0].image.uri, "https://example.com/thumbnail.jpg");
+    assert_eq!(entry.media[0].duration, Some(std::time::Duration::from_secs(60)));
+    assert!(entry.media[0].title.is_none());
+
+    // Aliases register further prefixes
+    let parser = parser::Builder::new().prefix_alias("mrss", "http://search.yahoo.com/mrss/").build();
+    let feed = parser.parse(xml.as_bytes()).unwrap();
+    assert_eq!(feed.entries[0].media[0].title.as_ref().unwrap().content, "alias");
+
+    // Prefixes declared by the feed take precedence
+    let xml = r#"<rss version="2.0" xmlns:dc="https://example.com/other"><channel>
+    <item><title>episode</title><dc:creator>Alice</dc:creator></item>
+</channel></rss>"#;
+    let parser = parser::Builder::new().prefix_alias("dc", "http://purl.org/dc/elements/1.1/").build();
+    let feed = parser.parse(xml.as_bytes()).unwrap();
+    assert!(feed.entries[0].authors.is_empty());
+}
+
+// Verifies element names are matched regardless of case when requested
+#[test]
+fn normalize_names() {
+    let xml = r#"<rss version="2.0" xmlns:dc="http://purl.org/dc/elements/1.1/"><channel>
+    <Title>feed</Title>
+    <lastbuilddate>Wed, 22 Jan 2020 10:58:02 GMT</lastbuilddate>
+    <item>
+        <title>one</title>
+        <PubDate>Wed, 22 Jan 2020 10:58:02 GMT</PubDate>
+        <Description>first</Description>
+    </item>
+    <item><title>two</title><dc:Date>2020-01-22T10:58:02Z</dc:Date></item>
+</channel></rss>"#;
+
+    // Exact matches only by default
+    let feed = parser::parse(xml.as_bytes()).unwrap();
+    assert!(feed.title.is_none());
+    assert!(feed.updated.is_none());
+    assert!(feed.entries[0].published.is_none());
+
+    // Enabled by default when parsing leniently
+    let check = |feed: Feed| {
+        assert_eq!(feed.title.unwrap().content, "feed");
+        assert!(feed.updated.is_some());
+        assert!(feed.entries[0].published.is_some());
+        assert_eq!(feed.entries[0].summary.as_ref().unwrap().content, "first");
+        assert!(feed.entries[1].published.is_some());
+    };
+    check(parser::Builder::new().normalize_names(true).build().parse(xml.as_bytes()).unwrap());
+    check(parser::Builder::new().lenient(true).build().parse(xml.as_bytes()).unwrap());
+
+    // ...unless disabled
+    let parser = parser::Builder::new().lenient(true).normalize_names(false).build();
+    assert!(parser.parse(xml.as_bytes()).unwrap().title.is_none());
+}
+
+// Verifies that elements in unsupported namespaces are retained as extensions
+#[test]
+fn extensions() {
+    let xml = r#"
+        <feed xmlns="http://www.w3.org/2005/Atom" xmlns:yt="http://www.youtube.com/xml/schemas/2015" xmlns:georss="http://www.georss.org/georss">
+            <title>feed</title>
+            <yt:channelId>UC1234</yt:channelId>
+            <entry>
+                <title>entry</title>
+                <yt:videoId>abc</yt:videoId>
+                <georss:point>45.256 -71.92</georss:point>
+                <georss:where xmlns:x="urn:example:x">
+                    <x:pos srsName="EPSG:4326"> 45.256 -71.92 </x:pos>
+                    <x:pos>1 2</x:pos>
+                </georss:where>
+            </entry>
+        </feed>"#;
+
+    const YT: &str = "http://www.youtube.com/xml/schemas/2015";
+    const GEORSS: &str = "http://www.georss.org/georss";
+    let feed = Feed::new(FeedType::Atom).extension(YT, Extension::new("channelId").value("UC1234"));
+    let entry = Entry::default()
+        .extension(YT, Extension::new("videoId").value("abc"))
+        .extension(GEORSS, Extension::new("point").value("45.256 -71.92"))
+        .extension(
+            GEORSS,
+            Extension::new("where")
+                .child("urn:example:x", Extension::new("pos").value("45.256 -71.92").attr("srsName", "EPSG:4326"))
+                .child("urn:example:x", Extension::new("pos").value("1 2")),
+        );
+
+    let actual = parser::parse(xml.as_bytes()).unwrap();
+    assert_eq!(actual.extensions, feed.extensions);
+    assert_eq!(actual.entries[0].extensions, entry.extensions);
+
+    // The streaming parser captures the same
+    let parser = parser::Builder::new().build();
+    let mut stream = parser.parse_streaming(xml.as_bytes()).unwrap();
+    assert_eq!(stream.feed().extensions, feed.extensions);
+    assert_eq!(stream.next().unwrap().unwrap().extensions, entry.extensions);
+}
+
+#[test]
+fn relocation() {
+    let parser = parser::Builder::new().build();
+
+    // The iTunes new feed URL takes precedence over the self link
+    let xml = r#"<rss version="2.0" xmlns:atom="http://www.w3.org/2005/Atom" xmlns:itunes="http://www.itunes.com/dtds/podcast-1.0.dtd"><channel>
+    <atom:link href="https://old.example.com/feed.xml" rel="self" type="application/rss+xml"/>
+    <itunes:new-feed-url>https://new.example.com/feed.xml</itunes:new-feed-url>
+</channel></rss>"#;
+    let feed = parser.parse(xml.as_bytes()).unwrap();
+    let relocation = feed.relocation("https://old.example.com/feed.xml").unwrap();
+    assert_eq!(relocation.url.as_str(), "https://new.example.com/feed.xml");
+    assert_eq!(relocation.source, RelocationSource::ItunesNewFeedUrl);
+
+    // The feed has not moved when fetched from the advertised URL (compared once normalised)
+    assert!(feed.relocation("https://NEW.example.com:443/feed.xml").is_none());
+
+    // Otherwise the self link of Atom and RSS feeds is reported, resolved against the fetched URL
+    let xml = r#"<feed xmlns="http://www.w3.org/2005/Atom">
+    <link href="https://example.com/"/>
+    <link href="/atom.xml" rel="self"/>
+</feed>"#;
+    let feed = parser.parse(xml.as_bytes()).unwrap();
+    let relocation = feed.relocation("https://example.com/feed").unwrap();
+    assert_eq!(relocation.url.as_str(), "https://example.com/atom.xml");
+    assert_eq!(relocation.source, RelocationSource::SelfLink);
+    assert!(feed.relocation("https://example.com/atom.xml").is_none());
+
+    // ...as is the feed URL of a JSON Feed
+    let json = r#"{"version": "https://jsonfeed.org/version/1.1", "title": "t", "home_page_url": "https://example.com/", "feed_url": "https://example.com/feed.json", "items": []}"#;
+    let feed = parser.parse(json.as_bytes()).unwrap();
+    let relocation = feed.relocation("http://example.com/feed.json").unwrap();
+    assert_eq!(relocation.url.as_str(), "https://example.com/feed.json");
+    assert_eq!(relocation.source, RelocationSource::SelfLink);
+    assert!(feed.relocation("https://example.com/feed.json").is_none());
+
+    // Feeds without either signal report nothing
+    let xml = r#"<rss version="2.0"><channel><link>https://example.com/</link></channel></rss>"#;
+    assert!(parser.parse(xml.as_bytes()).unwrap().relocation("https://example.com/rss").is_none());
+}
+
 // Verifies that a round-trip through the parser + serde works correctly over time
 #[test]
 fn serde_regression() {
     let fixture_root_dir = test::fixture_dir();
     find_fixture_files(&fixture_root_dir, |source_path, json_path| {
         // Parse the original fixture file
-        let data = fs::read(&source_path).unwrap();
+        let data = fs::read(source_path).unwrap();
         let parser = parser::Builder::default().sanitize_content(false).build();
         let mut feed = parser.parse(data.as_slice()).unwrap();
 
         // Parse the previously serialised form
-        let serde_data = fs::read(&json_path).unwrap();
+        let serde_data = fs::read(json_path).unwrap();
         let mut serde_feed = serde_json::from_slice(&serde_data).unwrap();
 
         // Basic check, and then try with replaced IDs too
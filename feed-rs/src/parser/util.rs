@@ -1,6 +1,7 @@
 use std::error::Error;
 use std::io::BufRead;
 use std::ops::Add;
+use std::str::FromStr;
 use std::sync::OnceLock;
 use std::time::Duration;
 
@@ -10,11 +11,11 @@ use url::Url;
 use uuid::Uuid;
 
 use fixes::PatSub;
-use model::{Link, Text};
+use model::{Extension, ExtensionMap, Feed, Link, Text};
 
 use crate::model;
-use crate::parser::{ParseFeedResult, Parser};
-use crate::xml::Element;
+use crate::parser::{ParseFeedError, ParseFeedResult, Parser, WarningKind};
+use crate::xml::{Element, NameValue};
 
 /// Set of regular expressions we use to clean up broken dates
 mod fixes {
@@ -80,6 +81,9 @@ static RFC1123_FORMAT_STR: &str = "%d %b %Y %H:%M:%S %z";
 /// Pluggable timestamp parser
 pub(crate) type TimestampParser = dyn Fn(&str) -> Option<DateTime<Utc>> + 'static;
 
+/// Receives the errors for entries skipped when parsing leniently
+pub(crate) type EntryErrorHandler = dyn Fn(&ParseFeedError);
+
 /// Pluggable ID (feed or entry) generator
 pub(crate) type IdGenerator = dyn Fn(&[Link], &Option<Text>, Option<&str>) -> String;
 
@@ -88,6 +92,44 @@ pub(crate) fn handle_encoded<R: BufRead>(element: Element<R>) -> ParseFeedResult
     Ok(element.children_as_string()?.map(Text::html))
 }
 
+// Sanitizes the feed level text fields that may contain HTML
+pub(crate) fn sanitize_feed(feed: &mut Feed) {
+    if let Some(t) = feed.description.as_mut() {
+        t.sanitize()
+    }
+    if let Some(t) = feed.rights.as_mut() {
+        t.sanitize()
+    }
+    if let Some(t) = feed.title.as_mut() {
+        t.sanitize()
+    }
+}
+
+// Handles an element in a namespace that is not otherwise supported, recording it (and its children) as an extension
+pub(crate) fn handle_extension<R: BufRead>(element: Element<R>, extensions: &mut ExtensionMap) -> ParseFeedResult<()> {
+    let mut extension = Extension {
+        name: element.name.clone(),
+        ..Default::default()
+    };
+
+    // Namespace declarations are not retained as attributes
+    for attr in &element.attributes {
+        if attr.name != "xmlns" && !attr.name.starts_with("xmlns:") {
+            extension.attrs.insert(attr.name.clone(), attr.value.clone());
+        }
+    }
+
+    // Surrounding whitespace (including that separating child elements) is not retained
+    extension.value = element.child_as_text().map(|text| text.trim().to_string()).filter(|text| !text.is_empty());
+    for child in element.children() {
+        handle_extension(child?, &mut extension.children)?;
+    }
+
+    let uri = element.namespace_uri.unwrap_or_default();
+    extensions.entry(uri).or_default().entry(element.name).or_default().push(extension);
+    Ok(())
+}
+
 // Handles "xml:lang" as an attribute (e.g. in Atom feeds)
 pub(crate) fn handle_language_attr<R: BufRead>(element: &Element<R>) -> Option<String> {
     element.attr_value("xml:lang")
@@ -115,12 +157,34 @@ pub(crate) fn handle_text<R: BufRead>(element: Element<R>) -> Option<Text> {
 /// Handles date/time
 pub(crate) fn handle_timestamp<R: BufRead>(parser: &Parser, element: Element<R>) -> Option<DateTime<Utc>> {
     if let Some(text) = element.child_as_text() {
-        parser.parse_timestamp(&text)
+        let timestamp = parser.parse_timestamp(&text);
+        if timestamp.is_none() {
+            element.warn(WarningKind::InvalidTimestamp, &text);
+        }
+        timestamp
     } else {
         None
     }
 }
 
+/// Parses the text of an element, recording a warning if it is invalid
+pub(crate) fn parse_text<T: FromStr, R: BufRead>(element: &Element<R>, kind: WarningKind, text: &str) -> Option<T> {
+    let parsed = text.parse::<T>().ok();
+    if parsed.is_none() {
+        element.warn(kind, text);
+    }
+    parsed
+}
+
+/// Parses the value of an attribute, recording a warning if it is invalid
+pub(crate) fn parse_attr<T: FromStr, R: BufRead>(element: &Element<R>, kind: WarningKind, attr: &NameValue) -> Option<T> {
+    let parsed = attr.value.parse::<T>().ok();
+    if parsed.is_none() {
+        element.warn_attr(kind, &attr.name, &attr.value);
+    }
+    parsed
+}
+
 /// Simplifies the "if let ... = parse ... assign" block
 pub(crate) fn if_some_then<T, F: FnOnce(T)>(v: Option<T>, func: F) {
     if let Some(v) = v {
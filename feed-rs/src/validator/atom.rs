@@ -0,0 +1,96 @@
+use std::collections::HashSet;
+use std::io::BufRead;
+
+use crate::parser::ParseFeedResult;
+use crate::validator::{attr_path, path, Report};
+use crate::xml::{Element, NS};
+
+// Elements required in both feeds and entries (https://tools.ietf.org/html/rfc4287#section-4.1.1)
+const REQUIRED: [&str; 3] = ["id", "title", "updated"];
+
+// Validates an Atom feed
+pub(super) fn validate_feed<R: BufRead>(report: &mut Report, root: Element<R>) -> ParseFeedResult<()> {
+    let feed_path = path(&root);
+    let mut found = HashSet::new();
+
+    for child in root.children() {
+        let child = child?;
+        match child.ns_and_tag() {
+            (NS::Atom, "entry") => validate_entry(report, child)?,
+
+            (NS::Atom, "icon") | (NS::Atom, "logo") => {
+                found.insert(child.name.clone());
+                if let Some(uri) = child.child_as_text() {
+                    report.uri(path(&child), &uri, child.xml_base.is_some());
+                }
+            }
+
+            (NS::Atom, _) => validate_common(report, &mut found, child),
+
+            // Nothing required for unknown elements
+            _ => {}
+        }
+    }
+
+    report.require(&feed_path, &REQUIRED, &found);
+    Ok(())
+}
+
+// Validates an Atom entry
+pub(super) fn validate_entry<R: BufRead>(report: &mut Report, entry: Element<R>) -> ParseFeedResult<()> {
+    let entry_path = path(&entry);
+    let mut found = HashSet::new();
+
+    for child in entry.children() {
+        let child = child?;
+        match child.ns_and_tag() {
+            (NS::Atom, "id") => {
+                found.insert(child.name.clone());
+                if let Some(id) = child.child_as_text() {
+                    report.id(path(&child), &id);
+                }
+            }
+
+            (NS::Atom, "published") => {
+                if let Some(text) = child.child_as_text() {
+                    report.rfc3339(path(&child), &text);
+                }
+            }
+
+            (NS::Atom, "content") => {
+                if let Some(src) = child.attr_value("src") {
+                    report.uri(attr_path(&child, "src"), &src, child.xml_base.is_some());
+                }
+            }
+
+            (NS::Atom, _) => validate_common(report, &mut found, child),
+
+            // Nothing required for unknown elements
+            _ => {}
+        }
+    }
+
+    report.require(&entry_path, &REQUIRED, &found);
+    Ok(())
+}
+
+// Validates the elements shared by feeds and entries
+fn validate_common<R: BufRead>(report: &mut Report, found: &mut HashSet<String>, child: Element<R>) {
+    found.insert(child.name.clone());
+
+    match child.name.as_str() {
+        "updated" => {
+            if let Some(text) = child.child_as_text() {
+                report.rfc3339(path(&child), &text);
+            }
+        }
+
+        "link" => {
+            if let Some(href) = child.attr_value("href") {
+                report.uri(attr_path(&child, "href"), &href, child.xml_base.is_some());
+            }
+        }
+
+        _ => {}
+    }
+}
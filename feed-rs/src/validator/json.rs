@@ -0,0 +1,83 @@
+use std::collections::HashSet;
+use std::io::Read;
+
+use serde_json::{Map, Value};
+
+use crate::parser::{ParseErrorKind, ParseFeedError, ParseFeedResult};
+use crate::validator::{Report, Severity, ViolationKind};
+
+// Members required at the top level (https://www.jsonfeed.org/version/1.1/#top-level-a-name-top-level-a)
+const FEED_REQUIRED: [&str; 3] = ["version", "title", "items"];
+
+// Members of the feed and items containing URLs
+const FEED_URLS: [&str; 4] = ["home_page_url", "feed_url", "icon", "favicon"];
+const ITEM_URLS: [&str; 4] = ["url", "external_url", "image", "banner_image"];
+
+// Validates a JSON Feed
+pub(super) fn validate<R: Read>(report: &mut Report, input: R, has_base: bool) -> ParseFeedResult<()> {
+    let value: Value = serde_json::from_reader(input)?;
+    let feed = value.as_object().ok_or(ParseFeedError::ParseError(ParseErrorKind::NoFeedRoot, None))?;
+
+    report.require("/", &FEED_REQUIRED, &members(feed));
+    validate_urls(report, "", feed, &FEED_URLS, has_base);
+
+    if let Some(items) = feed.get("items").and_then(Value::as_array) {
+        for (index, item) in items.iter().enumerate() {
+            if let Some(item) = item.as_object() {
+                validate_item(report, &format!("/items/{}", index), item, has_base);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// Validates an item
+fn validate_item(report: &mut Report, item_path: &str, item: &Map<String, Value>, has_base: bool) {
+    let found = members(item);
+    report.require(item_path, &["id"], &found);
+
+    // An item must have content, in HTML or plain text
+    if !found.contains("content_html") && !found.contains("content_text") {
+        report.push(
+            ViolationKind::MissingElement,
+            Severity::Error,
+            item_path.to_string(),
+            "content_html or content_text",
+        );
+    }
+
+    if let Some(id) = item.get("id").and_then(Value::as_str) {
+        report.id(format!("{}/id", item_path), id);
+    }
+
+    for member in ["date_published", "date_modified"] {
+        if let Some(text) = item.get(member).and_then(Value::as_str) {
+            report.rfc3339(format!("{}/{}", item_path, member), text);
+        }
+    }
+
+    validate_urls(report, item_path, item, &ITEM_URLS, has_base);
+
+    if let Some(attachments) = item.get("attachments").and_then(Value::as_array) {
+        for (index, attachment) in attachments.iter().enumerate() {
+            if let Some(attachment) = attachment.as_object() {
+                validate_urls(report, &format!("{}/attachments/{}", item_path, index), attachment, &["url"], has_base);
+            }
+        }
+    }
+}
+
+// Checks the members containing URLs are absolute
+fn validate_urls(report: &mut Report, object_path: &str, object: &Map<String, Value>, names: &[&str], has_base: bool) {
+    for name in names {
+        if let Some(uri) = object.get(*name).and_then(Value::as_str) {
+            report.uri(format!("{}/{}", object_path, name), uri, has_base);
+        }
+    }
+}
+
+// Names of the members of an object, excluding those with a null value
+fn members(object: &Map<String, Value>) -> HashSet<String> {
+    object.iter().filter(|(_, value)| !value.is_null()).map(|(name, _)| name.clone()).collect()
+}
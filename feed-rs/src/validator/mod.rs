@@ -0,0 +1,252 @@
+use std::collections::HashSet;
+use std::fmt;
+use std::io::{BufRead, BufReader, Read};
+
+use chrono::DateTime;
+use url::Url;
+
+use crate::parser::{first_char, ParseErrorKind, ParseFeedError, ParseFeedResult};
+use crate::xml::{Element, ElementSource, NS};
+
+mod atom;
+mod json;
+mod rss;
+
+#[cfg(test)]
+mod tests;
+
+/// A departure from the specification of the feed format
+#[derive(Clone, Debug, PartialEq)]
+pub struct Violation {
+    /// The nature of the violation
+    pub kind: ViolationKind,
+    /// How seriously the violation affects consumers of the feed
+    pub severity: Severity,
+    /// Path to the element (with "/@name" for an attribute) or JSON member (as a JSON Pointer), or its parent if missing
+    pub path: String,
+    /// The value as found in the source, or the name of the missing element
+    pub value: String,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {} \"{}\" in {}", self.severity, self.kind, self.value, self.path)
+    }
+}
+
+/// Categories of violation
+#[derive(Clone, Debug, PartialEq)]
+pub enum ViolationKind {
+    /// An element (or JSON member) required by the specification is not present
+    MissingElement,
+    /// A timestamp that is not in the format required by the specification (RFC 3339 for Atom and JSON Feed, RFC 822 for RSS)
+    InvalidTimestamp,
+    /// A relative URI, where there is no base URI to resolve it against
+    RelativeUri,
+    /// An entry with the same ID as an earlier entry
+    DuplicateId,
+}
+
+impl fmt::Display for ViolationKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ViolationKind::MissingElement => f.write_str("missing element"),
+            ViolationKind::InvalidTimestamp => f.write_str("invalid timestamp"),
+            ViolationKind::RelativeUri => f.write_str("relative URI"),
+            ViolationKind::DuplicateId => f.write_str("duplicate ID"),
+        }
+    }
+}
+
+/// How seriously a violation affects consumers of the feed
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    /// Consumers are likely to cope, but the feed should be corrected
+    Warning,
+    /// The feed does not conform to the specification, and consumers may reject it or lose content
+    Error,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Severity::Warning => f.write_str("warning"),
+            Severity::Error => f.write_str("error"),
+        }
+    }
+}
+
+/// Checks feeds against the specification of their format
+///
+/// Unlike the `Parser`, which tolerates (and where possible repairs) invalid content, the validator reports each departure from the specification.
+pub struct Validator {
+    base_uri: Option<String>,
+}
+
+impl Validator {
+    /// Validates the input (Atom, a flavour of RSS or JSON Feed), returning the violations in document order
+    ///
+    /// Content that cannot be read as a feed at all (e.g. malformed XML) results in an error, as for the parser.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - A source of content such as a string, file etc.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed_rs::validator::{self, Severity, ViolationKind};
+    /// let xml = r#"
+    /// <rss version="2.0">
+    ///   <channel>
+    ///     <title>sample feed</title>
+    ///     <link>https://example.com/</link>
+    ///     <pubDate>2019-08-26 10:00</pubDate>
+    ///   </channel>
+    /// </rss>
+    /// "#;
+    /// let violations = validator::validate(xml.as_bytes()).unwrap();
+    /// assert_eq!(violations.len(), 2);
+    /// assert_eq!(violations[0].kind, ViolationKind::InvalidTimestamp);
+    /// assert_eq!(violations[0].path, "/rss/channel/pubDate");
+    /// assert_eq!(violations[1].kind, ViolationKind::MissingElement);
+    /// assert_eq!(violations[1].severity, Severity::Error);
+    /// assert_eq!(violations[1].value, "description");
+    /// ```
+    pub fn validate<R: Read>(&self, source: R) -> ParseFeedResult<Vec<Violation>> {
+        let mut input = BufReader::new(source);
+        let mut report = Report::default();
+
+        match first_char(&mut input, false)? {
+            Some('<') => self.validate_xml(&mut report, input)?,
+
+            Some('{') => json::validate(&mut report, input, self.base_uri.is_some())?,
+
+            _ => return Err(ParseFeedError::ParseError(ParseErrorKind::NoFeedRoot, None)),
+        }
+
+        Ok(report.violations)
+    }
+
+    // Dispatches to the checks for the format indicated by the root element
+    fn validate_xml<R: BufRead>(&self, report: &mut Report, input: R) -> ParseFeedResult<()> {
+        let source = ElementSource::new(input, self.base_uri.as_deref())?;
+        let root = source.root()?.ok_or(ParseFeedError::ParseError(ParseErrorKind::NoFeedRoot, None))?;
+
+        let version = root.attr_value("version");
+        let result = match (root.name.as_str(), version.as_deref()) {
+            ("feed", _) => {
+                source.set_default_default_namespace(NS::Atom);
+                atom::validate_feed(report, root)
+            }
+            ("entry", _) => {
+                source.set_default_default_namespace(NS::Atom);
+                atom::validate_entry(report, root)
+            }
+            ("rss", Some("2.0")) | ("rss", Some("0.91")) | ("rss", Some("0.92")) => {
+                source.set_default_default_namespace(NS::RSS);
+                rss::validate_rss(report, root)
+            }
+            ("RDF", _) => {
+                source.set_default_default_namespace(NS::RSS);
+                rss::validate_rdf(report, root)
+            }
+            _ => Err(ParseFeedError::ParseError(ParseErrorKind::NoFeedRoot, None)),
+        };
+
+        result.map_err(|e| e.at(|| source.position()))
+    }
+}
+
+/// Validates the provided source with the defaults
+pub fn validate<R: Read>(source: R) -> ParseFeedResult<Vec<Violation>> {
+    Builder::new().build().validate(source)
+}
+
+/// Builder to create instances of `Validator`
+#[derive(Default)]
+pub struct Builder {
+    base_uri: Option<String>,
+}
+
+impl Builder {
+    /// Create a new instance of the builder
+    pub fn new() -> Builder {
+        Builder::default()
+    }
+
+    /// Source of the content, against which relative URIs may be resolved (so are not reported)
+    pub fn base_uri<S: AsRef<str>>(mut self, uri: Option<S>) -> Self {
+        self.base_uri = uri.map(|s| s.as_ref().to_string());
+        self
+    }
+
+    /// Create a new instance of the validator
+    pub fn build(self) -> Validator {
+        Validator { base_uri: self.base_uri }
+    }
+}
+
+// Violations found so far, with the state required to detect duplicates
+#[derive(Default)]
+struct Report {
+    violations: Vec<Violation>,
+    ids: HashSet<String>,
+}
+
+impl Report {
+    // Records a violation
+    fn push(&mut self, kind: ViolationKind, severity: Severity, path: String, value: &str) {
+        self.violations.push(Violation {
+            kind,
+            severity,
+            path,
+            value: value.to_string(),
+        });
+    }
+
+    // Records each of the required elements that were not found
+    fn require(&mut self, path: &str, required: &[&str], found: &HashSet<String>) {
+        for name in required.iter().filter(|name| !found.contains(**name)) {
+            self.push(ViolationKind::MissingElement, Severity::Error, path.to_string(), name);
+        }
+    }
+
+    // Checks the timestamp conforms to RFC 3339 (Atom, JSON Feed)
+    fn rfc3339(&mut self, path: String, text: &str) {
+        if DateTime::parse_from_rfc3339(text.trim()).is_err() {
+            self.push(ViolationKind::InvalidTimestamp, Severity::Error, path, text);
+        }
+    }
+
+    // Checks the timestamp conforms to RFC 822 (RSS)
+    fn rfc822(&mut self, path: String, text: &str) {
+        if DateTime::parse_from_rfc2822(text.trim()).is_err() {
+            self.push(ViolationKind::InvalidTimestamp, Severity::Error, path, text);
+        }
+    }
+
+    // Checks the URI is absolute, unless there is a base to resolve it against
+    fn uri(&mut self, path: String, uri: &str, has_base: bool) {
+        if !has_base && Url::parse(uri.trim()) == Err(url::ParseError::RelativeUrlWithoutBase) {
+            self.push(ViolationKind::RelativeUri, Severity::Warning, path, uri);
+        }
+    }
+
+    // Checks the ID has not been used by an earlier entry
+    fn id(&mut self, path: String, id: &str) {
+        if !self.ids.insert(id.trim().to_string()) {
+            self.push(ViolationKind::DuplicateId, Severity::Error, path, id);
+        }
+    }
+}
+
+// Path to the element most recently read from the source
+fn path<R: BufRead>(element: &Element<R>) -> String {
+    element.source().position().path
+}
+
+// Path to an attribute of the element most recently read from the source
+fn attr_path<R: BufRead>(element: &Element<R>, attr: &str) -> String {
+    format!("{}/@{}", path(element), attr)
+}
@@ -0,0 +1,175 @@
+use std::collections::HashSet;
+use std::io::BufRead;
+
+use crate::parser::ParseFeedResult;
+use crate::validator::{attr_path, path, Report, Severity, ViolationKind};
+use crate::xml::{Element, NS};
+
+// Elements required in a channel (https://www.rssboard.org/rss-specification#requiredChannelElements)
+const CHANNEL_REQUIRED: [&str; 3] = ["title", "link", "description"];
+
+// Elements required in an image (https://www.rssboard.org/rss-specification#ltimagegtSubelementOfLtchannelgt)
+const IMAGE_REQUIRED: [&str; 3] = ["url", "title", "link"];
+
+// Elements required in an RSS 1.0 item (https://web.resource.org/rss/1.0/spec#s5.5)
+const RDF_ITEM_REQUIRED: [&str; 2] = ["title", "link"];
+
+// Validates an RSS 0.9x or 2.0 feed
+pub(super) fn validate_rss<R: BufRead>(report: &mut Report, root: Element<R>) -> ParseFeedResult<()> {
+    let rss_path = path(&root);
+    let mut found = false;
+
+    for child in root.children() {
+        let child = child?;
+        if child.ns_and_tag() == (NS::RSS, "channel") {
+            found = true;
+            validate_channel(report, child)?;
+        }
+    }
+
+    if !found {
+        report.push(ViolationKind::MissingElement, Severity::Error, rss_path, "channel");
+    }
+    Ok(())
+}
+
+// Validates an RSS 1.0 feed
+pub(super) fn validate_rdf<R: BufRead>(report: &mut Report, root: Element<R>) -> ParseFeedResult<()> {
+    let rdf_path = path(&root);
+    let mut found = false;
+
+    for child in root.children() {
+        let child = child?;
+        match child.ns_and_tag() {
+            (NS::RSS, "channel") => {
+                found = true;
+                validate_children(report, child, &CHANNEL_REQUIRED)?;
+            }
+
+            (NS::RSS, "image") => validate_children(report, child, &IMAGE_REQUIRED)?,
+
+            (NS::RSS, "item") => {
+                if let Some(about) = child.attr_value("rdf:about") {
+                    report.id(attr_path(&child, "rdf:about"), &about);
+                }
+                validate_children(report, child, &RDF_ITEM_REQUIRED)?;
+            }
+
+            // Nothing required for unknown elements
+            _ => {}
+        }
+    }
+
+    if !found {
+        report.push(ViolationKind::MissingElement, Severity::Error, rdf_path, "channel");
+    }
+    Ok(())
+}
+
+// Validates the <channel> element of an RSS 0.9x or 2.0 feed
+fn validate_channel<R: BufRead>(report: &mut Report, channel: Element<R>) -> ParseFeedResult<()> {
+    let channel_path = path(&channel);
+    let mut found = HashSet::new();
+
+    for child in channel.children() {
+        let child = child?;
+        if child.namespace == NS::RSS {
+            found.insert(child.name.clone());
+        }
+
+        match child.ns_and_tag() {
+            (NS::RSS, "item") => validate_item(report, child)?,
+
+            (NS::RSS, "image") => validate_children(report, child, &IMAGE_REQUIRED)?,
+
+            (NS::RSS, "pubDate") | (NS::RSS, "lastBuildDate") => {
+                if let Some(text) = child.child_as_text() {
+                    report.rfc822(path(&child), &text);
+                }
+            }
+
+            (NS::RSS, "link") => validate_uri(report, &child),
+
+            // Nothing required for other elements
+            _ => {}
+        }
+    }
+
+    report.require(&channel_path, &CHANNEL_REQUIRED, &found);
+    Ok(())
+}
+
+// Validates an <item> of an RSS 0.9x or 2.0 feed
+fn validate_item<R: BufRead>(report: &mut Report, item: Element<R>) -> ParseFeedResult<()> {
+    let item_path = path(&item);
+    let mut found = HashSet::new();
+
+    for child in item.children() {
+        let child = child?;
+        if child.namespace == NS::RSS {
+            found.insert(child.name.clone());
+        }
+
+        match child.ns_and_tag() {
+            (NS::RSS, "pubDate") => {
+                if let Some(text) = child.child_as_text() {
+                    report.rfc822(path(&child), &text);
+                }
+            }
+
+            (NS::RSS, "link") | (NS::RSS, "comments") => validate_uri(report, &child),
+
+            (NS::RSS, "guid") => {
+                // The GUID is a permalink unless stated otherwise
+                let permalink = child.attr_value("isPermaLink").as_deref() != Some("false");
+                if let Some(guid) = child.child_as_text() {
+                    report.id(path(&child), &guid);
+                    if permalink {
+                        report.uri(path(&child), &guid, child.xml_base.is_some());
+                    }
+                }
+            }
+
+            (NS::RSS, "enclosure") => {
+                if let Some(url) = child.attr_value("url") {
+                    report.uri(attr_path(&child, "url"), &url, child.xml_base.is_some());
+                }
+            }
+
+            // Nothing required for other elements
+            _ => {}
+        }
+    }
+
+    // An item must have either a title or a description
+    if !found.contains("title") && !found.contains("description") {
+        report.push(ViolationKind::MissingElement, Severity::Error, item_path, "title or description");
+    }
+    Ok(())
+}
+
+// Validates an element with simple children, of which some are required
+fn validate_children<R: BufRead>(report: &mut Report, element: Element<R>, required: &[&str]) -> ParseFeedResult<()> {
+    let element_path = path(&element);
+    let mut found = HashSet::new();
+
+    for child in element.children() {
+        let child = child?;
+        if child.namespace == NS::RSS {
+            if matches!(child.name.as_str(), "link" | "url") {
+                validate_uri(report, &child);
+            }
+            found.insert(child.name.clone());
+        }
+    }
+
+    report.require(&element_path, required, &found);
+    Ok(())
+}
+
+// Validates an element containing a URI
+fn validate_uri<R: BufRead>(report: &mut Report, element: &Element<R>) {
+    if let Some(uri) = element.child_as_text() {
+        report.uri(path(element), &uri, element.xml_base.is_some());
+    }
+}
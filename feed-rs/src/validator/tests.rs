@@ -0,0 +1,145 @@
+use crate::util::test;
+use crate::validator::{self, Severity, Violation, ViolationKind};
+
+// Summarises the violations for comparison
+fn summarise(violations: &[Violation]) -> Vec<(ViolationKind, Severity, &str, &str)> {
+    violations
+        .iter()
+        .map(|v| (v.kind.clone(), v.severity, v.path.as_str(), v.value.as_str()))
+        .collect()
+}
+
+// Valid feeds have no violations
+#[test]
+fn test_valid() {
+    for fixture in ["atom/atom_spec_1.xml", "rss2/rss_2.0_spec_1.xml", "jsonfeed/jsonfeed_spec_1.json"] {
+        let source = test::fixture_as_raw(fixture);
+        let violations = validator::validate(source.as_slice()).unwrap();
+        assert!(violations.is_empty(), "{}: {:?}", fixture, violations);
+    }
+}
+
+#[test]
+fn test_atom() {
+    let xml = r#"<feed xmlns="http://www.w3.org/2005/Atom">
+    <title>feed</title>
+    <updated>2003-12-13T18:30:02</updated>
+    <link href="/index.html"/>
+    <entry>
+        <id>urn:1</id>
+        <updated>2003-12-13T18:30:02Z</updated>
+    </entry>
+    <entry xml:base="https://example.com/">
+        <id>urn:1</id>
+        <title>second</title>
+        <updated>2003-12-13T18:30:02Z</updated>
+        <published>13 Dec 2003</published>
+        <link href="2.html"/>
+    </entry>
+</feed>"#;
+    let violations = validator::validate(xml.as_bytes()).unwrap();
+    assert_eq!(
+        summarise(&violations),
+        vec![
+            (ViolationKind::InvalidTimestamp, Severity::Error, "/feed/updated", "2003-12-13T18:30:02"),
+            (ViolationKind::RelativeUri, Severity::Warning, "/feed/link/@href", "/index.html"),
+            (ViolationKind::MissingElement, Severity::Error, "/feed/entry", "title"),
+            (ViolationKind::DuplicateId, Severity::Error, "/feed/entry[2]/id", "urn:1"),
+            (ViolationKind::InvalidTimestamp, Severity::Error, "/feed/entry[2]/published", "13 Dec 2003"),
+            (ViolationKind::MissingElement, Severity::Error, "/feed", "id"),
+        ]
+    );
+
+    // A base URI provided by the caller resolves relative URIs
+    let validator = validator::Builder::new().base_uri(Some("https://example.com/")).build();
+    let violations = validator.validate(xml.as_bytes()).unwrap();
+    assert!(violations.iter().all(|v| v.kind != ViolationKind::RelativeUri));
+}
+
+#[test]
+fn test_rss() {
+    // Timestamps the parser repairs are still reported
+    let xml = r#"<rss version="2.0"><channel>
+    <title>feed</title>
+    <link>https://example.com/</link>
+    <pubDate>Wed, 22 Jan 2020 10:58:02 UTC</pubDate>
+    <image><url>https://example.com/logo.png</url><title>logo</title></image>
+    <item><title>one</title><guid>https://example.com/1</guid></item>
+    <item><guid isPermaLink="false">https://example.com/1</guid></item>
+    <item><description>three</description><guid>3</guid><enclosure url="3.mp3" length="1" type="audio/mpeg"/></item>
+</channel></rss>"#;
+    let violations = validator::validate(xml.as_bytes()).unwrap();
+    assert_eq!(
+        summarise(&violations),
+        vec![
+            (
+                ViolationKind::InvalidTimestamp,
+                Severity::Error,
+                "/rss/channel/pubDate",
+                "Wed, 22 Jan 2020 10:58:02 UTC"
+            ),
+            (ViolationKind::MissingElement, Severity::Error, "/rss/channel/image", "link"),
+            (
+                ViolationKind::DuplicateId,
+                Severity::Error,
+                "/rss/channel/item[2]/guid",
+                "https://example.com/1"
+            ),
+            (ViolationKind::MissingElement, Severity::Error, "/rss/channel/item[2]", "title or description"),
+            (ViolationKind::RelativeUri, Severity::Warning, "/rss/channel/item[3]/guid", "3"),
+            (ViolationKind::RelativeUri, Severity::Warning, "/rss/channel/item[3]/enclosure/@url", "3.mp3"),
+            (ViolationKind::MissingElement, Severity::Error, "/rss/channel", "description"),
+        ]
+    );
+
+    // RSS 1.0 has similar requirements
+    let xml = r#"<rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#" xmlns="http://purl.org/rss/1.0/">
+    <channel rdf:about="https://example.com/"><title>feed</title><link>https://example.com/</link></channel>
+    <item rdf:about="https://example.com/1"><title>one</title><link>https://example.com/1</link></item>
+    <item rdf:about="https://example.com/1"><title>two</title></item>
+</rdf:RDF>"#;
+    let violations = validator::validate(xml.as_bytes()).unwrap();
+    assert_eq!(
+        summarise(&violations),
+        vec![
+            (ViolationKind::MissingElement, Severity::Error, "/RDF/channel", "description"),
+            (ViolationKind::DuplicateId, Severity::Error, "/RDF/item[2]/@rdf:about", "https://example.com/1"),
+            (ViolationKind::MissingElement, Severity::Error, "/RDF/item[2]", "link"),
+        ]
+    );
+}
+
+#[test]
+fn test_json() {
+    let json = r#"{
+    "title": "feed",
+    "home_page_url": "/",
+    "items": [
+        {"id": "1", "content_text": "one", "date_published": "2020-01-22 10:58"},
+        {"id": "1", "attachments": [{"url": "a.mp3", "mime_type": "audio/mpeg"}]}
+    ]
+}"#;
+    let violations = validator::validate(json.as_bytes()).unwrap();
+    assert_eq!(
+        summarise(&violations),
+        vec![
+            (ViolationKind::MissingElement, Severity::Error, "/", "version"),
+            (ViolationKind::RelativeUri, Severity::Warning, "/home_page_url", "/"),
+            (ViolationKind::InvalidTimestamp, Severity::Error, "/items/0/date_published", "2020-01-22 10:58"),
+            (ViolationKind::MissingElement, Severity::Error, "/items/1", "content_html or content_text"),
+            (ViolationKind::DuplicateId, Severity::Error, "/items/1/id", "1"),
+            (ViolationKind::RelativeUri, Severity::Warning, "/items/1/attachments/0/url", "a.mp3"),
+        ]
+    );
+}
+
+// Content that is not a feed cannot be validated
+#[test]
+fn test_invalid() {
+    assert!(validator::validate("not a feed".as_bytes()).is_err());
+    assert!(validator::validate("<html></html>".as_bytes()).is_err());
+
+    // Malformed XML includes the position of the error
+    let error = validator::validate("<rss version=\"2.0\"><channel><title>feed</title".as_bytes()).unwrap_err();
+    assert!(error.position().is_some());
+}
@@ -0,0 +1,321 @@
+use std::io::Write;
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use chrono::{DateTime, Utc};
+use quick_xml::events::{BytesDecl, BytesText, Event};
+use quick_xml::{Reader, Writer};
+
+use crate::model::{Category, Content, Entry, Feed, Generator, Link, Person, Text};
+use crate::parser::util;
+use crate::writer::mediarss::{write_media_elements, write_media_group, write_media_rating};
+use crate::writer::{format_rfc3339, optional_attributes, write_empty, write_end, write_extensions, write_start, write_text, WriteFeedResult};
+
+#[cfg(test)]
+mod tests;
+
+const ATOM_NS: &str = "http://www.w3.org/2005/Atom";
+const MEDIARSS_NS: &str = "http://search.yahoo.com/mrss/";
+
+/// Writes the feed as an Atom 1.0 document
+///
+/// Optional elements are only emitted where the model has a value.
+/// The elements Atom requires of the feed and each entry are always emitted, so those missing from the model
+/// (e.g. an RSS 2 feed without a "lastBuildDate") are given a fallback:
+/// * "id" is the first link of the feed or entry, or a generated "urn:uuid:" identifier
+/// * "title" is empty
+/// * "updated" is the most recent timestamp within the feed, or the current time if there is none
+///
+/// # Arguments
+///
+/// * `feed` - the feed to serialise
+/// * `out` - destination for the XML document
+///
+/// # Examples
+///
+/// ```
+/// use feed_rs::{parser, writer};
+/// let xml = r#"
+/// <feed xmlns="http://www.w3.org/2005/Atom">
+///    <title type="text">sample feed</title>
+///    <updated>2005-07-31T12:29:29Z</updated>
+///    <id>feed1</id>
+///    <entry>
+///        <title>sample entry</title>
+///        <id>entry1</id>
+///    </entry>
+/// </feed>
+/// "#;
+/// let feed = parser::parse(xml.as_bytes()).unwrap();
+///
+/// let mut atom = Vec::new();
+/// writer::atom::write(&feed, &mut atom).unwrap();
+/// ```
+pub fn write<W: Write>(feed: &Feed, out: W) -> WriteFeedResult<()> {
+    write_document(feed, &mut Writer::new(out))
+}
+
+// Writes the complete document with the supplied XML writer (which may be configured for indentation)
+pub(crate) fn write_document<W: Write>(feed: &Feed, writer: &mut Writer<W>) -> WriteFeedResult<()> {
+    writer.write_event(Event::Decl(BytesDecl::new("1.0", Some("UTF-8"), None)))?;
+
+    // Only declare the MediaRSS namespace if we need it
+    let mut attributes = vec![("xmlns", ATOM_NS)];
+    if feed.media.is_some() || feed.rating.is_some() || feed.entries.iter().any(|entry| !entry.media.is_empty()) {
+        attributes.push(("xmlns:media", MEDIARSS_NS));
+    }
+    if let Some(language) = &feed.language {
+        attributes.push(("xml:lang", language));
+    }
+    write_start(writer, "feed", &attributes)?;
+
+    // The timestamp of the feed is the default for entries without one of their own
+    let updated = feed.updated.or_else(|| latest_timestamp(feed)).unwrap_or_else(Utc::now);
+    write_required(writer, &feed.id, &feed.links, &feed.title, &updated)?;
+
+    for author in &feed.authors {
+        write_person(writer, "author", author)?;
+    }
+
+    for link in &feed.links {
+        write_link(writer, "link", link)?;
+    }
+
+    for category in &feed.categories {
+        write_category(writer, category)?;
+    }
+
+    for contributor in &feed.contributors {
+        write_person(writer, "contributor", contributor)?;
+    }
+
+    if let Some(generator) = &feed.generator {
+        write_generator(writer, generator)?;
+    }
+
+    if let Some(icon) = &feed.icon {
+        write_text(writer, "icon", &[], &icon.uri)?;
+    }
+
+    if let Some(logo) = &feed.logo {
+        write_text(writer, "logo", &[], &logo.uri)?;
+    }
+
+    if let Some(rights) = &feed.rights {
+        write_text_construct(writer, "rights", rights)?;
+    }
+
+    if let Some(description) = &feed.description {
+        write_text_construct(writer, "subtitle", description)?;
+    }
+
+    // The channel level media elements are written ungrouped, as they apply to all media within the feed
+    if let Some(rating) = &feed.rating {
+        write_media_rating(writer, rating)?;
+    }
+
+    if let Some(media_obj) = &feed.media {
+        write_media_elements(writer, media_obj)?;
+    }
+
+    write_extensions(writer, &feed.extensions, None)?;
+
+    for entry in &feed.entries {
+        write_entry(writer, entry, &updated)?;
+    }
+
+    write_end(writer, "feed")
+}
+
+// Writes an Atom <category>
+fn write_category<W: Write>(writer: &mut Writer<W>, category: &Category) -> WriteFeedResult<()> {
+    let mut attributes = vec![("term", category.term.as_str())];
+    attributes.extend(optional_attributes(&[("scheme", &category.scheme), ("label", &category.label)]));
+
+    write_empty(writer, "category", &attributes)
+}
+
+// Writes an Atom <content> element
+fn write_content<W: Write>(writer: &mut Writer<W>, entry: &Entry, content: &Content) -> WriteFeedResult<()> {
+    // The language and base of the entry are sourced from the content element
+    let mut attributes = optional_attributes(&[("xml:lang", &entry.language), ("xml:base", &entry.base)]);
+
+    if let Some(src) = &content.src {
+        // Out-of-line content, which MUST be empty
+        attributes.push(("src", &src.href));
+        let content_type = src.media_type.clone().unwrap_or_else(|| content.content_type.to_string());
+        attributes.push(("type", &content_type));
+        write_empty(writer, "content", &attributes)
+    } else {
+        // Per RFC 4287 section 4.1.3.3, the body is written according to the type of content
+        let body = content.body.as_deref().unwrap_or_default();
+        let content_type = content.content_type.as_str();
+        let essence = content_type.split(';').next().unwrap_or_default().trim().to_ascii_lowercase();
+        if essence.ends_with("+xml") || essence.ends_with("/xml") {
+            // XML is written inline, unless it is not well-formed (in which case it can only be written as text)
+            if is_well_formed(body) {
+                attributes.push(("type", content_type));
+                write_start(writer, "content", &attributes)?;
+                writer.write_event(Event::Text(BytesText::from_escaped(body)))?;
+                write_end(writer, "content")
+            } else {
+                attributes.push(("type", "text"));
+                write_text(writer, "content", &attributes, body)
+            }
+        } else if essence.starts_with("text/") {
+            attributes.push(("type", text_type(content_type)));
+            write_text(writer, "content", &attributes, body)
+        } else {
+            // Any other type is base64 encoded (the parser retains such content as it was found, so may already be encoded)
+            attributes.push(("type", content_type));
+            let encoded = body.split_ascii_whitespace().collect::<String>();
+            if STANDARD.decode(&encoded).is_ok() {
+                write_text(writer, "content", &attributes, &encoded)
+            } else {
+                write_text(writer, "content", &attributes, &STANDARD.encode(body))
+            }
+        }
+    }
+}
+
+// Checks the content is well-formed XML, so it may be written inline
+fn is_well_formed(xml: &str) -> bool {
+    let mut reader = Reader::from_str(xml);
+    loop {
+        match reader.read_event() {
+            Ok(Event::Eof) => return true,
+            Ok(Event::Decl(_) | Event::DocType(_)) | Err(_) => return false,
+            Ok(_) => {}
+        }
+    }
+}
+
+// Writes an Atom <entry>, with the supplied timestamp used if the entry has not recorded when it was updated
+fn write_entry<W: Write>(writer: &mut Writer<W>, entry: &Entry, feed_updated: &DateTime<Utc>) -> WriteFeedResult<()> {
+    write_start(writer, "entry", &[])?;
+
+    let updated = entry.updated.or(entry.published).unwrap_or(*feed_updated);
+    write_required(writer, &entry.id, &entry.links, &entry.title, &updated)?;
+
+    for author in &entry.authors {
+        write_person(writer, "author", author)?;
+    }
+
+    if let Some(content) = &entry.content {
+        write_content(writer, entry, content)?;
+    }
+
+    for link in &entry.links {
+        write_link(writer, "link", link)?;
+    }
+
+    if let Some(summary) = &entry.summary {
+        write_text_construct(writer, "summary", summary)?;
+    }
+
+    for category in &entry.categories {
+        write_category(writer, category)?;
+    }
+
+    for contributor in &entry.contributors {
+        write_person(writer, "contributor", contributor)?;
+    }
+
+    if let Some(published) = &entry.published {
+        write_text(writer, "published", &[], &format_rfc3339(published))?;
+    }
+
+    if let Some(rights) = &entry.rights {
+        write_text_construct(writer, "rights", rights)?;
+    }
+
+    // Each media object is emitted as a group, which the parser maps back to an individual object
+    for media_obj in &entry.media {
+        write_media_group(writer, media_obj)?;
+    }
+
+    write_extensions(writer, &entry.extensions, None)?;
+
+    write_end(writer, "entry")
+}
+
+// Writes the <id>, <title> and <updated> elements required of feeds and entries, falling back to a link (or generated ID) and an empty title
+fn write_required<W: Write>(writer: &mut Writer<W>, id: &str, links: &[Link], title: &Option<Text>, updated: &DateTime<Utc>) -> WriteFeedResult<()> {
+    match (id, links.first()) {
+        ("", Some(link)) => write_text(writer, "id", &[], &link.href)?,
+        ("", None) => write_text(writer, "id", &[], &format!("urn:uuid:{}", util::uuid_gen()))?,
+        (id, _) => write_text(writer, "id", &[], id)?,
+    }
+
+    match title {
+        Some(title) => write_text_construct(writer, "title", title)?,
+        None => write_empty(writer, "title", &[])?,
+    }
+
+    write_text(writer, "updated", &[], &format_rfc3339(updated))
+}
+
+// Finds the most recent timestamp of the entries within the feed (or the time the feed was published)
+fn latest_timestamp(feed: &Feed) -> Option<DateTime<Utc>> {
+    feed.entries
+        .iter()
+        .flat_map(|entry| [entry.updated, entry.published])
+        .chain([feed.published])
+        .flatten()
+        .max()
+}
+
+// Writes an Atom <generator>
+fn write_generator<W: Write>(writer: &mut Writer<W>, generator: &Generator) -> WriteFeedResult<()> {
+    let attributes = optional_attributes(&[("uri", &generator.uri), ("version", &generator.version)]);
+    write_text(writer, "generator", &attributes, &generator.content)
+}
+
+// Writes an Atom <link> (the name allows for a namespace prefix when embedded in other formats)
+pub(crate) fn write_link<W: Write>(writer: &mut Writer<W>, name: &str, link: &Link) -> WriteFeedResult<()> {
+    let length = link.length.map(|length| length.to_string());
+
+    let mut attributes = vec![("href", link.href.as_str())];
+    attributes.extend(optional_attributes(&[
+        ("rel", &link.rel),
+        ("type", &link.media_type),
+        ("hreflang", &link.href_lang),
+        ("title", &link.title),
+        ("length", &length),
+    ]));
+
+    write_empty(writer, name, &attributes)
+}
+
+// Writes an Atom <author> or <contributor>
+fn write_person<W: Write>(writer: &mut Writer<W>, name: &str, person: &Person) -> WriteFeedResult<()> {
+    write_start(writer, name, &[])?;
+
+    write_text(writer, "name", &[], &person.name)?;
+
+    if let Some(uri) = &person.uri {
+        write_text(writer, "uri", &[], uri)?;
+    }
+
+    if let Some(email) = &person.email {
+        write_text(writer, "email", &[], email)?;
+    }
+
+    write_end(writer, name)
+}
+
+// Writes an Atom <title>, <summary>, <rights> or <subtitle> element
+fn write_text_construct<W: Write>(writer: &mut Writer<W>, name: &str, text: &Text) -> WriteFeedResult<()> {
+    // Text constructs are either text or HTML (XHTML is written as escaped HTML)
+    let text_type = if text.content_type.as_str() == "text/html" { "html" } else { "text" };
+    write_text(writer, name, &[("type", text_type)], &text.content)
+}
+
+// Maps a media type to the Atom content type, which may be any MIME type in addition to the text construct types
+fn text_type(media_type: &str) -> &str {
+    match media_type {
+        "text/plain" => "text",
+        "text/html" => "html",
+        other => other,
+    }
+}
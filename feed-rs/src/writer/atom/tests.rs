@@ -0,0 +1,195 @@
+use crate::model::{Category, Content, Entry, Feed, FeedType, Generator, Image, Link, MediaContent, MediaObject, MediaRating, MediaThumbnail, Person, Text};
+use crate::parser;
+use crate::writer::{atom, tests};
+
+// The required elements the writer falls back to: an empty title, and the timestamp written
+fn fallbacks(feed: &mut Feed, reparsed: &mut Feed) {
+    feed.title.get_or_insert_with(|| Text::new("".into()));
+    feed.updated = feed.updated.or(reparsed.updated);
+    for (entry, written) in feed.entries.iter_mut().zip(&reparsed.entries) {
+        entry.title.get_or_insert_with(|| Text::new("".into()));
+        entry.updated = entry.updated.or(written.updated);
+    }
+}
+
+// Verify that each of the Atom fixtures survives a round-trip through the writer
+#[test]
+fn test_round_trip_fixtures() {
+    tests::test_round_trip_fixtures("atom", FeedType::Atom, |feed, out| atom::write(feed, out), fallbacks);
+}
+
+// Verify the attributes of the various Atom constructs are emitted
+#[test]
+fn test_write_constructs() {
+    let feed = Feed::new(FeedType::Atom)
+        .id("urn:feed:1")
+        .title(Text::new("Fish & Chips".into()))
+        .description(Text::new("<b>tasty</b>".into()).content_type("text/html"))
+        .updated_parsed("2021-03-04T05:06:07Z")
+        .author(Person::new("Jane").uri("https://example.com/jane").email("jane@example.com"))
+        .link(
+            Link::new("https://example.com/", None)
+                .rel("alternate")
+                .media_type("text/html")
+                .href_lang("en")
+                .title("Home"),
+        )
+        .category(Category::new("food").scheme("https://example.com/tags").label("Food"))
+        .generator(Generator::new("feed-rs").uri("https://github.com/feed-rs/feed-rs").version("2.4.0"))
+        .logo(Image::new("https://example.com/logo.png".into()))
+        .entry(
+            Entry::default()
+                .id("urn:entry:1")
+                .title(Text::new("Entry".into()))
+                .updated_parsed("2021-03-04T05:06:07Z")
+                .link(
+                    Link::new("https://example.com/audio.mp3", None)
+                        .rel("enclosure")
+                        .media_type("audio/mpeg")
+                        .length(1234),
+                )
+                .media(
+                    MediaObject::default()
+                        .title("Clip")
+                        .content(
+                            MediaContent::new()
+                                .url("https://example.com/clip.mp4")
+                                .content_type("video/mp4")
+                                .width(640)
+                                .height(480),
+                        )
+                        .thumbnail(MediaThumbnail::new(Image::new("https://example.com/clip.jpg".into()))),
+                ),
+        );
+
+    let mut out = Vec::new();
+    atom::write(&feed, &mut out).unwrap();
+    let written = String::from_utf8(out).unwrap();
+
+    assert!(
+        written.starts_with(r#"<?xml version="1.0" encoding="UTF-8"?><feed xmlns="http://www.w3.org/2005/Atom" xmlns:media="http://search.yahoo.com/mrss/">"#)
+    );
+    assert!(written.contains(r#"<title type="text">Fish &amp; Chips</title>"#));
+    assert!(written.contains(r#"<subtitle type="html">&lt;b&gt;tasty&lt;/b&gt;</subtitle>"#));
+    assert!(written.contains(r#"<updated>2021-03-04T05:06:07Z</updated>"#));
+    assert!(written.contains(r#"<link href="https://example.com/" rel="alternate" type="text/html" hreflang="en" title="Home"/>"#));
+    assert!(written.contains(r#"<link href="https://example.com/audio.mp3" rel="enclosure" type="audio/mpeg" length="1234"/>"#));
+    assert!(written.contains(r#"<category term="food" scheme="https://example.com/tags" label="Food"/>"#));
+    assert!(written.contains(r#"<generator uri="https://github.com/feed-rs/feed-rs" version="2.4.0">feed-rs</generator>"#));
+    assert!(written.contains(r#"<author><name>Jane</name><uri>https://example.com/jane</uri><email>jane@example.com</email></author>"#));
+    assert!(written.contains(r#"<media:content url="https://example.com/clip.mp4" type="video/mp4" width="640" height="480"/>"#));
+
+    // And the model should be unchanged after parsing the output
+    let actual = parser::parse(written.as_bytes()).unwrap();
+    assert_eq!(actual, feed);
+}
+
+// Verify the elements required by Atom are written even when missing from the model
+#[test]
+fn test_write_required() {
+    let feed = Feed::new(FeedType::RSS2)
+        .entry(
+            Entry::default()
+                .link(Link::new("https://example.com/1", None))
+                .published("2021-03-04T05:06:07Z"),
+        )
+        .entry(Entry::default().updated_parsed("2022-01-02T03:04:05Z"));
+
+    let mut out = Vec::new();
+    atom::write(&feed, &mut out).unwrap();
+    let written = String::from_utf8(out).unwrap();
+    let actual = parser::parse(written.as_bytes()).unwrap();
+
+    // The feed has a generated ID, an empty title and the most recent timestamp of the entries
+    assert!(actual.id.starts_with("urn:uuid:"));
+    assert_eq!(actual.title.unwrap().content, "");
+    assert_eq!(actual.updated, feed.entries[1].updated);
+
+    // Entries use their link as the ID, and the published timestamp (or the timestamp of the feed)
+    assert_eq!(actual.entries[0].id, "https://example.com/1");
+    assert_eq!(actual.entries[0].updated, feed.entries[0].published);
+    assert!(actual.entries[1].id.starts_with("urn:uuid:"));
+    assert_eq!(actual.entries[1].updated, feed.entries[1].updated);
+    assert!(written.contains("<entry><id>https://example.com/1</id><title/><updated>2021-03-04T05:06:07Z</updated>"));
+
+    // The current time is used when the feed has no timestamps at all
+    let mut out = Vec::new();
+    atom::write(&Feed::new(FeedType::RSS2), &mut out).unwrap();
+    let actual = parser::parse(out.as_slice()).unwrap();
+    assert!(actual.updated.is_some());
+}
+
+// Verify content is written inline, escaped or base64 encoded according to its type
+#[test]
+fn test_write_content() {
+    let entry = |id: &str, content: Content| {
+        Entry::default()
+            .id(id)
+            .title(Text::new(id.into()))
+            .updated_parsed("2021-03-04T05:06:07Z")
+            .content(content)
+    };
+    let feed = Feed::new(FeedType::Atom)
+        .id("urn:feed:1")
+        .title(Text::new("content".into()))
+        .updated_parsed("2021-03-04T05:06:07Z")
+        .entry(entry(
+            "xml",
+            Content::default()
+                .content_type("application/rdf+xml")
+                .body(r#"<doc a="1"><p>fish &amp; chips</p></doc>"#),
+        ))
+        .entry(entry("malformed", Content::default().content_type("text/xml").body("<a><b></a>")))
+        .entry(entry("csv", Content::default().content_type("text/csv").body("a,<b>")))
+        .entry(entry(
+            "binary",
+            Content::default().content_type("application/octet-stream").body("fish & chips"),
+        ))
+        .entry(entry("encoded", Content::default().content_type("image/png").body("aGVs\nbG8=")));
+
+    let mut out = Vec::new();
+    atom::write(&feed, &mut out).unwrap();
+    let written = String::from_utf8(out).unwrap();
+
+    // Well-formed XML is written as markup, otherwise as text
+    assert!(written.contains(r#"<content type="application/rdf+xml"><doc a="1"><p>fish &amp; chips</p></doc></content>"#));
+    assert!(written.contains(r#"<content type="text">&lt;a&gt;&lt;b&gt;&lt;/a&gt;</content>"#));
+
+    // Other text is escaped, and anything else base64 encoded (unless it already is)
+    assert!(written.contains(r#"<content type="text/csv">a,&lt;b&gt;</content>"#));
+    assert!(written.contains(r#"<content type="application/octet-stream">ZmlzaCAmIGNoaXBz</content>"#));
+    assert!(written.contains(r#"<content type="image/png">aGVsbG8=</content>"#));
+
+    // The inline XML is read back as the content
+    let actual = parser::parse(written.as_bytes()).unwrap();
+    let content = actual.entries[0].content.as_ref().unwrap();
+    assert_eq!(content.content_type.as_str(), "application/rdf+xml");
+    assert_eq!(content.body.as_deref(), Some(r#"<doc a="1"><p>fish & chips</p></doc>"#));
+}
+
+// Verify the channel level media is written (declaring the namespace) and read back
+#[test]
+fn test_write_feed_media() {
+    let feed = Feed::new(FeedType::Atom)
+        .id("urn:feed:1")
+        .title(Text::new("media".into()))
+        .updated_parsed("2021-03-04T05:06:07Z")
+        .rating(MediaRating::new("adult".into()))
+        .media(
+            MediaObject::default()
+                .thumbnail(MediaThumbnail::new(Image::new("https://example.com/show.jpg".into())))
+                .credit("Jane"),
+        );
+
+    let mut out = Vec::new();
+    atom::write(&feed, &mut out).unwrap();
+    let written = String::from_utf8(out).unwrap();
+
+    assert!(written.contains(r#"xmlns:media="http://search.yahoo.com/mrss/""#));
+    assert!(written.contains(r#"<media:rating scheme="simple">adult</media:rating>"#));
+    assert!(written.contains(r#"<media:thumbnail url="https://example.com/show.jpg"/>"#));
+    assert!(written.contains(r#"<media:credit>Jane</media:credit>"#));
+
+    let actual = parser::parse(written.as_bytes()).unwrap();
+    assert_eq!(actual, feed);
+}
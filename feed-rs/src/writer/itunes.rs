@@ -0,0 +1,130 @@
+use std::io::Write;
+use std::time::Duration;
+
+use quick_xml::Writer;
+
+use crate::model::{Category, ItunesEpisodeType, ItunesMeta, ItunesPodcastType, MediaRating, Person, Text};
+use crate::writer::mediarss::format_npt;
+use crate::writer::{write_empty, write_end, write_start, write_text, WriteFeedResult};
+
+// Writes <itunes:author>
+pub(crate) fn write_author<W: Write>(writer: &mut Writer<W>, name: &str) -> WriteFeedResult<()> {
+    write_text(writer, "itunes:author", &[], name)
+}
+
+// Writes <itunes:category>, including any sub-categories
+pub(crate) fn write_category<W: Write>(writer: &mut Writer<W>, category: &Category) -> WriteFeedResult<()> {
+    let attributes = [("text", category.term.as_str())];
+    if category.subcategories.is_empty() {
+        write_empty(writer, "itunes:category", &attributes)
+    } else {
+        write_start(writer, "itunes:category", &attributes)?;
+        for subcategory in &category.subcategories {
+            write_category(writer, subcategory)?;
+        }
+        write_end(writer, "itunes:category")
+    }
+}
+
+// Writes <itunes:duration>
+pub(crate) fn write_duration<W: Write>(writer: &mut Writer<W>, duration: &Duration) -> WriteFeedResult<()> {
+    write_text(writer, "itunes:duration", &[], &format_npt(duration))
+}
+
+// Writes <itunes:explicit> from the rating created by the parser
+pub(crate) fn write_explicit<W: Write>(writer: &mut Writer<W>, rating: &MediaRating) -> WriteFeedResult<()> {
+    write_text(writer, "itunes:explicit", &[], &rating.value)
+}
+
+// Writes <itunes:image>
+pub(crate) fn write_image<W: Write>(writer: &mut Writer<W>, href: &str) -> WriteFeedResult<()> {
+    write_empty(writer, "itunes:image", &[("href", href)])
+}
+
+// Writes <itunes:owner> (both the name and email are required)
+pub(crate) fn write_owner<W: Write>(writer: &mut Writer<W>, person: &Person) -> WriteFeedResult<()> {
+    if let Some(email) = &person.email {
+        write_start(writer, "itunes:owner", &[])?;
+        write_text(writer, "itunes:name", &[], &person.name)?;
+        write_text(writer, "itunes:email", &[], email)?;
+        write_end(writer, "itunes:owner")?;
+    }
+
+    Ok(())
+}
+
+// Writes <itunes:title> or <itunes:summary>
+pub(crate) fn write_text_construct<W: Write>(writer: &mut Writer<W>, name: &str, text: &Text) -> WriteFeedResult<()> {
+    if text.content_type.as_str() == "text/html" {
+        write_text(writer, name, &[("type", "html")], &text.content)
+    } else {
+        write_text(writer, name, &[], &text.content)
+    }
+}
+
+// Writes the tags of the channel or item that are not mapped to other fields
+pub(crate) fn write_meta<W: Write>(writer: &mut Writer<W>, meta: &ItunesMeta) -> WriteFeedResult<()> {
+    if let Some(podcast_type) = &meta.podcast_type {
+        let text = match podcast_type {
+            ItunesPodcastType::Episodic => "episodic",
+            ItunesPodcastType::Serial => "serial",
+        };
+        write_text(writer, "itunes:type", &[], text)?;
+    }
+
+    if let Some(url) = &meta.new_feed_url {
+        write_text(writer, "itunes:new-feed-url", &[], url.as_str())?;
+    }
+
+    if let Some(complete) = meta.complete {
+        write_text(writer, "itunes:complete", &[], yes_no(complete))?;
+    }
+
+    if let Some(owner) = &meta.owner {
+        write_owner(writer, owner)?;
+    }
+
+    if let Some(episode) = meta.episode {
+        write_text(writer, "itunes:episode", &[], &episode.to_string())?;
+    }
+
+    if let Some(season) = meta.season {
+        write_text(writer, "itunes:season", &[], &season.to_string())?;
+    }
+
+    if let Some(episode_type) = &meta.episode_type {
+        let text = match episode_type {
+            ItunesEpisodeType::Full => "full",
+            ItunesEpisodeType::Trailer => "trailer",
+            ItunesEpisodeType::Bonus => "bonus",
+        };
+        write_text(writer, "itunes:episodeType", &[], text)?;
+    }
+
+    if let Some(explicit) = meta.explicit {
+        write_text(writer, "itunes:explicit", &[], &explicit.to_string())?;
+    }
+
+    if let Some(block) = meta.block {
+        write_text(writer, "itunes:block", &[], yes_no(block))?;
+    }
+
+    if !meta.keywords.is_empty() {
+        write_text(writer, "itunes:keywords", &[], &meta.keywords.join(","))?;
+    }
+
+    if let Some(subtitle) = &meta.subtitle {
+        write_text(writer, "itunes:subtitle", &[], subtitle)?;
+    }
+
+    Ok(())
+}
+
+// The flags are "Yes" or "No" in the iTunes spec
+fn yes_no(flag: bool) -> &'static str {
+    if flag {
+        "Yes"
+    } else {
+        "No"
+    }
+}
@@ -0,0 +1,263 @@
+use std::io::Write;
+
+use mediatype::names;
+use serde_json::Value;
+
+use crate::model::{Entry, Feed, Link, MediaContent, Person};
+use crate::parser::json::{JsonAttachment, JsonAuthor, JsonFeed, JsonHub, JsonItem};
+use crate::parser::util;
+use crate::writer::{format_rfc3339, WriteFeedResult};
+
+#[cfg(test)]
+mod tests;
+
+const JSON_FEED_VERSION: &str = "https://jsonfeed.org/version/1.1";
+
+/// Writes the feed as a JSON Feed 1.1 document
+///
+/// The home page and feed URLs are taken from the links of the feed ("alternate" and "self" respectively, falling back to the links without a relation
+/// in turn), with the next page and hubs taken from "next" and "hub" links. Item attachments are created from
+/// enclosure links and media content, the item image from the first media thumbnail and the banner image from a "banner" link.
+/// The members JSON Feed requires are always emitted, so those missing from the model are given a fallback:
+/// * the feed "title" is empty
+/// * the item "id" is the item URL, or a generated "urn:uuid:" identifier
+/// * the item content is the summary (as "content_html" if it is HTML, otherwise "content_text"), or an empty "content_text"
+///
+/// # Arguments
+///
+/// * `feed` - the feed to serialise
+/// * `out` - destination for the JSON document
+///
+/// # Examples
+///
+/// ```
+/// use feed_rs::{parser, writer};
+/// let xml = r#"
+/// <feed xmlns="http://www.w3.org/2005/Atom">
+///    <title type="text">sample feed</title>
+///    <id>feed1</id>
+///    <link rel="alternate" href="https://example.com/"/>
+///    <entry>
+///        <title>sample entry</title>
+///        <id>entry1</id>
+///        <content type="html">&lt;p&gt;Hello&lt;/p&gt;</content>
+///    </entry>
+/// </feed>
+/// "#;
+/// let feed = parser::parse(xml.as_bytes()).unwrap();
+///
+/// let mut json = Vec::new();
+/// writer::json::write(&feed, &mut json).unwrap();
+/// ```
+pub fn write<W: Write>(feed: &Feed, out: W) -> WriteFeedResult<()> {
+    let jf = convert(feed)?;
+    serde_json::to_writer(out, &jf)?;
+    Ok(())
+}
+
+// Convert our model into the JSON Feed structure
+pub(crate) fn convert(feed: &Feed) -> WriteFeedResult<JsonFeed> {
+    let (home_page_url, feed_url) = handle_feed_links(&feed.links);
+    let items = feed
+        .entries
+        .iter()
+        .map(|entry| serde_json::to_value(handle_entry(entry)))
+        .collect::<Result<_, _>>()?;
+
+    Ok(JsonFeed {
+        version: Some(JSON_FEED_VERSION.into()),
+        title: Some(feed.title.as_ref().map(|title| title.content.clone()).unwrap_or_default()),
+        home_page_url,
+        feed_url,
+        language: feed.language.clone(),
+        description: feed.description.as_ref().map(|description| description.content.clone()),
+        icon: feed.logo.as_ref().map(|logo| logo.uri.clone()),
+        favicon: feed.icon.as_ref().map(|icon| icon.uri.clone()),
+        authors: handle_authors(&feed.authors),
+        next_url: find_link(&feed.links, "next"),
+        expired: Some(true).filter(|_| feed.expired),
+        hubs: handle_hubs(&feed.links),
+        items,
+        extensions: feed.json_extensions.clone(),
+        ..Default::default()
+    })
+}
+
+// Converts the people to JSON authors (if there are any)
+fn handle_authors(people: &[Person]) -> Option<Vec<JsonAuthor>> {
+    if people.is_empty() {
+        None
+    } else {
+        let authors = people
+            .iter()
+            .map(|person| JsonAuthor {
+                name: Some(person.name.clone()),
+                url: person.uri.clone(),
+            })
+            .collect();
+        Some(authors)
+    }
+}
+
+// Converts an entry into a JSON Feed item
+fn handle_entry(entry: &Entry) -> JsonItem {
+    let mut ji = JsonItem {
+        title: entry.title.as_ref().map(|title| title.content.clone()),
+        summary: entry.summary.as_ref().map(|summary| summary.content.clone()),
+        date_published: entry.published.as_ref().map(format_rfc3339),
+        date_modified: entry.updated.as_ref().map(format_rfc3339),
+        authors: handle_authors(&entry.authors),
+        language: entry.language.clone(),
+        image: entry
+            .media
+            .iter()
+            .flat_map(|media_obj| &media_obj.thumbnails)
+            .next()
+            .map(|thumbnail| thumbnail.image.uri.clone()),
+        banner_image: find_link(&entry.links, "banner"),
+        extensions: entry.json_extensions.clone(),
+        ..Default::default()
+    };
+
+    // The first page link is the item URL, with the next (or a "related" link) being the external URL
+    let mut page_links = entry
+        .links
+        .iter()
+        .filter(|link| !is_attachment(link) && matches!(link.rel.as_deref(), None | Some("alternate")));
+    ji.url = page_links.next().map(|link| link.href.clone());
+    ji.external_url = entry
+        .links
+        .iter()
+        .find(|link| link.rel.as_deref() == Some("related"))
+        .or_else(|| page_links.next())
+        .map(|link| link.href.clone());
+
+    // The ID is required, so falls back to the URL of the item
+    let id = match (entry.id.as_str(), &ji.url) {
+        ("", Some(url)) => url.clone(),
+        ("", None) => format!("urn:uuid:{}", util::uuid_gen()),
+        (id, _) => id.to_string(),
+    };
+    ji.id = Some(Value::String(id));
+
+    // Content is either HTML or text, with other types (e.g. base64 encoded data) having no representation in JSON Feed
+    if let Some(content) = &entry.content {
+        if content.content_type.ty() == names::TEXT {
+            if content.content_type.subty() == names::HTML {
+                ji.content_html = content.body.clone();
+            } else {
+                ji.content_text = content.body.clone();
+            }
+        }
+    }
+
+    // Either form of content is required, so falls back to the summary
+    if ji.content_html.is_none() && ji.content_text.is_none() {
+        match &entry.summary {
+            Some(summary) if summary.content_type.subty() == names::HTML => ji.content_html = Some(summary.content.clone()),
+            Some(summary) => ji.content_text = Some(summary.content.clone()),
+            None => ji.content_text = Some(String::new()),
+        }
+    }
+
+    if !entry.categories.is_empty() {
+        ji.tags = Some(entry.categories.iter().map(|category| category.term.clone()).collect());
+    }
+
+    // Enclosures are the natural representation of an attachment, followed by any media content not already covered
+    let mut attachments: Vec<JsonAttachment> = entry.links.iter().filter(|link| is_attachment(link)).filter_map(link_attachment).collect();
+    for media_obj in &entry.media {
+        let title = media_obj.title.as_ref().map(|title| title.content.clone());
+        for content in &media_obj.content {
+            let duration = content.duration.or(media_obj.duration).map(|duration| duration.as_secs_f64());
+            if let Some(existing) = attachments
+                .iter_mut()
+                .find(|existing| content.url.as_ref().is_some_and(|url| existing.url.as_deref() == Some(url.as_str())))
+            {
+                // The duration of an enclosure is only available from the corresponding media content
+                existing.duration_in_seconds = existing.duration_in_seconds.or(duration);
+            } else if let Some(mut attachment) = media_attachment(content) {
+                attachment.title.clone_from(&title);
+                attachment.duration_in_seconds = duration;
+                attachments.push(attachment);
+            }
+        }
+    }
+    if !attachments.is_empty() {
+        ji.attachments = Some(attachments);
+    }
+
+    ji
+}
+
+// Separates the home page and feed URL from the links of a feed
+fn handle_feed_links(links: &[Link]) -> (Option<String>, Option<String>) {
+    // The JSON Feed parser creates a link without a relation for the home page and a "self" link for the feed,
+    // while other formats may have further links without a relation, which are taken in turn
+    let mut unspecified = links.iter().filter(|link| link.rel.is_none());
+
+    let home_page_url = links
+        .iter()
+        .find(|link| link.rel.as_deref() == Some("alternate"))
+        .or_else(|| unspecified.next())
+        .map(|link| link.href.clone());
+    let feed_url = links
+        .iter()
+        .find(|link| link.rel.as_deref() == Some("self"))
+        .or_else(|| unspecified.next())
+        .map(|link| link.href.clone());
+
+    (home_page_url, feed_url)
+}
+
+// Finds the first link with the given relation
+fn find_link(links: &[Link], rel: &str) -> Option<String> {
+    links.iter().find(|link| link.rel.as_deref() == Some(rel)).map(|link| link.href.clone())
+}
+
+// Converts the "hub" links to JSON hubs (if there are any), with the type of hub taken from the title
+fn handle_hubs(links: &[Link]) -> Option<Vec<JsonHub>> {
+    let hubs: Vec<JsonHub> = links
+        .iter()
+        .filter(|link| link.rel.as_deref() == Some("hub"))
+        .map(|link| JsonHub {
+            hub_type: Some(link.title.clone().unwrap_or_else(|| "WebSub".into())),
+            url: Some(link.href.clone()),
+        })
+        .collect();
+    Some(hubs).filter(|hubs| !hubs.is_empty())
+}
+
+// Checks if the link refers to an attachment (an enclosure, or a link with a media type as produced by the JSON Feed parser)
+fn is_attachment(link: &Link) -> bool {
+    match link.rel.as_deref() {
+        Some("enclosure") => true,
+        None => link.media_type.is_some(),
+        _ => false,
+    }
+}
+
+// Converts a link to an attachment (the MIME type is required)
+fn link_attachment(link: &Link) -> Option<JsonAttachment> {
+    link.media_type.as_ref().map(|mime_type| JsonAttachment {
+        url: Some(link.href.clone()),
+        mime_type: Some(mime_type.clone()),
+        title: link.title.clone(),
+        size_in_bytes: link.length,
+        duration_in_seconds: None,
+    })
+}
+
+// Converts media content to an attachment (both the URL and MIME type are required)
+fn media_attachment(content: &MediaContent) -> Option<JsonAttachment> {
+    match (&content.url, &content.content_type) {
+        (Some(url), Some(mime_type)) => Some(JsonAttachment {
+            url: Some(url.to_string()),
+            mime_type: Some(mime_type.to_string()),
+            title: None,
+            size_in_bytes: content.size,
+            duration_in_seconds: None,
+        }),
+        _ => None,
+    }
+}
@@ -0,0 +1,135 @@
+use crate::model::{Category, Content, Entry, Feed, FeedType, Link, MediaContent, MediaObject, Person, Text};
+use crate::validator;
+use crate::writer::{json, tests, WriteFeedResult};
+
+// The required members the writer falls back to: an empty title, and the content written
+fn fallbacks(feed: &mut Feed, reparsed: &mut Feed) {
+    feed.title.get_or_insert_with(|| Text::new("".into()));
+    for (entry, written) in feed.entries.iter_mut().zip(&reparsed.entries) {
+        entry.content = entry.content.take().or_else(|| written.content.clone());
+    }
+}
+
+// Writes the feed as JSON Feed
+fn write(feed: &Feed, out: &mut Vec<u8>) -> WriteFeedResult<()> {
+    json::write(feed, out)
+}
+
+// Verify that each of the JSON Feed fixtures survives a round-trip through the writer
+#[test]
+fn test_round_trip_fixtures() {
+    tests::test_round_trip_fixtures("jsonfeed", FeedType::JSON, write, fallbacks);
+}
+
+// Verify the mapping of links, content and media to the JSON Feed fields
+#[test]
+fn test_write_fields() {
+    let feed = Feed::new(FeedType::Atom)
+        .id("urn:feed:1")
+        .title(Text::new("Fish & Chips".into()))
+        .link(Link::new("https://example.com/feed.json", None).rel("self"))
+        .link(Link::new("https://example.com/", None).rel("alternate"))
+        .language("en")
+        .author(Person::new("Jane").uri("https://example.com/jane"))
+        .entry(
+            Entry::default()
+                .id("urn:entry:1")
+                .title(Text::new("Entry".into()))
+                .link(Link::new("https://example.com/entry", None).rel("alternate").media_type("text/html"))
+                .link(Link::new("https://example.org/story", None).rel("related"))
+                .link(
+                    Link::new("https://example.com/audio.mp3", None)
+                        .rel("enclosure")
+                        .media_type("audio/mpeg")
+                        .length(1234),
+                )
+                .content(Content::default().body("<p>Hello</p>").content_type("text/html"))
+                .category(Category::new("food"))
+                .published("2021-03-04T05:06:07Z")
+                .media(
+                    MediaObject::default()
+                        .title("Clip")
+                        .content(MediaContent::new().url("https://example.com/clip.mp4").content_type("video/mp4").size(5678)),
+                ),
+        );
+
+    let mut out = Vec::new();
+    json::write(&feed, &mut out).unwrap();
+    let written: serde_json::Value = serde_json::from_slice(&out).unwrap();
+
+    let expected = serde_json::json!({
+        "version": "https://jsonfeed.org/version/1.1",
+        "title": "Fish & Chips",
+        "home_page_url": "https://example.com/",
+        "feed_url": "https://example.com/feed.json",
+        "language": "en",
+        "authors": [{ "name": "Jane", "url": "https://example.com/jane" }],
+        "items": [{
+            "id": "urn:entry:1",
+            "url": "https://example.com/entry",
+            "external_url": "https://example.org/story",
+            "title": "Entry",
+            "content_html": "<p>Hello</p>",
+            "date_published": "2021-03-04T05:06:07Z",
+            "tags": ["food"],
+            "attachments": [
+                { "url": "https://example.com/audio.mp3", "mime_type": "audio/mpeg", "size_in_bytes": 1234 },
+                { "url": "https://example.com/clip.mp4", "mime_type": "video/mp4", "title": "Clip", "size_in_bytes": 5678 }
+            ]
+        }]
+    });
+    assert_eq!(written, expected);
+}
+
+// Verify the JSON Feed 1.1 fields survive a round-trip, with media durations written for attachments
+#[test]
+fn test_round_trip_v1_1_fields() {
+    let source = r#"{
+        "version": "https://jsonfeed.org/version/1.1",
+        "title": "Podcast",
+        "next_url": "https://example.com/feed.json?page=2",
+        "expired": true,
+        "hubs": [{"type": "WebSub", "url": "https://hub.example.com/"}],
+        "items": [{
+            "id": "1",
+            "language": "fr-CA",
+            "image": "https://example.com/episode.jpg",
+            "banner_image": "https://example.com/banner.jpg",
+            "attachments": [{"url": "https://example.com/episode.mp3", "mime_type": "audio/mpeg", "duration_in_seconds": 90.5}]
+        }]
+    }"#;
+    let (expected, actual, written) = tests::round_trip(source.as_bytes(), write, fallbacks);
+    assert_eq!(expected, actual, "round trip failed, wrote:\n{}", written);
+
+    let written: serde_json::Value = serde_json::from_str(&written).unwrap();
+    assert_eq!(written["next_url"], "https://example.com/feed.json?page=2");
+    assert_eq!(written["expired"], true);
+    assert_eq!(written["hubs"], serde_json::json!([{"type": "WebSub", "url": "https://hub.example.com/"}]));
+    let item = &written["items"][0];
+    assert_eq!(item["language"], "fr-CA");
+    assert_eq!(item["image"], "https://example.com/episode.jpg");
+    assert_eq!(item["banner_image"], "https://example.com/banner.jpg");
+    assert_eq!(item["attachments"][0]["duration_in_seconds"], 90.5);
+}
+
+// Verify the required members are always written, falling back to the link, summary or an empty value
+#[test]
+fn test_write_required() {
+    let feed = Feed::new(FeedType::RSS2)
+        .entry(Entry::default().link(Link::new("https://example.com/1", None)))
+        .entry(Entry::default().summary(Text::html("<p>Summary</p>".into())))
+        .entry(Entry::default().summary(Text::new("Summary".into())));
+
+    let mut out = Vec::new();
+    json::write(&feed, &mut out).unwrap();
+    assert!(validator::validate(out.as_slice()).unwrap().is_empty());
+    let written: serde_json::Value = serde_json::from_slice(&out).unwrap();
+
+    assert_eq!(written["title"], "");
+    assert_eq!(written["items"][0]["id"], "https://example.com/1");
+    assert_eq!(written["items"][0]["content_text"], "");
+    assert!(written["items"][1]["id"].as_str().unwrap().starts_with("urn:uuid:"));
+    assert!(written["items"][1].get("title").is_none());
+    assert_eq!(written["items"][1]["content_html"], "<p>Summary</p>");
+    assert_eq!(written["items"][2]["content_text"], "Summary");
+}
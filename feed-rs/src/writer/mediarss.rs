@@ -0,0 +1,341 @@
+use std::io::Write;
+use std::time::Duration;
+
+use quick_xml::Writer;
+
+use crate::model::{
+    Link, MediaCommunity, MediaContent, MediaEmbed, MediaLocation, MediaObject, MediaPlayer, MediaPrice, MediaRating, MediaRelationship, MediaRestriction,
+    MediaScene, MediaState, MediaText, MediaThumbnail, Text,
+};
+use crate::writer::{optional_attributes, write_empty, write_end, write_start, write_text, WriteFeedResult};
+
+/// Writes the media object as a "media:group" element
+pub(crate) fn write_media_group<W: Write>(writer: &mut Writer<W>, media_obj: &MediaObject) -> WriteFeedResult<()> {
+    write_start(writer, "media:group", &[])?;
+    write_media_elements(writer, media_obj)?;
+    write_end(writer, "media:group")
+}
+
+/// Writes the fields of the media object as a sequence of "media:*" elements
+/// This is the inverse of the parser, so is used for both grouped and ungrouped (entry level) media elements
+pub(crate) fn write_media_elements<W: Write>(writer: &mut Writer<W>, media_obj: &MediaObject) -> WriteFeedResult<()> {
+    if let Some(title) = &media_obj.title {
+        write_media_text_construct(writer, "media:title", title)?;
+    }
+
+    for content in &media_obj.content {
+        write_media_content(writer, content)?;
+    }
+
+    for thumbnail in &media_obj.thumbnails {
+        write_media_thumbnail(writer, thumbnail)?;
+    }
+
+    if let Some(description) = &media_obj.description {
+        write_media_text_construct(writer, "media:description", description)?;
+    }
+
+    if let Some(community) = &media_obj.community {
+        write_media_community(writer, community)?;
+    }
+
+    for credit in &media_obj.credits {
+        write_text(writer, "media:credit", &[], &credit.entity)?;
+    }
+
+    for text in &media_obj.texts {
+        write_media_text(writer, text)?;
+    }
+
+    write_media_optional_elements(writer, media_obj)
+}
+
+/// Writes the optional elements of the media object that have no equivalent in other namespaces (e.g. "media:keywords")
+pub(crate) fn write_media_optional_elements<W: Write>(writer: &mut Writer<W>, media_obj: &MediaObject) -> WriteFeedResult<()> {
+    if !media_obj.keywords.is_empty() {
+        write_text(writer, "media:keywords", &[], &media_obj.keywords.join(", "))?;
+    }
+
+    for category in &media_obj.categories {
+        let attributes = optional_attributes(&[("scheme", &category.scheme), ("label", &category.label)]);
+        write_text(writer, "media:category", &attributes, &category.term)?;
+    }
+
+    for hash in &media_obj.hashes {
+        write_text(writer, "media:hash", &[("algo", hash.algo.as_str())], &hash.value)?;
+    }
+
+    if let Some(player) = &media_obj.player {
+        write_media_player(writer, player)?;
+    }
+
+    if let Some(embed) = &media_obj.embed {
+        write_media_embed(writer, embed)?;
+    }
+
+    for license in &media_obj.licenses {
+        let content_type = license.content_type.as_ref().map(|ct| ct.to_string());
+        let href = license.href.as_ref().map(|href| href.to_string());
+        let attributes = optional_attributes(&[("type", &content_type), ("href", &href)]);
+        match &license.name {
+            Some(name) => write_text(writer, "media:license", &attributes, name)?,
+            None => write_empty(writer, "media:license", &attributes)?,
+        }
+    }
+
+    for restriction in &media_obj.restrictions {
+        write_media_restriction(writer, restriction)?;
+    }
+
+    for price in &media_obj.prices {
+        write_media_price(writer, price)?;
+    }
+
+    for link in &media_obj.peer_links {
+        write_media_link(writer, "media:peerLink", link)?;
+    }
+
+    for link in &media_obj.subtitles {
+        write_media_link(writer, "media:subTitle", link)?;
+    }
+
+    for location in &media_obj.locations {
+        write_media_location(writer, location)?;
+    }
+
+    if !media_obj.scenes.is_empty() {
+        write_start(writer, "media:scenes", &[])?;
+        for scene in &media_obj.scenes {
+            write_media_scene(writer, scene)?;
+        }
+        write_end(writer, "media:scenes")?;
+    }
+
+    if let Some(status) = &media_obj.status {
+        let state = match status.state {
+            MediaState::Active => "active",
+            MediaState::Blocked => "blocked",
+            MediaState::Deleted => "deleted",
+        };
+        let mut attributes = vec![("state", state)];
+        attributes.extend(optional_attributes(&[("reason", &status.reason)]));
+        write_empty(writer, "media:status", &attributes)?;
+    }
+
+    if let Some(copyright) = &media_obj.copyright {
+        let url = copyright.url.as_ref().map(|url| url.to_string());
+        let attributes = optional_attributes(&[("url", &url)]);
+        write_text(writer, "media:copyright", &attributes, &copyright.notice)?;
+    }
+
+    if !media_obj.back_links.is_empty() {
+        write_start(writer, "media:backLinks", &[])?;
+        for url in &media_obj.back_links {
+            write_text(writer, "media:backLink", &[], url.as_str())?;
+        }
+        write_end(writer, "media:backLinks")?;
+    }
+
+    Ok(())
+}
+
+/// Formats a duration as "normal play time" per the RSS media spec (seconds, with optional fractional seconds)
+pub(crate) fn format_npt(duration: &Duration) -> String {
+    let millis = duration.subsec_millis();
+    if millis == 0 {
+        duration.as_secs().to_string()
+    } else {
+        let formatted = format!("{}.{:03}", duration.as_secs(), millis);
+        formatted.trim_end_matches('0').to_string()
+    }
+}
+
+// Writes "media:community"
+pub(crate) fn write_media_community<W: Write>(writer: &mut Writer<W>, community: &MediaCommunity) -> WriteFeedResult<()> {
+    write_start(writer, "media:community", &[])?;
+
+    let average = community.stars_avg.map(|v| v.to_string());
+    let count = community.stars_count.map(|v| v.to_string());
+    let min = community.stars_min.map(|v| v.to_string());
+    let max = community.stars_max.map(|v| v.to_string());
+    let stars = optional_attributes(&[("average", &average), ("count", &count), ("min", &min), ("max", &max)]);
+    if !stars.is_empty() {
+        write_empty(writer, "media:starRating", &stars)?;
+    }
+
+    let views = community.stats_views.map(|v| v.to_string());
+    let favorites = community.stats_favorites.map(|v| v.to_string());
+    let statistics = optional_attributes(&[("views", &views), ("favorites", &favorites)]);
+    if !statistics.is_empty() {
+        write_empty(writer, "media:statistics", &statistics)?;
+    }
+
+    write_end(writer, "media:community")
+}
+
+// Writes "media:content" (content without a URL is not representable)
+pub(crate) fn write_media_content<W: Write>(writer: &mut Writer<W>, content: &MediaContent) -> WriteFeedResult<()> {
+    if let Some(url) = &content.url {
+        let content_type = content.content_type.as_ref().map(|ct| ct.to_string());
+        let width = content.width.map(|v| v.to_string());
+        let height = content.height.map(|v| v.to_string());
+        let size = content.size.map(|v| v.to_string());
+        let duration = content.duration.map(|v| v.as_secs().to_string());
+
+        let mut attributes = vec![("url", url.as_str())];
+        attributes.extend(optional_attributes(&[
+            ("type", &content_type),
+            ("width", &width),
+            ("height", &height),
+            ("fileSize", &size),
+            ("duration", &duration),
+        ]));
+
+        if content.rating.is_some() || content.player.is_some() {
+            write_start(writer, "media:content", &attributes)?;
+            if let Some(rating) = &content.rating {
+                write_media_rating(writer, rating)?;
+            }
+            if let Some(player) = &content.player {
+                write_media_player(writer, player)?;
+            }
+            write_end(writer, "media:content")?;
+        } else {
+            write_empty(writer, "media:content", &attributes)?;
+        }
+    }
+
+    Ok(())
+}
+
+// Writes "media:embed" and the contained parameters
+fn write_media_embed<W: Write>(writer: &mut Writer<W>, embed: &MediaEmbed) -> WriteFeedResult<()> {
+    let url = embed.url.as_ref().map(|url| url.to_string());
+    let width = embed.width.map(|v| v.to_string());
+    let height = embed.height.map(|v| v.to_string());
+    let attributes = optional_attributes(&[("url", &url), ("width", &width), ("height", &height)]);
+
+    write_start(writer, "media:embed", &attributes)?;
+    for (name, value) in &embed.params {
+        write_text(writer, "media:param", &[("name", name.as_str())], value)?;
+    }
+    write_end(writer, "media:embed")
+}
+
+// Writes "media:peerLink" or "media:subTitle"
+fn write_media_link<W: Write>(writer: &mut Writer<W>, name: &str, link: &Link) -> WriteFeedResult<()> {
+    let mut attributes = optional_attributes(&[("type", &link.media_type), ("lang", &link.href_lang)]);
+    attributes.push(("href", link.href.as_str()));
+    write_empty(writer, name, &attributes)
+}
+
+// Writes "media:location", with the coordinates as a "georss:point"
+fn write_media_location<W: Write>(writer: &mut Writer<W>, location: &MediaLocation) -> WriteFeedResult<()> {
+    let start = location.start_time.as_ref().map(format_npt);
+    let end = location.end_time.as_ref().map(format_npt);
+    let attributes = optional_attributes(&[("description", &location.description), ("start", &start), ("end", &end)]);
+
+    write_start(writer, "media:location", &attributes)?;
+    if let (Some(latitude), Some(longitude)) = (location.latitude, location.longitude) {
+        let attributes = [("xmlns:georss", "http://www.georss.org/georss")];
+        write_text(writer, "georss:point", &attributes, &format!("{} {}", latitude, longitude))?;
+    }
+    write_end(writer, "media:location")
+}
+
+// Writes "media:player"
+fn write_media_player<W: Write>(writer: &mut Writer<W>, player: &MediaPlayer) -> WriteFeedResult<()> {
+    let width = player.width.map(|v| v.to_string());
+    let height = player.height.map(|v| v.to_string());
+
+    let mut attributes = vec![("url", player.url.as_str())];
+    attributes.extend(optional_attributes(&[("width", &width), ("height", &height)]));
+
+    write_empty(writer, "media:player", &attributes)
+}
+
+// Writes "media:price"
+fn write_media_price<W: Write>(writer: &mut Writer<W>, price: &MediaPrice) -> WriteFeedResult<()> {
+    let amount = price.price.map(|v| v.to_string());
+    let info = price.info.as_ref().map(|info| info.to_string());
+    let attributes = optional_attributes(&[("type", &price.price_type), ("price", &amount), ("info", &info), ("currency", &price.currency)]);
+
+    write_empty(writer, "media:price", &attributes)
+}
+
+// Writes "media:rating"
+pub(crate) fn write_media_rating<W: Write>(writer: &mut Writer<W>, rating: &MediaRating) -> WriteFeedResult<()> {
+    write_text(writer, "media:rating", &[("scheme", &rating.urn)], &rating.value)
+}
+
+// Writes "media:restriction"
+fn write_media_restriction<W: Write>(writer: &mut Writer<W>, restriction: &MediaRestriction) -> WriteFeedResult<()> {
+    let relationship = match restriction.relationship {
+        MediaRelationship::Allow => "allow",
+        MediaRelationship::Deny => "deny",
+    };
+    let mut attributes = vec![("relationship", relationship)];
+    attributes.extend(optional_attributes(&[("type", &restriction.restriction_type)]));
+
+    write_text(writer, "media:restriction", &attributes, &restriction.values.join(" "))
+}
+
+// Writes "media:scene"
+fn write_media_scene<W: Write>(writer: &mut Writer<W>, scene: &MediaScene) -> WriteFeedResult<()> {
+    write_start(writer, "media:scene", &[])?;
+
+    if let Some(title) = &scene.title {
+        write_text(writer, "media:sceneTitle", &[], title)?;
+    }
+
+    if let Some(description) = &scene.description {
+        write_text(writer, "media:sceneDescription", &[], description)?;
+    }
+
+    if let Some(start_time) = &scene.start_time {
+        write_text(writer, "media:sceneStartTime", &[], &format_npt(start_time))?;
+    }
+
+    if let Some(end_time) = &scene.end_time {
+        write_text(writer, "media:sceneEndTime", &[], &format_npt(end_time))?;
+    }
+
+    write_end(writer, "media:scene")
+}
+
+// Writes "media:text"
+pub(crate) fn write_media_text<W: Write>(writer: &mut Writer<W>, text: &MediaText) -> WriteFeedResult<()> {
+    let start = text.start_time.as_ref().map(format_npt);
+    let end = text.end_time.as_ref().map(format_npt);
+
+    let mut attributes = vec![("type", media_text_type(&text.text))];
+    attributes.extend(optional_attributes(&[("start", &start), ("end", &end)]));
+
+    write_text(writer, "media:text", &attributes, &text.text.content)
+}
+
+// Writes a "media:title" or "media:description"
+fn write_media_text_construct<W: Write>(writer: &mut Writer<W>, name: &str, text: &Text) -> WriteFeedResult<()> {
+    write_text(writer, name, &[("type", media_text_type(text))], &text.content)
+}
+
+// Writes "media:thumbnail"
+pub(crate) fn write_media_thumbnail<W: Write>(writer: &mut Writer<W>, thumbnail: &MediaThumbnail) -> WriteFeedResult<()> {
+    let width = thumbnail.image.width.map(|v| v.to_string());
+    let height = thumbnail.image.height.map(|v| v.to_string());
+    let time = thumbnail.time.as_ref().map(format_npt);
+
+    let mut attributes = vec![("url", thumbnail.image.uri.as_str())];
+    attributes.extend(optional_attributes(&[("width", &width), ("height", &height), ("time", &time)]));
+
+    write_empty(writer, "media:thumbnail", &attributes)
+}
+
+// The MediaRSS spec only supports "plain" and "html" for text elements
+fn media_text_type(text: &Text) -> &'static str {
+    if text.content_type.as_str() == "text/html" {
+        "html"
+    } else {
+        "plain"
+    }
+}
@@ -0,0 +1,252 @@
+use std::error::Error;
+use std::fmt;
+use std::io::Write;
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use quick_xml::events::{BytesEnd, BytesStart, BytesText, Event};
+use quick_xml::Writer as XmlWriter;
+
+use crate::model::{ExtensionMap, Feed, FeedType};
+use crate::xml::NS;
+
+pub mod atom;
+pub mod json;
+pub mod rss1;
+pub mod rss2;
+
+pub(crate) mod itunes;
+pub(crate) mod mediarss;
+pub(crate) mod podcast;
+
+#[cfg(test)]
+mod tests;
+
+pub type WriteFeedResult<T> = Result<T, WriteFeedError>;
+
+/// An error returned when writing a feed to a destination fails
+#[derive(Debug)]
+pub enum WriteFeedError {
+    // IO error
+    IoError(std::io::Error),
+    // Underlying issue with JSON serialisation
+    JsonSerde(serde_json::error::Error),
+    // The feed cannot be written in the requested format
+    UnsupportedFormat(FeedType),
+}
+
+impl From<std::io::Error> for WriteFeedError {
+    fn from(err: std::io::Error) -> Self {
+        WriteFeedError::IoError(err)
+    }
+}
+
+impl From<serde_json::error::Error> for WriteFeedError {
+    fn from(err: serde_json::error::Error) -> Self {
+        WriteFeedError::JsonSerde(err)
+    }
+}
+
+impl fmt::Display for WriteFeedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WriteFeedError::IoError(ie) => write!(f, "unable to write feed: {}", ie),
+            WriteFeedError::JsonSerde(je) => write!(f, "unable to write JSON: {}", je),
+            WriteFeedError::UnsupportedFormat(feed_type) => write!(f, "unsupported format: {:?}", feed_type),
+        }
+    }
+}
+
+impl Error for WriteFeedError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            WriteFeedError::IoError(ie) => Some(ie),
+            WriteFeedError::JsonSerde(je) => Some(je),
+            _ => None,
+        }
+    }
+}
+
+// Formats a timestamp per RFC-3339 (Atom, JSON Feed)
+pub(crate) fn format_rfc3339(timestamp: &DateTime<Utc>) -> String {
+    timestamp.to_rfc3339_opts(SecondsFormat::AutoSi, true)
+}
+
+/// Writer for the supported feed formats, with options configured through the `Builder`
+pub struct Writer {
+    pretty: bool,
+}
+
+impl Writer {
+    /// Writes the feed in the requested format
+    ///
+    /// All formats other than RSS 0.x are supported, as RSS 2.0 is backwards compatible with these earlier versions.
+    ///
+    /// # Arguments
+    ///
+    /// * `feed` - the feed to serialise
+    /// * `feed_type` - the format of the output
+    /// * `out` - destination for the document
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use feed_rs::model::FeedType;
+    /// use feed_rs::{parser, writer};
+    /// let xml = r#"
+    /// <feed xmlns="http://www.w3.org/2005/Atom">
+    ///    <title type="text">sample feed</title>
+    ///    <id>feed1</id>
+    /// </feed>
+    /// "#;
+    /// let feed = parser::parse(xml.as_bytes()).unwrap();
+    ///
+    /// let writer = writer::Builder::new().pretty(true).build();
+    ///
+    /// let mut out = Vec::new();
+    /// writer.write(&feed, FeedType::RSS2, &mut out).unwrap();
+    /// ```
+    pub fn write<W: Write>(&self, feed: &Feed, feed_type: FeedType, out: W) -> WriteFeedResult<()> {
+        match feed_type {
+            FeedType::Atom => atom::write_document(feed, &mut self.xml_writer(out)),
+            FeedType::JSON => self.write_json(feed, out),
+            FeedType::RSS1 => rss1::write_document(feed, &mut self.xml_writer(out)),
+            FeedType::RSS2 => rss2::write_document(feed, &mut self.xml_writer(out)),
+            FeedType::RSS0 => Err(WriteFeedError::UnsupportedFormat(feed_type)),
+        }
+    }
+
+    // Serialises the JSON Feed representation, indenting if required
+    fn write_json<W: Write>(&self, feed: &Feed, out: W) -> WriteFeedResult<()> {
+        let jf = json::convert(feed)?;
+        if self.pretty {
+            serde_json::to_writer_pretty(out, &jf)?;
+        } else {
+            serde_json::to_writer(out, &jf)?;
+        }
+        Ok(())
+    }
+
+    // Creates the XML writer, indenting if required
+    fn xml_writer<W: Write>(&self, out: W) -> XmlWriter<W> {
+        if self.pretty {
+            XmlWriter::new_with_indent(out, b' ', 2)
+        } else {
+            XmlWriter::new(out)
+        }
+    }
+}
+
+/// Builder to create instances of `Writer`
+#[derive(Default)]
+pub struct Builder {
+    pretty: bool,
+}
+
+impl Builder {
+    /// Create a new instance of the builder
+    pub fn new() -> Builder {
+        Builder::default()
+    }
+
+    /// Create a new instance of the writer
+    pub fn build(self) -> Writer {
+        Writer { pretty: self.pretty }
+    }
+
+    /// Indent the output for readability (off by default)
+    pub fn pretty(mut self, flag: bool) -> Self {
+        self.pretty = flag;
+        self
+    }
+}
+
+// Formats a timestamp per RFC-2822 (RSS)
+pub(crate) fn format_rfc2822(timestamp: &DateTime<Utc>) -> String {
+    timestamp.to_rfc2822()
+}
+
+// Filters the attributes down to those with a value
+pub(crate) fn optional_attributes<'a>(attributes: &[(&'a str, &'a Option<String>)]) -> Vec<(&'a str, &'a str)> {
+    attributes
+        .iter()
+        .filter_map(|(name, value)| value.as_deref().map(|value| (*name, value)))
+        .collect()
+}
+
+// Writes an element start tag with the supplied attributes
+pub(crate) fn write_start<W: Write>(writer: &mut XmlWriter<W>, name: &str, attributes: &[(&str, &str)]) -> WriteFeedResult<()> {
+    let mut start = BytesStart::new(name);
+    start.extend_attributes(attributes.iter().copied());
+    writer.write_event(Event::Start(start))?;
+    Ok(())
+}
+
+// Writes an element end tag
+pub(crate) fn write_end<W: Write>(writer: &mut XmlWriter<W>, name: &str) -> WriteFeedResult<()> {
+    writer.write_event(Event::End(BytesEnd::new(name)))?;
+    Ok(())
+}
+
+// Writes an element without children
+pub(crate) fn write_empty<W: Write>(writer: &mut XmlWriter<W>, name: &str, attributes: &[(&str, &str)]) -> WriteFeedResult<()> {
+    let mut start = BytesStart::new(name);
+    start.extend_attributes(attributes.iter().copied());
+    writer.write_event(Event::Empty(start))?;
+    Ok(())
+}
+
+// Writes the elements in unsupported namespaces retained by the parser, declaring the namespace of each as its default
+// The parser does not retain the namespace of a prefixed attribute, so those with a conventional prefix are declared in the usual namespace
+// (as when parsing a feed without the declaration), with any other prefixed attributes left out
+pub(crate) fn write_extensions<W: Write>(writer: &mut XmlWriter<W>, extensions: &ExtensionMap, parent_uri: Option<&str>) -> WriteFeedResult<()> {
+    for (uri, elements) in extensions {
+        for extension in elements.values().flatten() {
+            let mut declarations: Vec<(String, &str)> = Vec::new();
+            let mut written = Vec::new();
+            for (name, value) in &extension.attrs {
+                match name.split_once(':') {
+                    None | Some(("xml", _)) => written.push((name.as_str(), value.as_str())),
+                    Some((prefix, _)) => {
+                        if let Some(prefix_uri) = attribute_namespace(prefix) {
+                            let declaration = format!("xmlns:{}", prefix);
+                            if !declarations.iter().any(|(existing, _)| *existing == declaration) {
+                                declarations.push((declaration, prefix_uri));
+                            }
+                            written.push((name.as_str(), value.as_str()));
+                        }
+                    }
+                }
+            }
+
+            let mut attributes = Vec::new();
+            if parent_uri != Some(uri.as_str()) {
+                attributes.push(("xmlns", uri.as_str()));
+            }
+            attributes.extend(declarations.iter().map(|(name, uri)| (name.as_str(), *uri)));
+            attributes.extend(written);
+
+            write_start(writer, &extension.name, &attributes)?;
+            if let Some(value) = &extension.value {
+                writer.write_event(Event::Text(BytesText::new(value)))?;
+            }
+            write_extensions(writer, &extension.children, Some(uri))?;
+            write_end(writer, &extension.name)?;
+        }
+    }
+    Ok(())
+}
+
+// The namespace conventionally identified by the prefix of an attribute
+fn attribute_namespace(prefix: &str) -> Option<&'static str> {
+    match prefix {
+        "rdf" => Some("http://www.w3.org/1999/02/22-rdf-syntax-ns#"),
+        _ => NS::well_known_uri(prefix),
+    }
+}
+
+// Writes an element containing (escaped) text
+pub(crate) fn write_text<W: Write>(writer: &mut XmlWriter<W>, name: &str, attributes: &[(&str, &str)], text: &str) -> WriteFeedResult<()> {
+    write_start(writer, name, attributes)?;
+    writer.write_event(Event::Text(BytesText::new(text)))?;
+    write_end(writer, name)
+}
@@ -0,0 +1,170 @@
+use std::io::Write;
+
+use quick_xml::Writer;
+
+use crate::model::{PodcastAlternateEnclosure, PodcastChannel, PodcastItem, PodcastMedia, PodcastPerson, PodcastValue};
+use crate::writer::{optional_attributes, write_empty, write_end, write_start, write_text, WriteFeedResult};
+
+// Writes the Podcasting 2.0 elements at the channel level
+pub(crate) fn write_channel_elements<W: Write>(writer: &mut Writer<W>, podcast: &PodcastChannel) -> WriteFeedResult<()> {
+    if let Some(guid) = &podcast.guid {
+        write_text(writer, "podcast:guid", &[], guid)?;
+    }
+
+    if let Some(locked) = &podcast.locked {
+        let attributes = optional_attributes(&[("owner", &locked.owner)]);
+        write_text(writer, "podcast:locked", &attributes, if locked.locked { "yes" } else { "no" })?;
+    }
+
+    for funding in &podcast.funding {
+        write_text(
+            writer,
+            "podcast:funding",
+            &[("url", funding.url.as_str())],
+            funding.label.as_deref().unwrap_or_default(),
+        )?;
+    }
+
+    for person in &podcast.persons {
+        write_person(writer, person)?;
+    }
+
+    for value in &podcast.value {
+        write_value(writer, value)?;
+    }
+
+    Ok(())
+}
+
+// Writes the Podcasting 2.0 elements at the item level, other than those describing the media
+pub(crate) fn write_item_elements<W: Write>(writer: &mut Writer<W>, podcast: &PodcastItem) -> WriteFeedResult<()> {
+    if let Some(season) = &podcast.season {
+        let attributes = optional_attributes(&[("name", &season.name)]);
+        write_text(writer, "podcast:season", &attributes, &season.number.to_string())?;
+    }
+
+    if let Some(episode) = &podcast.episode {
+        let attributes = optional_attributes(&[("display", &episode.display)]);
+        write_text(writer, "podcast:episode", &attributes, &episode.number.to_string())?;
+    }
+
+    for person in &podcast.persons {
+        write_person(writer, person)?;
+    }
+
+    for value in &podcast.value {
+        write_value(writer, value)?;
+    }
+
+    Ok(())
+}
+
+// Writes the Podcasting 2.0 elements describing the media of an item
+pub(crate) fn write_media_elements<W: Write>(writer: &mut Writer<W>, podcast: &PodcastMedia) -> WriteFeedResult<()> {
+    for transcript in &podcast.transcripts {
+        let content_type = transcript.content_type.as_ref().map(|ct| ct.to_string());
+        let mut attributes = vec![("url", transcript.url.as_str())];
+        attributes.extend(optional_attributes(&[
+            ("type", &content_type),
+            ("language", &transcript.language),
+            ("rel", &transcript.rel),
+        ]));
+        write_empty(writer, "podcast:transcript", &attributes)?;
+    }
+
+    if let Some(chapters) = &podcast.chapters {
+        let content_type = chapters.content_type.as_ref().map(|ct| ct.to_string());
+        let mut attributes = vec![("url", chapters.url.as_str())];
+        attributes.extend(optional_attributes(&[("type", &content_type)]));
+        write_empty(writer, "podcast:chapters", &attributes)?;
+    }
+
+    for soundbite in &podcast.soundbites {
+        let start_time = soundbite.start_time.as_secs_f64().to_string();
+        let duration = soundbite.duration.as_secs_f64().to_string();
+        let attributes = [("startTime", start_time.as_str()), ("duration", duration.as_str())];
+        match &soundbite.title {
+            Some(title) => write_text(writer, "podcast:soundbite", &attributes, title)?,
+            None => write_empty(writer, "podcast:soundbite", &attributes)?,
+        }
+    }
+
+    for enclosure in &podcast.alternate_enclosures {
+        write_alternate_enclosure(writer, enclosure)?;
+    }
+
+    Ok(())
+}
+
+// Writes <podcast:alternateEnclosure> and the contained sources
+fn write_alternate_enclosure<W: Write>(writer: &mut Writer<W>, enclosure: &PodcastAlternateEnclosure) -> WriteFeedResult<()> {
+    let content_type = enclosure.content_type.as_ref().map(|ct| ct.to_string());
+    let length = enclosure.length.map(|length| length.to_string());
+    let bitrate = enclosure.bitrate.map(|bitrate| bitrate.to_string());
+    let height = enclosure.height.map(|height| height.to_string());
+    let default = enclosure.default.then(|| "true".to_string());
+    let attributes = optional_attributes(&[
+        ("type", &content_type),
+        ("length", &length),
+        ("bitrate", &bitrate),
+        ("height", &height),
+        ("lang", &enclosure.language),
+        ("title", &enclosure.title),
+        ("rel", &enclosure.rel),
+        ("codecs", &enclosure.codecs),
+        ("default", &default),
+    ]);
+    write_start(writer, "podcast:alternateEnclosure", &attributes)?;
+
+    for source in &enclosure.sources {
+        let content_type = source.content_type.as_ref().map(|ct| ct.to_string());
+        let mut attributes = vec![("uri", source.uri.as_str())];
+        attributes.extend(optional_attributes(&[("contentType", &content_type)]));
+        write_empty(writer, "podcast:source", &attributes)?;
+    }
+
+    if let Some(integrity) = &enclosure.integrity {
+        write_empty(
+            writer,
+            "podcast:integrity",
+            &[("type", integrity.integrity_type.as_str()), ("value", integrity.value.as_str())],
+        )?;
+    }
+
+    write_end(writer, "podcast:alternateEnclosure")
+}
+
+// Writes <podcast:person>
+fn write_person<W: Write>(writer: &mut Writer<W>, person: &PodcastPerson) -> WriteFeedResult<()> {
+    let img = person.img.as_ref().map(|img| img.to_string());
+    let href = person.href.as_ref().map(|href| href.to_string());
+    let attributes = optional_attributes(&[("role", &person.role), ("group", &person.group), ("img", &img), ("href", &href)]);
+    write_text(writer, "podcast:person", &attributes, &person.name)
+}
+
+// Writes <podcast:value> and the contained recipients
+fn write_value<W: Write>(writer: &mut Writer<W>, value: &PodcastValue) -> WriteFeedResult<()> {
+    let mut attributes = vec![("type", value.value_type.as_str()), ("method", value.method.as_str())];
+    attributes.extend(optional_attributes(&[("suggested", &value.suggested)]));
+    write_start(writer, "podcast:value", &attributes)?;
+
+    for recipient in &value.recipients {
+        let split = recipient.split.to_string();
+        let mut attributes = optional_attributes(&[
+            ("name", &recipient.name),
+            ("customKey", &recipient.custom_key),
+            ("customValue", &recipient.custom_value),
+        ]);
+        attributes.extend([
+            ("type", recipient.recipient_type.as_str()),
+            ("address", recipient.address.as_str()),
+            ("split", split.as_str()),
+        ]);
+        if recipient.fee {
+            attributes.push(("fee", "true"));
+        }
+        write_empty(writer, "podcast:valueRecipient", &attributes)?;
+    }
+
+    write_end(writer, "podcast:value")
+}
@@ -0,0 +1,196 @@
+use std::io::Write;
+
+use quick_xml::events::{BytesDecl, Event};
+use quick_xml::Writer;
+
+use crate::model::{Entry, Feed, Image, Link};
+use crate::writer::{format_rfc3339, write_empty, write_end, write_extensions, write_start, write_text, WriteFeedResult};
+
+#[cfg(test)]
+mod tests;
+
+/// Namespaces for the RDF container and the modules that may be emitted
+const NAMESPACES: [(&str, &str); 4] = [
+    ("xmlns:rdf", "http://www.w3.org/1999/02/22-rdf-syntax-ns#"),
+    ("xmlns", "http://purl.org/rss/1.0/"),
+    ("xmlns:content", "http://purl.org/rss/1.0/modules/content/"),
+    ("xmlns:dc", "http://purl.org/dc/elements/1.1/"),
+];
+
+/// Writes the feed as an RSS 1.0 (RDF Site Summary) document
+///
+/// Each item is identified (via "rdf:about") by its first link, falling back to the identifier of the entry, and is listed in the "rdf:Seq" of the channel.
+/// The elements RSS 1.0 requires of the channel and items are always emitted, with an empty "title" or "description" if the model has none,
+/// and the "link" falling back to the identifier.
+/// The Dublin Core module is used for authors, dates, the language and rights as these have no equivalent in the core RSS 1.0 elements.
+///
+/// # Arguments
+///
+/// * `feed` - the feed to serialise
+/// * `out` - destination for the XML document
+///
+/// # Examples
+///
+/// ```
+/// use feed_rs::{parser, writer};
+/// let xml = r#"
+/// <feed xmlns="http://www.w3.org/2005/Atom">
+///    <title type="text">sample feed</title>
+///    <id>feed1</id>
+///    <link rel="alternate" href="https://example.com/"/>
+///    <entry>
+///        <title>sample entry</title>
+///        <id>entry1</id>
+///        <link rel="alternate" href="https://example.com/entry1"/>
+///    </entry>
+/// </feed>
+/// "#;
+/// let feed = parser::parse(xml.as_bytes()).unwrap();
+///
+/// let mut rdf = Vec::new();
+/// writer::rss1::write(&feed, &mut rdf).unwrap();
+/// ```
+pub fn write<W: Write>(feed: &Feed, out: W) -> WriteFeedResult<()> {
+    write_document(feed, &mut Writer::new(out))
+}
+
+// Writes the complete document with the supplied XML writer (which may be configured for indentation)
+pub(crate) fn write_document<W: Write>(feed: &Feed, writer: &mut Writer<W>) -> WriteFeedResult<()> {
+    writer.write_event(Event::Decl(BytesDecl::new("1.0", Some("UTF-8"), None)))?;
+
+    write_start(writer, "rdf:RDF", &NAMESPACES)?;
+
+    write_channel(writer, feed)?;
+
+    if let Some(logo) = &feed.logo {
+        write_image(writer, logo, &feed.links)?;
+    }
+
+    for entry in &feed.entries {
+        write_item(writer, entry)?;
+    }
+
+    write_end(writer, "rdf:RDF")
+}
+
+// Writes the <channel> element, including the table of contents for the items
+fn write_channel<W: Write>(writer: &mut Writer<W>, feed: &Feed) -> WriteFeedResult<()> {
+    let about = resource(&feed.links, &feed.id);
+    write_start(writer, "channel", &[("rdf:about", about)])?;
+
+    // The title, link and description are mandatory, so fall back to empty text and the resource itself
+    let title = feed.title.as_ref().map_or("", |title| title.content.as_str());
+    write_text(writer, "title", &[], title)?;
+
+    let mut links = page_links(&feed.links).peekable();
+    if links.peek().is_none() {
+        write_text(writer, "link", &[], about)?;
+    }
+    for link in links {
+        write_text(writer, "link", &[], &link.href)?;
+    }
+
+    let description = feed.description.as_ref().map_or("", |description| description.content.as_str());
+    write_text(writer, "description", &[], description)?;
+
+    if let Some(logo) = &feed.logo {
+        write_empty(writer, "image", &[("rdf:resource", &logo.uri)])?;
+    }
+
+    write_start(writer, "items", &[])?;
+    write_start(writer, "rdf:Seq", &[])?;
+    for entry in &feed.entries {
+        write_empty(writer, "rdf:li", &[("rdf:resource", resource(&entry.links, &entry.id))])?;
+    }
+    write_end(writer, "rdf:Seq")?;
+    write_end(writer, "items")?;
+
+    for author in &feed.authors {
+        write_text(writer, "dc:creator", &[], &author.name)?;
+    }
+
+    if let Some(date) = feed.published.as_ref().or(feed.updated.as_ref()) {
+        write_text(writer, "dc:date", &[], &format_rfc3339(date))?;
+    }
+
+    if let Some(language) = &feed.language {
+        write_text(writer, "dc:language", &[], language)?;
+    }
+
+    if let Some(rights) = &feed.rights {
+        write_text(writer, "dc:rights", &[], &rights.content)?;
+    }
+
+    write_extensions(writer, &feed.extensions, None)?;
+
+    write_end(writer, "channel")
+}
+
+// Writes <image> (the link is required, so falls back to the channel link)
+fn write_image<W: Write>(writer: &mut Writer<W>, image: &Image, links: &[Link]) -> WriteFeedResult<()> {
+    write_start(writer, "image", &[("rdf:about", &image.uri)])?;
+
+    if let Some(title) = &image.title {
+        write_text(writer, "title", &[], title)?;
+    }
+
+    write_text(writer, "url", &[], &image.uri)?;
+
+    if let Some(link) = image.link.as_ref().or_else(|| page_links(links).next()) {
+        write_text(writer, "link", &[], &link.href)?;
+    }
+
+    write_end(writer, "image")
+}
+
+// Writes <item>
+fn write_item<W: Write>(writer: &mut Writer<W>, entry: &Entry) -> WriteFeedResult<()> {
+    let about = resource(&entry.links, &entry.id);
+    write_start(writer, "item", &[("rdf:about", about)])?;
+
+    let title = entry.title.as_ref().map_or("", |title| title.content.as_str());
+    write_text(writer, "title", &[], title)?;
+
+    // The link is mandatory, and the parser will discard items without one
+    let mut links = page_links(&entry.links).peekable();
+    if links.peek().is_none() {
+        write_text(writer, "link", &[], about)?;
+    }
+    for link in links {
+        write_text(writer, "link", &[], &link.href)?;
+    }
+
+    if let Some(summary) = &entry.summary {
+        write_text(writer, "description", &[], &summary.content)?;
+    }
+
+    if let Some(body) = entry.content.as_ref().and_then(|content| content.body.as_ref()) {
+        write_text(writer, "content:encoded", &[], body)?;
+    }
+
+    for author in &entry.authors {
+        write_text(writer, "dc:creator", &[], &author.name)?;
+    }
+
+    if let Some(date) = entry.published.as_ref().or(entry.updated.as_ref()) {
+        write_text(writer, "dc:date", &[], &format_rfc3339(date))?;
+    }
+
+    if let Some(rights) = &entry.rights {
+        write_text(writer, "dc:rights", &[], &rights.content)?;
+    }
+
+    write_extensions(writer, &entry.extensions, None)?;
+
+    write_end(writer, "item")
+}
+
+// Links to the resource itself (i.e. without a relation, or an "alternate" representation)
+fn page_links(links: &[Link]) -> impl Iterator<Item = &Link> {
+    links.iter().filter(|link| matches!(link.rel.as_deref(), None | Some("alternate")))
+}
+
+// The URI identifying the channel or item
+fn resource<'a>(links: &'a [Link], id: &'a str) -> &'a str {
+    page_links(links).next().map_or(id, |link| link.href.as_str())
+}
@@ -0,0 +1,70 @@
+use crate::model::{Entry, Feed, FeedType, Image, Link, Person, Text};
+use crate::parser;
+use crate::validator;
+use crate::writer::{rss1, tests};
+
+// Verify that each of the RSS 1.0 fixtures survives a round-trip through the writer
+#[test]
+fn test_round_trip_fixtures() {
+    tests::test_round_trip_fixtures("rss1", FeedType::RSS1, |feed, out| rss1::write(feed, out), |_, _| {});
+}
+
+// Verify the RDF structure and Dublin Core elements are emitted
+#[test]
+fn test_write_constructs() {
+    let feed = Feed::new(FeedType::RSS1)
+        .id("urn:feed:1")
+        .title(Text::new("Fish & Chips".into()))
+        .link(Link::new("https://example.com/", None))
+        .description(Text::new("Tasty".into()))
+        .author(Person::new("Jane"))
+        .language("en")
+        .rights(Text::new("Copyright 2021".into()))
+        .published("2021-03-04T05:06:07Z")
+        .logo(Image::new("https://example.com/logo.png".into()).title("Logo").link("https://example.com/"))
+        .entry(
+            Entry::default()
+                .id("urn:entry:1")
+                .title(Text::new("Entry".into()))
+                .link(Link::new("https://example.com/entry", None))
+                .author(Person::new("Joe"))
+                .published("2021-03-05T05:06:07Z"),
+        );
+
+    let mut out = Vec::new();
+    rss1::write(&feed, &mut out).unwrap();
+    let written = String::from_utf8(out).unwrap();
+
+    assert!(written.contains(r#"<channel rdf:about="https://example.com/"><title>Fish &amp; Chips</title>"#));
+    assert!(written.contains(r#"<image rdf:resource="https://example.com/logo.png"/>"#));
+    assert!(written.contains(r#"<items><rdf:Seq><rdf:li rdf:resource="https://example.com/entry"/></rdf:Seq></items>"#));
+    assert!(written
+        .contains(r#"<dc:creator>Jane</dc:creator><dc:date>2021-03-04T05:06:07Z</dc:date><dc:language>en</dc:language><dc:rights>Copyright 2021</dc:rights>"#));
+    assert!(written.contains(
+        r#"<image rdf:about="https://example.com/logo.png"><title>Logo</title><url>https://example.com/logo.png</url><link>https://example.com/</link></image>"#
+    ));
+    assert!(written.contains(r#"<item rdf:about="https://example.com/entry"><title>Entry</title><link>https://example.com/entry</link><dc:creator>Joe</dc:creator><dc:date>2021-03-05T05:06:07Z</dc:date></item>"#));
+
+    // The identifiers are generated by the parser as RSS 1.0 does not have them
+    let mut actual = parser::parse(written.as_bytes()).unwrap();
+    actual.id = feed.id.clone();
+    actual.entries[0].id = feed.entries[0].id.clone();
+    assert_eq!(actual, feed);
+}
+
+// Verify the required elements are always written, falling back to the identifier and empty text
+#[test]
+fn test_write_required() {
+    let feed = Feed::new(FeedType::Atom)
+        .id("https://example.com/feed")
+        .entry(Entry::default().id("https://example.com/1"));
+
+    let mut out = Vec::new();
+    rss1::write(&feed, &mut out).unwrap();
+    assert!(validator::validate(out.as_slice()).unwrap().is_empty());
+    let written = String::from_utf8(out).unwrap();
+    assert!(
+        written.contains(r#"<channel rdf:about="https://example.com/feed"><title></title><link>https://example.com/feed</link><description></description>"#)
+    );
+    assert!(written.contains(r#"<item rdf:about="https://example.com/1"><title></title><link>https://example.com/1</link>"#));
+}
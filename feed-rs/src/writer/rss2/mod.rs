@@ -0,0 +1,422 @@
+use std::io::Write;
+
+use quick_xml::events::{BytesDecl, Event};
+use quick_xml::Writer;
+
+use crate::model::{Category, Content, Entry, Feed, Image, Link, MediaContent, MediaObject, Person};
+use crate::writer::atom::write_link;
+use crate::writer::{
+    format_rfc2822, itunes, mediarss, optional_attributes, podcast, write_empty, write_end, write_extensions, write_start, write_text, WriteFeedResult,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// Namespaces for the extensions that may be emitted
+const NAMESPACES: [(&str, &str); 6] = [
+    ("xmlns:atom", "http://www.w3.org/2005/Atom"),
+    ("xmlns:content", "http://purl.org/rss/1.0/modules/content/"),
+    ("xmlns:dc", "http://purl.org/dc/elements/1.1/"),
+    ("xmlns:itunes", "http://www.itunes.com/dtds/podcast-1.0.dtd"),
+    ("xmlns:media", "http://search.yahoo.com/mrss/"),
+    ("xmlns:podcast", "https://podcastindex.org/namespace/1.0"),
+];
+
+/// Writes the feed as an RSS 2.0 document
+///
+/// The mapping is the inverse of the RSS 2.0 parser, so a feed parsed from RSS 2.0 will result in the same model when the output is parsed.
+/// Feeds from other formats are mapped on a best-effort basis e.g. links that are not "alternate" are written as "atom:link" elements.
+/// The elements RSS 2.0 requires of the channel are always emitted, so those missing from the model are given a fallback:
+/// * "title" and "description" are empty
+/// * "link" is the "self" link of the feed, or its identifier
+///
+/// # Arguments
+///
+/// * `feed` - the feed to serialise
+/// * `out` - destination for the XML document
+///
+/// # Examples
+///
+/// ```
+/// use feed_rs::{parser, writer};
+/// let xml = r#"
+/// <rss version="2.0">
+///   <channel>
+///     <title>sample feed</title>
+///     <link>https://example.com/</link>
+///     <item>
+///       <title>sample item</title>
+///       <enclosure url="https://example.com/episode1.mp3" length="1234" type="audio/mpeg"/>
+///     </item>
+///   </channel>
+/// </rss>
+/// "#;
+/// let feed = parser::parse(xml.as_bytes()).unwrap();
+///
+/// let mut rss = Vec::new();
+/// writer::rss2::write(&feed, &mut rss).unwrap();
+/// ```
+pub fn write<W: Write>(feed: &Feed, out: W) -> WriteFeedResult<()> {
+    write_document(feed, &mut Writer::new(out))
+}
+
+// Writes the complete document with the supplied XML writer (which may be configured for indentation)
+pub(crate) fn write_document<W: Write>(feed: &Feed, writer: &mut Writer<W>) -> WriteFeedResult<()> {
+    writer.write_event(Event::Decl(BytesDecl::new("1.0", Some("UTF-8"), None)))?;
+
+    let mut attributes = vec![("version", "2.0")];
+    attributes.extend(NAMESPACES);
+    write_start(writer, "rss", &attributes)?;
+
+    write_channel(writer, feed)?;
+
+    write_end(writer, "rss")
+}
+
+// Writes <category> or, if it has sub-categories, <itunes:category>
+fn write_category<W: Write>(writer: &mut Writer<W>, category: &Category) -> WriteFeedResult<()> {
+    if category.subcategories.is_empty() {
+        let attributes = optional_attributes(&[("domain", &category.scheme)]);
+        write_text(writer, "category", &attributes, &category.term)
+    } else {
+        itunes::write_category(writer, category)
+    }
+}
+
+// Writes the <channel> element
+fn write_channel<W: Write>(writer: &mut Writer<W>, feed: &Feed) -> WriteFeedResult<()> {
+    write_start(writer, "channel", &[])?;
+
+    let title = feed.title.as_ref().map_or("", |title| title.content.as_str());
+    write_text(writer, "title", &[], title)?;
+
+    // The channel link is the link without a relation (as parsed from RSS) or failing that, the first "alternate" link
+    let channel_link = feed
+        .links
+        .iter()
+        .position(|link| link.rel.is_none())
+        .or_else(|| feed.links.iter().position(|link| link.rel.as_deref() == Some("alternate")));
+    if channel_link.is_none() {
+        write_text(writer, "link", &[], fallback_link(feed))?;
+    }
+    for (i, link) in feed.links.iter().enumerate() {
+        if link.rel.is_none() || Some(i) == channel_link {
+            write_text(writer, "link", &[], &link.href)?;
+        } else {
+            write_link(writer, "atom:link", link)?;
+        }
+    }
+
+    let description = feed.description.as_ref().map_or("", |description| description.content.as_str());
+    write_text(writer, "description", &[], description)?;
+
+    if let Some(language) = &feed.language {
+        write_text(writer, "language", &[], language)?;
+    }
+
+    if let Some(rights) = &feed.rights {
+        write_text(writer, "copyright", &[], &rights.content)?;
+    }
+
+    // The owner is written with the other itunes tags
+    let owner = feed.itunes.as_ref().and_then(|meta| meta.owner.as_ref());
+    for contributor in feed.contributors.iter().filter(|contributor| Some(*contributor) != owner) {
+        write_contributor(writer, contributor)?;
+    }
+
+    if let Some(published) = &feed.published {
+        write_text(writer, "pubDate", &[], &format_rfc2822(published))?;
+    }
+
+    if let Some(updated) = &feed.updated {
+        write_text(writer, "lastBuildDate", &[], &format_rfc2822(updated))?;
+    }
+
+    for category in &feed.categories {
+        write_category(writer, category)?;
+    }
+
+    if let Some(generator) = &feed.generator {
+        let attributes = optional_attributes(&[("uri", &generator.uri)]);
+        write_text(writer, "generator", &attributes, &generator.content)?;
+    }
+
+    if let Some(ttl) = feed.ttl {
+        write_text(writer, "ttl", &[], &ttl.to_string())?;
+    }
+
+    if let Some(logo) = &feed.logo {
+        write_image(writer, logo)?;
+    }
+
+    // The itunes namespace carries the authors and explicit flag at the channel level, with other ratings in the media namespace
+    for author in &feed.authors {
+        itunes::write_author(writer, &author.name)?;
+    }
+
+    match &feed.rating {
+        Some(rating) if rating.urn == "itunes" => itunes::write_explicit(writer, rating)?,
+        Some(rating) => mediarss::write_media_rating(writer, rating)?,
+        None => {}
+    }
+
+    if let Some(media_obj) = &feed.media {
+        mediarss::write_media_elements(writer, media_obj)?;
+    }
+
+    if let Some(meta) = &feed.itunes {
+        itunes::write_meta(writer, meta)?;
+    }
+
+    if let Some(podcast) = &feed.podcast {
+        podcast::write_channel_elements(writer, podcast)?;
+    }
+
+    write_extensions(writer, &feed.extensions, None)?;
+
+    for entry in &feed.entries {
+        write_item(writer, entry)?;
+    }
+
+    write_end(writer, "channel")
+}
+
+// Writes <managingEditor> and <webMaster> from the contact created by the parser, with other contributors written as <atom:contributor>
+fn write_contributor<W: Write>(writer: &mut Writer<W>, person: &Person) -> WriteFeedResult<()> {
+    match (person.name.as_str(), &person.email) {
+        ("managingEditor", Some(email)) => write_text(writer, "managingEditor", &[], email),
+        ("webMaster", Some(email)) => write_text(writer, "webMaster", &[], email),
+        _ => {
+            write_start(writer, "atom:contributor", &[])?;
+            write_text(writer, "atom:name", &[], &person.name)?;
+            if let Some(uri) = &person.uri {
+                write_text(writer, "atom:uri", &[], uri)?;
+            }
+            if let Some(email) = &person.email {
+                write_text(writer, "atom:email", &[], email)?;
+            }
+            write_end(writer, "atom:contributor")
+        }
+    }
+}
+
+// Writes <content:encoded>
+fn write_content_encoded<W: Write>(writer: &mut Writer<W>, content: &Content) -> WriteFeedResult<()> {
+    if let Some(body) = &content.body {
+        // The parser uses the xml:base in scope as the source of the content
+        let attributes = content.src.as_ref().map(|src| ("xml:base", src.href.as_str()));
+        write_text(writer, "content:encoded", attributes.as_slice(), body)?;
+    }
+
+    Ok(())
+}
+
+// Writes the default media object for an item, using <enclosure> and the itunes namespace where possible
+fn write_default_media<W: Write>(writer: &mut Writer<W>, media_obj: &MediaObject) -> WriteFeedResult<()> {
+    // An enclosure can only describe the URL, length and type of the content
+    let mut enclosure_written = false;
+    for content in &media_obj.content {
+        if !enclosure_written && is_enclosure(content) {
+            write_enclosure(writer, content)?;
+            enclosure_written = true;
+        } else {
+            mediarss::write_media_content(writer, content)?;
+        }
+    }
+
+    if let Some(title) = &media_obj.title {
+        itunes::write_text_construct(writer, "itunes:title", title)?;
+    }
+
+    // Similarly, the itunes image is just a link to the image
+    let mut image_written = false;
+    for thumbnail in &media_obj.thumbnails {
+        let image = &thumbnail.image;
+        if !image_written && thumbnail.time.is_none() && image.width.is_none() && image.height.is_none() {
+            itunes::write_image(writer, &image.uri)?;
+            image_written = true;
+        } else {
+            mediarss::write_media_thumbnail(writer, thumbnail)?;
+        }
+    }
+
+    if let Some(duration) = &media_obj.duration {
+        itunes::write_duration(writer, duration)?;
+    }
+
+    for credit in &media_obj.credits {
+        itunes::write_author(writer, &credit.entity)?;
+    }
+
+    if let Some(description) = &media_obj.description {
+        itunes::write_text_construct(writer, "itunes:summary", description)?;
+    }
+
+    if let Some(community) = &media_obj.community {
+        mediarss::write_media_community(writer, community)?;
+    }
+
+    for text in &media_obj.texts {
+        mediarss::write_media_text(writer, text)?;
+    }
+
+    mediarss::write_media_optional_elements(writer, media_obj)?;
+
+    if let Some(podcast) = &media_obj.podcast {
+        podcast::write_media_elements(writer, podcast)?;
+    }
+
+    Ok(())
+}
+
+// Writes <enclosure>
+fn write_enclosure<W: Write>(writer: &mut Writer<W>, content: &MediaContent) -> WriteFeedResult<()> {
+    if let Some(url) = &content.url {
+        let length = content.size.map(|size| size.to_string());
+        let content_type = content.content_type.as_ref().map(|ct| ct.to_string());
+
+        let mut attributes = vec![("url", url.as_str())];
+        attributes.extend(optional_attributes(&[("length", &length), ("type", &content_type)]));
+
+        write_empty(writer, "enclosure", &attributes)?;
+    }
+
+    Ok(())
+}
+
+// Writes <image>
+fn write_image<W: Write>(writer: &mut Writer<W>, image: &Image) -> WriteFeedResult<()> {
+    write_start(writer, "image", &[])?;
+
+    write_text(writer, "url", &[], &image.uri)?;
+
+    if let Some(title) = &image.title {
+        write_text(writer, "title", &[], title)?;
+    }
+
+    if let Some(link) = &image.link {
+        write_text(writer, "link", &[], &link.href)?;
+    }
+
+    if let Some(width) = image.width {
+        write_text(writer, "width", &[], &width.to_string())?;
+    }
+
+    if let Some(height) = image.height {
+        write_text(writer, "height", &[], &height.to_string())?;
+    }
+
+    if let Some(description) = &image.description {
+        write_text(writer, "description", &[], description)?;
+    }
+
+    write_end(writer, "image")
+}
+
+// Writes <item>
+//
+// This reverses the mapping described on the parser's handle_item:
+// * the content of the entry is written as "content:encoded"
+// * the summary of the entry is written as "description"
+// * the default media object (the last, as it is appended after any groups) is written as an "enclosure" plus itunes, MediaRSS and Podcasting 2.0 elements
+// * other media objects are written as a "media:group"
+fn write_item<W: Write>(writer: &mut Writer<W>, entry: &Entry) -> WriteFeedResult<()> {
+    write_start(writer, "item", &[])?;
+
+    if let Some(title) = &entry.title {
+        write_text(writer, "title", &[], &title.content)?;
+    }
+
+    for link in &entry.links {
+        write_item_link(writer, link)?;
+    }
+
+    if let Some(summary) = &entry.summary {
+        write_text(writer, "description", &[], &summary.content)?;
+    }
+
+    for author in &entry.authors {
+        write_item_author(writer, author)?;
+    }
+
+    for category in &entry.categories {
+        write_category(writer, category)?;
+    }
+
+    if !entry.id.is_empty() {
+        // The guid is a permalink by default, so flag it if it is not one of the links
+        if entry.links.iter().any(|link| link.href == entry.id) {
+            write_text(writer, "guid", &[], &entry.id)?;
+        } else {
+            write_text(writer, "guid", &[("isPermaLink", "false")], &entry.id)?;
+        }
+    }
+
+    if let Some(published) = entry.published.as_ref().or(entry.updated.as_ref()) {
+        write_text(writer, "pubDate", &[], &format_rfc2822(published))?;
+    }
+
+    if let Some(content) = &entry.content {
+        write_content_encoded(writer, content)?;
+    }
+
+    if let Some(meta) = &entry.itunes {
+        itunes::write_meta(writer, meta)?;
+    }
+
+    if let Some(podcast) = &entry.podcast {
+        podcast::write_item_elements(writer, podcast)?;
+    }
+
+    // The parser only emits a default object if it has content, so anything else needs to be in a group to be retained
+    let (groups, default) = match entry.media.split_last() {
+        Some((last, others)) if last.has_content() => (others, Some(last)),
+        _ => (entry.media.as_slice(), None),
+    };
+    for media_obj in groups {
+        mediarss::write_media_group(writer, media_obj)?;
+    }
+    if let Some(media_obj) = default {
+        write_default_media(writer, media_obj)?;
+    }
+
+    write_extensions(writer, &entry.extensions, None)?;
+
+    write_end(writer, "item")
+}
+
+// Writes <author> if we have an email, otherwise <dc:creator>
+fn write_item_author<W: Write>(writer: &mut Writer<W>, person: &Person) -> WriteFeedResult<()> {
+    match &person.email {
+        // The parser uses the element name for the name of the person
+        Some(email) if person.name == "author" => write_text(writer, "author", &[], email),
+        Some(email) => write_text(writer, "author", &[], &format!("{} ({})", email, person.name)),
+        None => write_text(writer, "dc:creator", &[], &person.name),
+    }
+}
+
+// The channel link for a feed without a page of its own: the feed document itself
+fn fallback_link(feed: &Feed) -> &str {
+    feed.links
+        .iter()
+        .find(|link| link.rel.as_deref() == Some("self"))
+        .map_or(feed.id.as_str(), |link| link.href.as_str())
+}
+
+// Writes <link> for the item, or <atom:link> if the link has a relation other than "alternate"
+fn write_item_link<W: Write>(writer: &mut Writer<W>, link: &Link) -> WriteFeedResult<()> {
+    match link.rel.as_deref() {
+        None | Some("alternate") => write_text(writer, "link", &[], &link.href),
+        _ => write_link(writer, "atom:link", link),
+    }
+}
+
+// Checks if the content may be written as an enclosure without losing information
+fn is_enclosure(content: &MediaContent) -> bool {
+    content.url.is_some()
+        && content.width.is_none()
+        && content.height.is_none()
+        && content.duration.is_none()
+        && content.rating.is_none()
+        && content.player.is_none()
+}
@@ -0,0 +1,130 @@
+use crate::model::{Category, Entry, Feed, FeedType, Image, ItunesMeta, Link, MediaContent, MediaObject, Person, Text};
+use crate::parser;
+use crate::validator;
+use crate::writer::{rss2, tests};
+
+// The required elements the writer falls back to: an empty title and description, and the link written
+// RSS has no identifier for the channel, so a feed without a link of its own will get a new identifier from the fallback
+fn fallbacks(feed: &mut Feed, reparsed: &mut Feed) {
+    if !feed.links.iter().any(|link| matches!(link.rel.as_deref(), None | Some("alternate"))) {
+        feed.links.insert(0, reparsed.links[0].clone());
+        reparsed.id = feed.id.clone();
+    }
+    feed.title.get_or_insert_with(|| Text::new("".into()));
+    feed.description.get_or_insert_with(|| Text::new("".into()));
+}
+
+// Verify that each of the RSS 2.0 fixtures survives a round-trip through the writer
+#[test]
+fn test_round_trip_fixtures() {
+    tests::test_round_trip_fixtures("rss2", FeedType::RSS2, |feed, out| rss2::write(feed, out), fallbacks);
+}
+
+// Verify the RSS specific elements are emitted
+#[test]
+fn test_write_constructs() {
+    let feed = Feed::new(FeedType::RSS2)
+        .id("urn:feed:1")
+        .title(Text::new("Fish & Chips".into()))
+        .link(Link::new("https://example.com/", None))
+        .link(Link::new("https://example.com/feed.xml", None).rel("self").media_type("application/rss+xml"))
+        .description(Text::new("Tasty".into()))
+        .contributor(Person::new("managingEditor").email("editor@example.com"))
+        .category(Category::new("food").scheme("https://example.com/tags"))
+        .ttl(60)
+        .logo(
+            Image::new("https://example.com/logo.png".into())
+                .title("Logo")
+                .link("https://example.com/")
+                .width(88),
+        )
+        .entry(
+            Entry::default()
+                .id("urn:entry:1")
+                .title(Text::new("Entry".into()))
+                .link(Link::new("https://example.com/entry", None))
+                .published("2021-03-04T05:06:07Z")
+                .updated_parsed("2021-03-04T05:06:07Z")
+                .author(Person::new("author").email("jane@example.com"))
+                .media(MediaObject::default().content(MediaContent::new().url("https://example.com/audio.mp3").content_type("audio/mpeg").size(1234))),
+        );
+
+    let mut out = Vec::new();
+    rss2::write(&feed, &mut out).unwrap();
+    let written = String::from_utf8(out).unwrap();
+
+    assert!(written.starts_with(r#"<?xml version="1.0" encoding="UTF-8"?><rss version="2.0""#));
+    assert!(written.contains(r#"<title>Fish &amp; Chips</title><link>https://example.com/</link>"#));
+    assert!(written.contains(r#"<atom:link href="https://example.com/feed.xml" rel="self" type="application/rss+xml"/>"#));
+    assert!(written.contains(r#"<managingEditor>editor@example.com</managingEditor>"#));
+    assert!(written.contains(r#"<category domain="https://example.com/tags">food</category>"#));
+    assert!(written.contains(r#"<ttl>60</ttl>"#));
+    assert!(written.contains(r#"<image><url>https://example.com/logo.png</url><title>Logo</title><link>https://example.com/</link><width>88</width></image>"#));
+    assert!(written.contains(r#"<guid isPermaLink="false">urn:entry:1</guid>"#));
+    assert!(written.contains(r#"<pubDate>Thu, 4 Mar 2021 05:06:07 +0000</pubDate>"#));
+    assert!(written.contains(r#"<author>jane@example.com</author>"#));
+    assert!(written.contains(r#"<enclosure url="https://example.com/audio.mp3" length="1234" type="audio/mpeg"/>"#));
+
+    // The feed identifier is generated by the parser as RSS does not have one
+    let mut actual = parser::parse(written.as_bytes()).unwrap();
+    actual.id = feed.id.clone();
+    assert_eq!(actual, feed);
+}
+
+// Verify only the itunes owner is written as <itunes:owner>, with other contributors kept as Atom contributors
+#[test]
+fn test_write_contributors() {
+    let owner = Person::new("Owner").email("owner@example.com");
+    let feed = Feed::new(FeedType::RSS2)
+        .id("urn:feed:1")
+        .title(Text::new("Podcast".into()))
+        .link(Link::new("https://example.com/", None))
+        .description(Text::new("Episodes".into()))
+        .contributor(Person::new("webMaster").email("web@example.com"))
+        .contributor(Person::new("Jane"))
+        .contributor(Person::new("John").email("john@example.com").uri("https://example.com/john"))
+        .contributor(owner.clone())
+        .itunes(ItunesMeta {
+            owner: Some(owner),
+            ..Default::default()
+        });
+
+    let mut out = Vec::new();
+    rss2::write(&feed, &mut out).unwrap();
+    let written = String::from_utf8(out).unwrap();
+
+    assert!(written.contains(r#"<webMaster>web@example.com</webMaster>"#));
+    assert!(written.contains(r#"<atom:contributor><atom:name>Jane</atom:name></atom:contributor>"#));
+    assert!(written.contains(
+        r#"<atom:contributor><atom:name>John</atom:name><atom:uri>https://example.com/john</atom:uri><atom:email>john@example.com</atom:email></atom:contributor>"#
+    ));
+    assert_eq!(written.matches("<itunes:owner>").count(), 1);
+    assert!(written.contains(r#"<itunes:owner><itunes:name>Owner</itunes:name><itunes:email>owner@example.com</itunes:email></itunes:owner>"#));
+
+    // The owner is written after the other contributors, so is restored last
+    let mut actual = parser::parse(written.as_bytes()).unwrap();
+    actual.id = feed.id.clone();
+    assert_eq!(actual, feed);
+}
+
+// Verify the required channel elements are always written, falling back to the "self" link or identifier and empty text
+#[test]
+fn test_write_required() {
+    let feed = Feed::new(FeedType::Atom)
+        .id("https://example.com/feed")
+        .link(Link::new("https://example.com/feed.xml", None).rel("self"));
+
+    let mut out = Vec::new();
+    rss2::write(&feed, &mut out).unwrap();
+    assert!(validator::validate(out.as_slice()).unwrap().is_empty());
+    let written = String::from_utf8(out).unwrap();
+    assert!(written.contains(
+        r#"<title></title><link>https://example.com/feed.xml</link><atom:link href="https://example.com/feed.xml" rel="self"/><description></description>"#
+    ));
+
+    let feed = Feed::new(FeedType::Atom).id("https://example.com/feed");
+    let mut out = Vec::new();
+    rss2::write(&feed, &mut out).unwrap();
+    let written = String::from_utf8(out).unwrap();
+    assert!(written.contains(r#"<link>https://example.com/feed</link>"#));
+}
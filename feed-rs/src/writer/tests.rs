@@ -0,0 +1,116 @@
+use std::fs;
+
+use crate::model::{Extension, Feed, FeedType};
+use crate::parser;
+use crate::util::test;
+use crate::writer::{self, WriteFeedError, WriteFeedResult};
+
+// Writes the feed in one of the formats
+pub(super) type WriteFn = fn(&Feed, &mut Vec<u8>) -> WriteFeedResult<()>;
+
+// Applies the values the writer falls back to for required elements to the source feed, given the feed parsed from the output
+pub(super) type FallbackFn = fn(&mut Feed, &mut Feed);
+
+// Parses the source, writes it with the writer then parses the output
+// The feed is returned with the fallbacks applied, so it can be compared with the output
+pub(super) fn round_trip(source: &[u8], write: WriteFn, fallbacks: FallbackFn) -> (Feed, Feed, String) {
+    let parser = parser::Builder::new().sanitize_content(false).build();
+    let feed = parser.parse(source).unwrap();
+    round_trip_feed(&parser, feed, write, fallbacks)
+}
+
+// Writes the parsed feed then parses the output with the same parser
+fn round_trip_feed(parser: &parser::Parser, mut feed: Feed, write: WriteFn, fallbacks: FallbackFn) -> (Feed, Feed, String) {
+    let mut out = Vec::new();
+    write(&feed, &mut out).unwrap();
+    let written = String::from_utf8(out).unwrap();
+
+    let mut reparsed = parser.parse(written.as_bytes()).unwrap();
+    fallbacks(&mut feed, &mut reparsed);
+    (feed, reparsed, written)
+}
+
+// Verifies that each of the fixtures in the directory survives a round-trip through the writer
+// Fixtures that are deliberately invalid, or are actually in another format, are skipped
+pub(super) fn test_round_trip_fixtures(dir: &str, feed_type: FeedType, write: WriteFn, fallbacks: FallbackFn) {
+    let parser = parser::Builder::new().sanitize_content(false).build();
+    let mut fixture_dir = test::fixture_dir();
+    fixture_dir.push(dir);
+    for entry in fs::read_dir(fixture_dir).unwrap() {
+        let path = entry.unwrap().path();
+        let filename = path.file_name().unwrap().to_str().unwrap();
+        if filename.ends_with(".serde.json") || !path.extension().is_some_and(|ext| ext == "xml" || ext == "json") {
+            continue;
+        }
+
+        let source = fs::read(&path).unwrap();
+        if let Some(feed) = parser.parse(source.as_slice()).ok().filter(|feed| feed.feed_type == feed_type) {
+            let (expected, actual, written) = round_trip_feed(&parser, feed, write, fallbacks);
+            assert_eq!(expected, actual, "round trip of {:?} failed, wrote:\n{}", path, written);
+        }
+    }
+}
+
+// Verify the indented output of each format is parsed to the same model as the compact output
+#[test]
+fn test_pretty() {
+    let source = test::fixture_as_string("rss2/rss_2.0_example_1.xml");
+    let parser = parser::Builder::new().sanitize_content(false).build();
+    let feed = parser.parse(source.as_bytes()).unwrap();
+
+    let compact = writer::Builder::new().build();
+    let pretty = writer::Builder::new().pretty(true).build();
+    for feed_type in [FeedType::Atom, FeedType::JSON, FeedType::RSS1, FeedType::RSS2] {
+        let mut compact_out = Vec::new();
+        compact.write(&feed, feed_type.clone(), &mut compact_out).unwrap();
+        let mut pretty_out = Vec::new();
+        pretty.write(&feed, feed_type.clone(), &mut pretty_out).unwrap();
+
+        assert!(pretty_out.contains(&b'\n'), "{:?} output was not indented", feed_type);
+        let expected = parser.parse(compact_out.as_slice()).unwrap();
+        let actual = parser.parse(pretty_out.as_slice()).unwrap();
+        assert_eq!(expected, actual, "{:?} output differs when indented", feed_type);
+    }
+}
+
+// RSS 0.x is not written, as RSS 2.0 is backwards compatible
+#[test]
+fn test_unsupported_format() {
+    let feed = Feed::new(FeedType::RSS0);
+    let result = writer::Builder::new().build().write(&feed, FeedType::RSS0, Vec::new());
+    assert!(matches!(result, Err(WriteFeedError::UnsupportedFormat(FeedType::RSS0))));
+}
+
+// Verify extensions are written in their own namespace, declaring those of conventional attribute prefixes and leaving out other prefixed attributes
+#[test]
+fn test_write_extensions() {
+    const YT: &str = "http://www.youtube.com/xml/schemas/2015";
+    let feed = Feed::new(FeedType::Atom).extension(
+        YT,
+        Extension::new("channel")
+            .attr("id", "UC1234")
+            .attr("rdf:resource", "https://example.com/")
+            .attr("xml:lang", "en")
+            .attr("yt:kind", "music")
+            .child(YT, Extension::new("name").value("Music")),
+    );
+
+    for feed_type in [FeedType::Atom, FeedType::RSS1, FeedType::RSS2] {
+        let mut out = Vec::new();
+        writer::Builder::new().build().write(&feed, feed_type.clone(), &mut out).unwrap();
+        let written = String::from_utf8(out).unwrap();
+        assert!(
+            written.contains(
+                r#"<channel xmlns="http://www.youtube.com/xml/schemas/2015" xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#" id="UC1234" rdf:resource="https://example.com/" xml:lang="en"><name>Music</name></channel>"#
+            ),
+            "{:?} extension was not written, wrote:\n{}",
+            feed_type,
+            written
+        );
+
+        let actual = parser::parse(written.as_bytes()).unwrap();
+        let channel = &actual.extensions[YT]["channel"][0];
+        assert_eq!(channel.attrs.len(), 3);
+        assert_eq!(channel.children[YT]["name"][0].value.as_deref(), Some("Music"));
+    }
+}
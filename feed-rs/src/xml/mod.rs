@@ -1,15 +1,27 @@
 use core::fmt;
 use std::cell::RefCell;
+use std::collections::{HashMap, VecDeque};
 use std::error::Error;
 use std::fmt::Debug;
+#[cfg(feature = "async")]
+use std::io;
 use std::io::BufRead;
 use std::mem;
 
+use quick_xml::encoding::Decoder;
 use quick_xml::events::{BytesEnd, BytesStart, Event};
 use quick_xml::name::ResolveResult;
-use quick_xml::{NsReader, Reader};
+use quick_xml::NsReader;
+#[cfg(feature = "async")]
+use tokio::io::{AsyncBufRead, AsyncBufReadExt};
 use url::Url;
 
+use crate::parser::limits::Limits;
+use crate::parser::{Limit, ParseWarning, Position, WarningKind};
+use position::{LineTracker, Location};
+
+mod position;
+
 #[cfg(test)]
 mod tests;
 
@@ -30,15 +42,43 @@ impl<R: BufRead> ElementSource<R> {
     /// * `xml_data` - the data you wish to parse
     /// * `xml_base_uri` - the base URI if known (e.g. Content-Location, feed URI etc)
     pub(crate) fn new(xml_data: R, xml_base_uri: Option<&str>) -> XmlResult<ElementSource<R>> {
+        ElementSource::with_config(xml_data, xml_base_uri, XmlConfig::default())
+    }
+
+    /// Parses the XML stream and emits elements, with options controlling how the content is interpreted
+    ///
+    /// # Arguments
+    ///
+    /// * `xml_data` - the data you wish to parse
+    /// * `xml_base_uri` - the base URI if known (e.g. Content-Location, feed URI etc)
+    /// * `config` - options such as the limits on the content
+    pub(crate) fn with_config(xml_data: R, xml_base_uri: Option<&str>, config: XmlConfig) -> XmlResult<ElementSource<R>> {
+        // Content preceding the document is skipped when recovering, looking for the start of the document across the buffers
+        let mut xml_data = LineTracker::new(xml_data);
+        if config.recover {
+            let mut at_start = true;
+            loop {
+                let buf = xml_data.fill_ahead(ROOT_LOOKAHEAD).map_err(quick_xml::Error::from)?;
+                let (skipped, found) = leading_garbage(buf, at_start);
+                xml_data.consume(skipped);
+                if found {
+                    break;
+                }
+                at_start = false;
+            }
+            xml_data.set_origin();
+        }
+
         // Create the XML parser
-        let mut reader = NsReader::from_reader(xml_data);
-        let config = reader.config_mut();
-        config.expand_empty_elements = true;
-        config.trim_markup_names_in_closing_tags = true;
-        config.trim_text(false);
+        let reader = configure_reader(NsReader::from_reader(xml_data), config.recover);
+        let events = EventSource::Reader {
+            reader,
+            buf_event: Vec::with_capacity(512),
+            tokenizer: Tokenizer::default(),
+        };
 
-        let state = RefCell::new(SourceState::new(reader, xml_base_uri)?);
-        Ok(ElementSource { state })
+        let state = SourceState::new(events, xml_base_uri, config)?;
+        Ok(ElementSource { state: RefCell::new(state) })
     }
 
     /// Set default namespace if not set explicitly by the document.
@@ -49,6 +89,33 @@ impl<R: BufRead> ElementSource<R> {
         }
     }
 
+    /// Returns the location of the most recently consumed content
+    pub(crate) fn position(&self) -> Position {
+        self.state.borrow().position()
+    }
+
+    /// Starts collecting warnings about content that could not be interpreted
+    pub(crate) fn collect_warnings(&self) {
+        self.state.borrow_mut().warnings.get_or_insert_with(Vec::new);
+    }
+
+    /// Returns the warnings collected so far
+    pub(crate) fn take_warnings(&self) -> Vec<ParseWarning> {
+        self.state.borrow_mut().warnings.as_mut().map(mem::take).unwrap_or_default()
+    }
+
+    /// Counts an entry encountered by the parser, returning the number of entries so far
+    pub(crate) fn count_entry(&self) -> usize {
+        let mut state = self.state.borrow_mut();
+        state.entries += 1;
+        state.entries
+    }
+
+    /// Records a warning for the current element (or one of its attributes) if warnings are being collected
+    pub(crate) fn warn(&self, kind: WarningKind, attribute: Option<&str>, value: &str) {
+        self.state.borrow_mut().warn(kind, attribute, value);
+    }
+
     /// Returns the first element in the source
     pub(crate) fn root(&self) -> XmlResult<Option<Element<'_, R>>> {
         self.next_element_at_depth(1)
@@ -106,14 +173,19 @@ impl<R: BufRead> ElementSource<R> {
         Ok(())
     }
 
-    // Returns the next element at the nominated depth
-    fn next_element_at_depth(&self, iter_depth: u32) -> XmlResult<Option<Element<'_, R>>> {
+    /// Returns the next element at the nominated depth (or None if there are no more elements at that depth within the current parent)
+    pub(crate) fn next_element_at_depth(&self, iter_depth: u32) -> XmlResult<Option<Element<'_, R>>> {
         // Read nodes until we arrive at the correct depth
         let mut state = self.state.borrow_mut();
         while let Some(node) = state.next()? {
             match node {
                 // The start of an element may be interesting to the iterator
-                XmlEvent::Start { name, attributes, namespace } => {
+                XmlEvent::Start {
+                    name,
+                    attributes,
+                    namespace,
+                    namespace_uri,
+                } => {
                     // Starting an element increases our depth
                     state.current_depth += 1;
 
@@ -122,8 +194,17 @@ impl<R: BufRead> ElementSource<R> {
 
                     // If we are at the correct depth we found a node of interest
                     if state.current_depth == iter_depth {
+                        // Elements of the supported namespaces are matched regardless of case if requested (e.g. "pubdate" for "pubDate")
+                        let namespace = namespace.unwrap_or(state.default_namespace);
+                        let name = if state.config.normalize_names && namespace != NS::Unknown {
+                            normalize_name(&name)
+                        } else {
+                            name
+                        };
+
                         let element = Element {
-                            namespace: namespace.unwrap_or(state.default_namespace),
+                            namespace,
+                            namespace_uri,
                             name,
                             attributes,
                             xml_base: ElementSource::xml_base_fetch(&state),
@@ -225,21 +306,235 @@ impl<R: BufRead> ElementSource<R> {
     }
 }
 
+#[cfg(feature = "async")]
+impl ElementSource<io::Empty> {
+    /// Reads the XML asynchronously, with elements emitted once the whole document has been read
+    ///
+    /// The document is tokenised as the bytes arrive, so the executor is not blocked waiting on the source.
+    /// The events are retained until the end of the document is reached, at which point the (synchronous) parsing of elements can proceed without further I/O.
+    /// The limits on the content are applied as the events are read, so the events retained are bounded by the limits in the same manner as when reading synchronously.
+    ///
+    /// # Arguments
+    ///
+    /// * `xml_data` - the data you wish to parse
+    /// * `xml_base_uri` - the base URI if known (e.g. Content-Location, feed URI etc)
+    /// * `config` - options such as the limits on the content
+    pub(crate) async fn from_async_reader<A: AsyncBufRead + Unpin>(
+        xml_data: A,
+        xml_base_uri: Option<&str>,
+        config: XmlConfig,
+    ) -> XmlResult<ElementSource<io::Empty>> {
+        // Content preceding the document is skipped when recovering, looking for the start of the document across the buffers
+        let mut xml_data = LineTracker::new(xml_data);
+        if config.recover {
+            let mut at_start = true;
+            loop {
+                let buf = xml_data.fill_ahead_async(ROOT_LOOKAHEAD).await.map_err(quick_xml::Error::from)?;
+                let (skipped, found) = leading_garbage(buf, at_start);
+                xml_data.consume(skipped);
+                if found {
+                    break;
+                }
+                at_start = false;
+            }
+            xml_data.set_origin();
+        }
+
+        let mut reader = configure_reader(NsReader::from_reader(xml_data), config.recover);
+        let mut buf_event = Vec::with_capacity(512);
+
+        // The events are converted (and the limits applied) as they are read, stopping at the first error
+        let mut tokenizer = Tokenizer::default();
+        while !tokenizer.finished() {
+            let decoder = reader.decoder();
+            let offset = reader.buffer_position();
+            let location = reader.get_mut().mark(offset);
+            match reader.read_resolved_event_into_async(&mut buf_event).await {
+                Ok((ns_resolution, event)) => tokenizer.event(location, decoder, ns_resolution, event, &config),
+                Err(e) => tokenizer.error(reader.get_ref().locate(reader.error_position()), e.into(), &config),
+            }
+            buf_event.clear();
+        }
+
+        let state = SourceState::new(EventSource::Buffered { tokenizer }, xml_base_uri, config)?;
+        Ok(ElementSource { state: RefCell::new(state) })
+    }
+}
+
+/// Options controlling how the XML is interpreted
+#[derive(Clone, Default)]
+pub(crate) struct XmlConfig {
+    /// Resolve the named entities defined by HTML (e.g. "&nbsp;"), in addition to those predefined by XML
+    pub html_entities: bool,
+    /// Limits on the content read from the source
+    pub limits: Limits,
+    /// Match the names of elements in the supported namespaces regardless of case
+    pub normalize_names: bool,
+    /// Namespace URIs for prefixes used without being declared, in addition to the well-known prefixes
+    pub prefixes: HashMap<String, String>,
+    /// Repair malformed content (e.g. unclosed tags) rather than failing
+    pub recover: bool,
+}
+
+impl XmlConfig {
+    // Resolves a named entity to its replacement text
+    fn resolve_entity(&self, name: &str) -> Option<&'static str> {
+        if self.html_entities {
+            quick_xml::escape::resolve_html5_entity(name)
+        } else {
+            quick_xml::escape::resolve_xml_entity(name)
+        }
+    }
+
+    // Resolves a prefix used without being declared to a namespace URI, through the registered prefixes or those conventionally used by well-known namespaces
+    fn resolve_prefix(&self, prefix: &str) -> Option<String> {
+        match self.prefixes.get(prefix) {
+            Some(uri) => Some(uri.clone()),
+            None => NS::well_known_uri(prefix).map(str::to_string),
+        }
+    }
+}
+
+// Applies the configuration required by the element source to a new reader
+fn configure_reader<R>(mut reader: NsReader<R>, recover: bool) -> NsReader<R> {
+    let config = reader.config_mut();
+    config.expand_empty_elements = true;
+    config.trim_markup_names_in_closing_tags = true;
+    config.trim_text(false);
+
+    // When recovering, bare ampersands are retained as text and unbalanced end tags are repaired by the element source
+    config.allow_dangling_amp = recover;
+    config.allow_unmatched_ends = recover;
+    config.check_end_names = !recover;
+    reader
+}
+
+// Names of elements in the supported namespaces that are not entirely lowercase (checked against the names matched by the parsers in the tests)
+const MIXED_CASE_NAMES: [&str; 19] = [
+    "alternateEnclosure",
+    "backLink",
+    "backLinks",
+    "episodeType",
+    "lastBuildDate",
+    "managingEditor",
+    "peerLink",
+    "pubDate",
+    "sceneDescription",
+    "sceneEndTime",
+    "sceneStartTime",
+    "sceneTitle",
+    "skipDays",
+    "skipHours",
+    "starRating",
+    "subTitle",
+    "textInput",
+    "valueRecipient",
+    "webMaster",
+];
+
+// Maps an element name to the form expected by the parsers, regardless of its case (e.g. "PubDate" or "Description")
+fn normalize_name(name: &str) -> String {
+    MIXED_CASE_NAMES
+        .iter()
+        .find(|known| known.eq_ignore_ascii_case(name))
+        .map_or_else(|| name.to_ascii_lowercase(), |known| known.to_string())
+}
+
+// Names of the XML declaration and the root elements of the supported formats, which may start a document
+const ROOT_NAMES: [&str; 4] = ["?xml", "rss", "feed", "rdf"];
+
+// Content examined beyond a possible start of the document, allowing for a namespace prefix on the root element
+const ROOT_LOOKAHEAD: usize = 64;
+
+/// Whether the content starts with the XML declaration or the root element of a feed (ignoring any namespace prefix)
+///
+/// Returns None if the content ends before the name is complete, so this cannot be determined.
+pub(crate) fn is_root(content: &[u8]) -> Option<bool> {
+    let Some(rest) = content.strip_prefix(b"<") else {
+        return Some(false);
+    };
+    let length = rest.iter().position(|b| !is_name_byte(*b));
+    let name = &rest[..length.unwrap_or(rest.len())];
+    let local = name.iter().rposition(|b| *b == b':').map_or(name, |colon| &name[colon + 1..]);
+
+    length.map(|_| ROOT_NAMES.iter().any(|root| local.eq_ignore_ascii_case(root.as_bytes())))
+}
+
+// Bytes that may appear in the name of the XML declaration or an element
+fn is_name_byte(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || matches!(b, b'?' | b':' | b'-' | b'_' | b'.')
+}
+
+// Finds the content preceding the document (e.g. warnings printed by the server generating the feed), returning the length to skip and whether the document starts after it
+// The document does not start within the buffer if the buffer is entirely skipped, or it ends with a possible start that needs more content to be determined
+// At the start of the source, a byte order mark and whitespace followed by markup (e.g. a comment or processing instruction) are the start of the document
+fn leading_garbage(buf: &[u8], at_start: bool) -> (usize, bool) {
+    if at_start {
+        let content = buf.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(buf);
+        let content = &content[content.iter().position(|b| !b.is_ascii_whitespace()).unwrap_or(content.len())..];
+        if content.is_empty() || content.starts_with(b"<?") || content.starts_with(b"<!") || is_root(content) != Some(false) {
+            return (0, true);
+        }
+    }
+
+    for start in 0..buf.len() {
+        match is_root(&buf[start..]) {
+            Some(true) => return (start, true),
+            // Where the content has been read ahead of the start, the name is too long to be a root element (or the source has ended)
+            None if start > 0 => return (start, false),
+            _ => {}
+        }
+    }
+    (buf.len(), buf.is_empty())
+}
+
+// The origin of XML events: read on demand, or buffered in advance (e.g. when read asynchronously)
+// There is a single instance per document, so the difference in the size of the variants is of no consequence
+#[allow(clippy::large_enum_variant)]
+enum EventSource<R: BufRead> {
+    Reader {
+        reader: NsReader<LineTracker<R>>,
+        buf_event: Vec<u8>,
+        tokenizer: Tokenizer,
+    },
+    #[cfg_attr(not(feature = "async"), allow(dead_code))]
+    Buffered {
+        // Holds the events of the entire document
+        tokenizer: Tokenizer,
+    },
+}
+
 // Wraps the XML source and current depth of iteration
 struct SourceState<R: BufRead> {
-    reader: NsReader<R>,
-    buf_event: Vec<u8>,
+    events: EventSource<R>,
     next: XmlResult<Option<XmlEvent>>,
-    // An event stashed while coalescing text (e.g. the start tag terminating a run of text and entity references)
-    pending_text: Option<XmlEvent>,
+    // Location of the next event
+    next_location: Location,
+    // Location of the most recently consumed event
+    location: Location,
+    // Elements enclosing the most recently consumed event, starting with the document itself
+    path: Vec<PathSegment>,
+    // Content that could not be interpreted by the parser (if requested)
+    warnings: Option<Vec<ParseWarning>>,
+    // Number of entries encountered by the parser, checked against the limit
+    entries: usize,
     current_depth: u32,
     base_uris: Vec<(u32, Url)>,
     default_namespace: NS,
+    config: XmlConfig,
+}
+
+impl<R: BufRead> EventSource<R> {
+    fn tokenizer(&mut self) -> &mut Tokenizer {
+        match self {
+            EventSource::Reader { tokenizer, .. } | EventSource::Buffered { tokenizer } => tokenizer,
+        }
+    }
 }
 
 impl<R: BufRead> SourceState<R> {
     // Wrap the reader in additional state (buffers, tree depth etc)
-    fn new(reader: NsReader<R>, xml_base_uri: Option<&str>) -> XmlResult<SourceState<R>> {
+    fn new(events: EventSource<R>, xml_base_uri: Option<&str>, config: XmlConfig) -> XmlResult<SourceState<R>> {
         // If we have a base URI, parse it and init at the root
         let mut base_uris = Vec::new();
         if let Some(xml_base_uri) = xml_base_uri {
@@ -247,121 +542,117 @@ impl<R: BufRead> SourceState<R> {
             base_uris.push((0, uri));
         }
 
-        let buf_event = Vec::with_capacity(512);
         let mut state = SourceState {
-            reader,
-            buf_event,
+            events,
             next: Ok(None),
-            pending_text: None,
+            next_location: Location::default(),
+            location: Location::default(),
+            path: vec![PathSegment::default()],
+            warnings: None,
+            entries: 0,
             current_depth: 0,
             base_uris,
             default_namespace: NS::Unknown,
+            config,
         };
-        state.next = state.fetch_next();
+        (state.next_location, state.next) = state.fetch_next();
         Ok(state)
     }
 
-    // Returns the next event
-    fn fetch_next(&mut self) -> XmlResult<Option<XmlEvent>> {
-        // Emit an event stashed while coalescing text
-        if let Some(event) = self.pending_text.take() {
-            return Ok(Some(event));
-        }
-
-        let decoder = self.reader.decoder();
-        let reader = &mut self.reader;
+    // Returns the next event, with the location at which it starts
+    fn fetch_next(&mut self) -> (Location, XmlResult<Option<XmlEvent>>) {
+        match &mut self.events {
+            EventSource::Reader { reader, buf_event, tokenizer } => loop {
+                if let Some(next) = tokenizer.pop() {
+                    return next;
+                }
 
-        // Text, CData and entity references are coalesced into a single text event
-        let mut text: Option<String> = None;
+                let decoder = reader.decoder();
+                let offset = reader.buffer_position();
+                let location = reader.get_mut().mark(offset);
+                match reader.read_resolved_event_into(buf_event) {
+                    Ok((ns_resolution, event)) => tokenizer.event(location, decoder, ns_resolution, event, &self.config),
+                    Err(e) => tokenizer.error(reader.get_ref().locate(reader.error_position()), e.into(), &self.config),
+                }
+                buf_event.clear();
+            },
 
-        loop {
-            let (ns_resolution, event) = reader.read_resolved_event_into(&mut self.buf_event)?;
-
-            match event {
-                // Start of an element
-                Event::Start(ref e) => {
-                    // Parse the namespace
-                    // The default namespace is applied when the event is consumed, since it may not be known yet
-                    // (e.g. the root element is examined to determine the feed type, which in turn sets the default namespace)
-                    let namespace = match ns_resolution {
-                        ResolveResult::Bound(ns) => decoder.decode(ns.as_ref()).ok().map(|decoded| NS::parse(decoded.as_ref())),
-                        ResolveResult::Unknown(_) => None,
-                        ResolveResult::Unbound => None,
-                    };
+            EventSource::Buffered { tokenizer, .. } => tokenizer.pop().expect("buffered events are read to the end of the content"),
+        }
+    }
 
-                    let start = XmlEvent::start(namespace, e, reader);
-                    return match text.take() {
-                        Some(text) => {
-                            self.pending_text = Some(start);
-                            Ok(Some(XmlEvent::Text(text)))
-                        }
-                        None => Ok(Some(start)),
-                    };
-                }
+    // Returns the next interesting event or None if no more events are found
+    fn next(&mut self) -> XmlResult<Option<XmlEvent>> {
+        let (location, fetched) = self.fetch_next();
+        let next = mem::replace(&mut self.next, fetched);
+        self.location = mem::replace(&mut self.next_location, location);
+
+        // The elements closed by truncating the content are reported within the innermost element
+        if let Ok(Some(XmlEvent::End { .. })) = &next {
+            if let Some((_, cause)) = self.events.tokenizer().truncated.take_if(|(at, _)| *at == self.location) {
+                self.warn(WarningKind::TruncatedContent, None, &cause);
+            }
+        }
 
-                // End of an element
-                Event::End(ref e) => {
-                    let end = XmlEvent::end(e, reader);
-                    return match text.take() {
-                        Some(text) => {
-                            self.pending_text = Some(end);
-                            Ok(Some(XmlEvent::Text(text)))
-                        }
-                        None => Ok(Some(end)),
-                    };
-                }
+        // Keep track of where we are in the document
+        match &next {
+            Ok(Some(XmlEvent::Start { name, .. })) => {
+                let parent = self.path.last_mut().expect("path always contains the document");
+                let index = parent.children.entry(name.clone()).or_default();
+                *index += 1;
+                let segment = PathSegment {
+                    name: name.clone(),
+                    index: *index,
+                    children: HashMap::new(),
+                };
+                self.path.push(segment);
+            }
+            Ok(Some(XmlEvent::End { .. })) if self.path.len() > 1 => {
+                self.path.pop();
+            }
+            _ => {}
+        }
 
-                // Text
-                Event::Text(ref t) => {
-                    if !t.is_empty() {
-                        let decoded = decoder.decode(t)?;
-                        text.get_or_insert_with(String::new).push_str(&decoded);
-                    }
-                }
+        next
+    }
 
-                // CData is converted to text
-                Event::CData(ref t) => {
-                    if !t.is_empty() {
-                        let decoded = decoder.decode(t)?;
-                        text.get_or_insert_with(String::new).push_str(&decoded);
-                    }
-                }
+    // The location of the most recently consumed event
+    // Records a warning for the current element (or one of its attributes) if warnings are being collected
+    fn warn(&mut self, kind: WarningKind, attribute: Option<&str>, value: &str) {
+        if self.warnings.is_none() {
+            return;
+        }
 
-                // Character and entity references (e.g. "&#38;" or "&amp;") are resolved into text
-                Event::GeneralRef(ref r) => {
-                    let buffer = text.get_or_insert_with(String::new);
-                    if let Some(ch) = r.resolve_char_ref()? {
-                        buffer.push(ch);
-                    } else {
-                        let name = decoder.decode(r)?;
-                        match quick_xml::escape::resolve_predefined_entity(&name) {
-                            Some(resolved) => buffer.push_str(resolved),
-                            // Unknown entities cannot be resolved, so retain them in their escaped form
-                            None => {
-                                buffer.push('&');
-                                buffer.push_str(&name);
-                                buffer.push(';');
-                            }
-                        }
-                    }
-                }
+        let mut path = self.position().path;
+        if let Some(attribute) = attribute {
+            path.push_str("/@");
+            path.push_str(attribute);
+        }
 
-                // The end of the document
-                Event::Eof => {
-                    return Ok(text.take().map(XmlEvent::Text));
-                }
+        let warning = ParseWarning {
+            kind,
+            path,
+            value: value.to_string(),
+        };
+        self.warnings.get_or_insert_with(Vec::new).push(warning);
+    }
 
-                // Ignore everything else
-                _ => {}
+    fn position(&self) -> Position {
+        // Elements are identified by name, with the position amongst siblings of the same name when repeated
+        let mut path = String::new();
+        for segment in &self.path[1..] {
+            path.push('/');
+            path.push_str(&segment.name);
+            if segment.index > 1 {
+                path.push_str(&format!("[{}]", segment.index));
             }
         }
-    }
+        if path.is_empty() {
+            path.push('/');
+        }
 
-    // Returns the next interesting event or None if no more events are found
-    fn next(&mut self) -> XmlResult<Option<XmlEvent>> {
-        let next = mem::replace(&mut self.next, Ok(None));
-        self.next = self.fetch_next();
-        next
+        let Location { offset, line, column } = self.location;
+        Position { offset, line, column, path }
     }
 
     // Peeks the next event (does not advance)
@@ -371,6 +662,16 @@ impl<R: BufRead> SourceState<R> {
     }
 }
 
+// An element enclosing the current position in the document
+#[derive(Default)]
+struct PathSegment {
+    name: String,
+    // Position of the element amongst siblings with the same name (starting at 1)
+    index: usize,
+    // Number of child elements encountered so far, by name
+    children: HashMap<String, usize>,
+}
+
 /// An element (specifically, XML element start tag)
 pub(crate) struct Element<'a, R: BufRead> {
     /// Qualified name of the element.
@@ -379,6 +680,9 @@ pub(crate) struct Element<'a, R: BufRead> {
     /// The namespace mapping at this point of the document.
     pub namespace: NS,
 
+    /// URI of the namespace, if declared (e.g. to identify namespaces that are not recognised).
+    pub namespace_uri: Option<String>,
+
     /// A list of attributes associated with the element.
     pub attributes: Vec<NameValue>,
 
@@ -424,6 +728,21 @@ impl<'a, R: BufRead> Element<'a, R> {
         Ok(Some(buffer))
     }
 
+    /// Records a warning for the text content of this element
+    pub(crate) fn warn(&self, kind: WarningKind, value: &str) {
+        self.source.warn(kind, None, value);
+    }
+
+    /// Records a warning for an attribute of this element
+    pub(crate) fn warn_attr(&self, kind: WarningKind, attribute: &str, value: &str) {
+        self.source.warn(kind, Some(attribute), value);
+    }
+
+    /// Returns the source of this element (e.g. to determine the position once the element has been consumed)
+    pub(crate) fn source(&self) -> &'a ElementSource<R> {
+        self.source
+    }
+
     /// Returns the namespace + tag name for this element
     pub(crate) fn ns_and_tag(&self) -> (NS, &str) {
         (self.namespace, &self.name)
@@ -466,6 +785,7 @@ pub(crate) enum NS {
     DublinCore,
     MediaRSS,
     Itunes,
+    Podcast,
 }
 
 impl NS {
@@ -479,11 +799,25 @@ impl NS {
             "http://purl.org/dc/elements/1.1/" => NS::DublinCore,
             "http://search.yahoo.com/mrss/" => NS::MediaRSS,
             "http://www.itunes.com/dtds/podcast-1.0.dtd" => NS::Itunes,
+            "https://podcastindex.org/namespace/1.0" => NS::Podcast,
 
             // Everything else is ignored
             _ => NS::Unknown,
         }
     }
+
+    // Namespaces with a prefix that is conventionally used, and so recognised even when the feed does not declare it
+    pub(crate) fn well_known_uri(prefix: &str) -> Option<&'static str> {
+        match prefix {
+            "atom" => Some("http://www.w3.org/2005/Atom"),
+            "content" => Some("http://purl.org/rss/1.0/modules/content/"),
+            "dc" => Some("http://purl.org/dc/elements/1.1/"),
+            "media" => Some("http://search.yahoo.com/mrss/"),
+            "itunes" => Some("http://www.itunes.com/dtds/podcast-1.0.dtd"),
+            "podcast" => Some("https://podcastindex.org/namespace/1.0"),
+            _ => None,
+        }
+    }
 }
 
 /// Combination of a name and value (e.g. attribute name + value)
@@ -499,6 +833,7 @@ pub enum XmlError {
     Url { e: url::ParseError },
     Encoding { e: quick_xml::encoding::EncodingError },
     Escape { e: quick_xml::escape::EscapeError },
+    LimitExceeded { limit: Limit },
 }
 
 impl fmt::Display for XmlError {
@@ -508,12 +843,26 @@ impl fmt::Display for XmlError {
             XmlError::Url { e } => write!(f, "Url error: {}", e),
             XmlError::Encoding { e } => write!(f, "Encoding error: {}", e),
             XmlError::Escape { e } => write!(f, "Escape error: {}", e),
+            XmlError::LimitExceeded { limit } => write!(f, "{} exceeded", limit),
         }
     }
 }
 
 impl Error for XmlError {}
 
+impl XmlError {
+    // Whether the reader can continue past the error, skipping the ill-formed content
+    fn is_recoverable(&self) -> bool {
+        matches!(
+            self,
+            XmlError::Parser {
+                e: quick_xml::Error::IllFormed(_) | quick_xml::Error::Encoding(_) | quick_xml::Error::Escape(_) | quick_xml::Error::Namespace(_)
+            } | XmlError::Encoding { .. }
+                | XmlError::Escape { .. }
+        )
+    }
+}
+
 impl From<quick_xml::Error> for XmlError {
     fn from(e: quick_xml::Error) -> Self {
         XmlError::Parser { e }
@@ -532,6 +881,12 @@ impl From<quick_xml::encoding::EncodingError> for XmlError {
     }
 }
 
+impl From<Limit> for XmlError {
+    fn from(limit: Limit) -> Self {
+        XmlError::LimitExceeded { limit }
+    }
+}
+
 impl From<quick_xml::escape::EscapeError> for XmlError {
     fn from(e: quick_xml::escape::EscapeError) -> Self {
         XmlError::Escape { e }
@@ -544,6 +899,8 @@ enum XmlEvent {
     // The namespace is `None` when the document does not declare one; the source's default namespace is applied on consumption
     Start {
         namespace: Option<NS>,
+        // URI of the namespace, if declared
+        namespace_uri: Option<String>,
         name: String,
         attributes: Vec<NameValue>,
     },
@@ -555,19 +912,222 @@ enum XmlEvent {
     Text(String),
 }
 
+// Outcome of converting an event from the underlying reader
+enum ReaderStep {
+    // An event to emit
+    Event(XmlEvent),
+    // The end of the document
+    Eof,
+    // Nothing to emit yet (e.g. the event was text, which is accumulated until the next element boundary)
+    Continue,
+}
+
+// Converts the events of the underlying reader into those emitted by the element source, whether reading synchronously or asynchronously
+// * text, CData and entity references are coalesced into a single text event
+// * when recovering, unbalanced tags are repaired so the elements are always closed
+// * the limits on the content are applied as each event is read, so content beyond a limit is never accumulated
+#[derive(Default)]
+struct Tokenizer {
+    // Events ready to be emitted, with the location at which they start
+    ready: VecDeque<(Location, XmlEvent)>,
+    // Text accumulated up to the next element boundary, and the location at which it starts
+    text: Option<String>,
+    text_location: Location,
+    // Elements opened but not yet closed
+    open: Vec<String>,
+    // Set once the end of the content has been reached, with an error to report once the preceding events have been emitted
+    end: Option<(Location, Option<XmlError>)>,
+    // When recovering, the location at which the content ended prematurely (closing the open elements) and the cause
+    truncated: Option<(Location, String)>,
+}
+
+impl Tokenizer {
+    // Whether the end of the content has been reached (no further events should be read)
+    #[cfg(feature = "async")]
+    fn finished(&self) -> bool {
+        self.end.is_some()
+    }
+
+    // Returns the next event, or the end of the content once all events have been emitted (None if more events need to be read)
+    fn pop(&mut self) -> Option<(Location, XmlResult<Option<XmlEvent>>)> {
+        if let Some((location, event)) = self.ready.pop_front() {
+            return Some((location, Ok(Some(event))));
+        }
+
+        // Exceeding a limit is reported on every subsequent read, so the error is not lost if a caller discards it (e.g. when reading optional text)
+        let (location, error) = self.end.as_mut()?;
+        let result = match error {
+            Some(XmlError::LimitExceeded { limit }) => Err(XmlError::LimitExceeded { limit: *limit }),
+            _ => error.take().map_or(Ok(None), Err),
+        };
+        Some((*location, result))
+    }
+
+    // Processes an event read from the underlying reader, starting at the supplied location
+    fn event(&mut self, location: Location, decoder: Decoder, ns_resolution: ResolveResult, event: Event, config: &XmlConfig) {
+        if self.text.is_none() {
+            self.text_location = location;
+        }
+
+        match XmlEvent::from_reader_event(decoder, ns_resolution, event, &mut self.text, config) {
+            Ok(ReaderStep::Event(event)) => self.element(location, event, config),
+            Ok(ReaderStep::Eof) => self.finish(location, None, config),
+            Ok(ReaderStep::Continue) => {
+                // Text is checked as it is accumulated, failing as soon as it is too long
+                let length = self.text.as_ref().map_or(0, String::len);
+                if let Err(limit) = Limits::check(config.limits.max_text_length, length, Limit::TextLength) {
+                    self.text = None;
+                    self.end = Some((self.text_location, Some(limit.into())));
+                }
+            }
+            Err(e) => self.error(location, e, config),
+        }
+    }
+
+    // Processes an error encountered by the underlying reader at the supplied location
+    fn error(&mut self, location: Location, e: XmlError, config: &XmlConfig) {
+        // Ill-formed content is skipped when recovering
+        if !(config.recover && e.is_recoverable()) {
+            self.finish(location, Some(e), config);
+        }
+    }
+
+    // Emits the start or end of an element, preceded by any text
+    fn element(&mut self, location: Location, event: XmlEvent, config: &XmlConfig) {
+        self.flush_text();
+
+        match event {
+            XmlEvent::Start { ref name, ref attributes, .. } => {
+                self.open.push(name.clone());
+                let checked = Limits::check(config.limits.max_depth, self.open.len(), Limit::Depth)
+                    .and_then(|_| Limits::check(config.limits.max_attributes, attributes.len(), Limit::Attributes));
+
+                // The element exceeding a limit is emitted ahead of the error, so the error is reported within the element
+                self.ready.push_back((location, event));
+                if let Err(limit) = checked {
+                    self.end = Some((location, Some(limit.into())));
+                }
+            }
+
+            // When recovering, an end tag also closes any elements left open within its element (e.g. "<br>"), and is dropped if there is no such element
+            XmlEvent::End { ref name } if config.recover => {
+                if let Some(index) = self.open.iter().rposition(|open| open == name) {
+                    let closing = self.open.split_off(index);
+                    self.ready.extend(closing.into_iter().rev().map(|name| (location, XmlEvent::End { name })));
+                }
+            }
+
+            _ => {
+                if let XmlEvent::End { .. } = event {
+                    self.open.pop();
+                }
+                self.ready.push_back((location, event));
+            }
+        }
+    }
+
+    // Ends the content at the supplied location, preceded by any text
+    fn finish(&mut self, location: Location, error: Option<XmlError>, config: &XmlConfig) {
+        self.flush_text();
+
+        // When recovering, the content ends at an error that cannot be skipped (e.g. a truncated download), closing any open elements
+        // Exceeding a limit still fails the content
+        let (error, cause) = match error {
+            Some(XmlError::LimitExceeded { .. }) => (error, None),
+            Some(e) if config.recover => (None, Some(e.to_string())),
+            Some(e) => (Some(e), None),
+            None => (None, Some("unexpected end of the content".to_string())),
+        };
+        if config.recover && !self.open.is_empty() {
+            let closing = mem::take(&mut self.open);
+            self.ready.extend(closing.into_iter().rev().map(|name| (location, XmlEvent::End { name })));
+            self.truncated = cause.map(|cause| (location, cause));
+        }
+        self.end = Some((location, error));
+    }
+
+    // Emits the text accumulated so far
+    fn flush_text(&mut self) {
+        if let Some(text) = self.text.take() {
+            self.ready.push_back((self.text_location, XmlEvent::Text(text)));
+        }
+    }
+}
+
 impl XmlEvent {
     // Creates a new event corresponding to an XML end-tag
-    fn end<R: BufRead>(event: &BytesEnd, reader: &Reader<R>) -> XmlEvent {
+    fn end(event: &BytesEnd, decoder: Decoder) -> XmlEvent {
         // Parse the name
-        let name = XmlEvent::parse_name(event.name().as_ref(), reader);
+        let name = XmlEvent::parse_name(event.name().as_ref(), decoder);
 
         XmlEvent::End { name }
     }
 
+    // Converts an event from the reader, accumulating text, CData and entity references into the supplied buffer
+    fn from_reader_event(decoder: Decoder, ns_resolution: ResolveResult, event: Event, text: &mut Option<String>, config: &XmlConfig) -> XmlResult<ReaderStep> {
+        match event {
+            // Start of an element
+            Event::Start(ref e) => {
+                // Parse the namespace
+                // The default namespace is applied when the event is consumed, since it may not be known yet
+                // (e.g. the root element is examined to determine the feed type, which in turn sets the default namespace)
+                let namespace_uri = match ns_resolution {
+                    ResolveResult::Bound(ns) => decoder.decode(ns.as_ref()).ok().map(|decoded| decoded.into_owned()),
+                    ResolveResult::Unknown(prefix) => decoder.decode(&prefix).ok().and_then(|decoded| config.resolve_prefix(&decoded)),
+                    ResolveResult::Unbound => None,
+                };
+
+                Ok(ReaderStep::Event(XmlEvent::start(namespace_uri, e, decoder, config)))
+            }
+
+            // End of an element
+            Event::End(ref e) => Ok(ReaderStep::Event(XmlEvent::end(e, decoder))),
+
+            // Text
+            Event::Text(ref t) if !t.is_empty() => {
+                let decoded = decoder.decode(t)?;
+                text.get_or_insert_with(String::new).push_str(&decoded);
+                Ok(ReaderStep::Continue)
+            }
+
+            // CData is converted to text
+            Event::CData(ref t) if !t.is_empty() => {
+                let decoded = decoder.decode(t)?;
+                text.get_or_insert_with(String::new).push_str(&decoded);
+                Ok(ReaderStep::Continue)
+            }
+
+            // Character and entity references (e.g. "&#38;" or "&amp;") are resolved into text
+            Event::GeneralRef(ref r) => {
+                let buffer = text.get_or_insert_with(String::new);
+                if let Some(ch) = r.resolve_char_ref()? {
+                    buffer.push(ch);
+                } else {
+                    let name = decoder.decode(r)?;
+                    match config.resolve_entity(&name) {
+                        Some(resolved) => buffer.push_str(resolved),
+                        // Unknown entities cannot be resolved, so retain them in their escaped form
+                        None => {
+                            buffer.push('&');
+                            buffer.push_str(&name);
+                            buffer.push(';');
+                        }
+                    }
+                }
+                Ok(ReaderStep::Continue)
+            }
+
+            // The end of the document
+            Event::Eof => Ok(ReaderStep::Eof),
+
+            // Ignore everything else
+            _ => Ok(ReaderStep::Continue),
+        }
+    }
+
     // Extracts the element name, dropping the namespace prefix if present
-    fn parse_name<R: BufRead>(bytes: &[u8], reader: &Reader<R>) -> String {
-        reader
-            .decoder()
+    fn parse_name(bytes: &[u8], decoder: Decoder) -> String {
+        decoder
             .decode(bytes)
             .ok()
             .and_then(|name| name.split(':').next_back().map(str::to_string))
@@ -575,26 +1135,26 @@ impl XmlEvent {
     }
 
     // Creates a new event corresponding to an XML start-tag
-    fn start<R: BufRead>(namespace: Option<NS>, event: &BytesStart, reader: &Reader<R>) -> XmlEvent {
+    fn start(namespace_uri: Option<String>, event: &BytesStart, decoder: Decoder, config: &XmlConfig) -> XmlEvent {
         // Parse the name
-        let name = XmlEvent::parse_name(event.name().as_ref(), reader);
+        let name = XmlEvent::parse_name(event.name().as_ref(), decoder);
 
         // Parse the attributes
         let attributes = event
             .attributes()
             .filter_map(|a| {
                 if let Ok(a) = a {
-                    let name = match reader.decoder().decode(a.key.as_ref()) {
+                    let name = match decoder.decode(a.key.as_ref()) {
                         Ok(decoded) => decoded,
                         Err(_) => return None,
                     };
 
                     // Unescape the XML attribute, or use the original value if this fails (broken escape sequence etc)
-                    let decoded_value = match reader.decoder().decode(&a.value) {
+                    let decoded_value = match decoder.decode(&a.value) {
                         Ok(decoded) => decoded,
                         Err(_) => return None,
                     };
-                    let value = quick_xml::escape::unescape(&decoded_value)
+                    let value = quick_xml::escape::unescape_with(&decoded_value, |entity| config.resolve_entity(entity))
                         .unwrap_or_else(|_| decoded_value.clone())
                         .to_string();
 
@@ -605,7 +1165,12 @@ impl XmlEvent {
             })
             .collect::<Vec<NameValue>>();
 
-        XmlEvent::Start { namespace, name, attributes }
+        XmlEvent::Start {
+            namespace: namespace_uri.as_deref().map(NS::parse),
+            namespace_uri,
+            name,
+            attributes,
+        }
     }
 }
 
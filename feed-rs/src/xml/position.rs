@@ -0,0 +1,222 @@
+use std::collections::VecDeque;
+use std::io::{self, BufRead, Read};
+#[cfg(feature = "async")]
+use std::pin::Pin;
+#[cfg(feature = "async")]
+use std::task::{Context, Poll};
+
+#[cfg(feature = "async")]
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncRead, ReadBuf};
+
+/// Wraps a source, tracking the line being read so byte offsets can be reported as a line and column
+///
+/// Only the line boundaries following the event being read are retained, so the memory used does not grow with the content.
+pub(crate) struct LineTracker<R> {
+    inner: R,
+    // Number of bytes consumed from the source
+    consumed: u64,
+    // Number of bytes skipped ahead of the content (e.g. when recovering), added to the offsets reported by the reader
+    origin: u64,
+    // Content read from the source ahead of the consumer by fill_ahead(), which is returned before any further content
+    ahead: Vec<u8>,
+    lines: LineIndex,
+}
+
+impl<R> LineTracker<R> {
+    pub(crate) fn new(inner: R) -> LineTracker<R> {
+        LineTracker {
+            inner,
+            consumed: 0,
+            origin: 0,
+            ahead: Vec::new(),
+            lines: LineIndex::default(),
+        }
+    }
+
+    /// Treats the content consumed so far as preceding the document, so it is included in the locations reported for the reader's offsets
+    pub(crate) fn set_origin(&mut self) {
+        self.origin = self.consumed;
+    }
+
+    /// Locates the start of the event about to be read, discarding the boundaries of the lines before it
+    pub(crate) fn mark(&mut self, offset: u64) -> Location {
+        self.lines.mark(self.origin + offset);
+        self.locate(offset)
+    }
+
+    /// Locates an offset reported by the reader, which must not precede the most recent mark
+    pub(crate) fn locate(&self, offset: u64) -> Location {
+        let offset = self.origin + offset;
+        let (line, column) = self.lines.locate(offset);
+        Location { offset, line, column }
+    }
+}
+
+impl<R: BufRead> LineTracker<R> {
+    /// Reads ahead until at least the given number of bytes are available to the consumer, unless the source ends first
+    pub(crate) fn fill_ahead(&mut self, length: usize) -> io::Result<&[u8]> {
+        if self.ahead.is_empty() && self.inner.fill_buf()?.len() >= length {
+            return self.fill_buf();
+        }
+
+        while self.ahead.len() < length {
+            let buf = self.inner.fill_buf()?;
+            if buf.is_empty() {
+                break;
+            }
+            let count = buf.len();
+            self.ahead.extend_from_slice(buf);
+            self.inner.consume(count);
+        }
+        self.fill_buf()
+    }
+}
+
+#[cfg(feature = "async")]
+impl<R: AsyncBufRead + Unpin> LineTracker<R> {
+    /// Reads ahead until at least the given number of bytes are available to the consumer, unless the source ends first
+    pub(crate) async fn fill_ahead_async(&mut self, length: usize) -> io::Result<&[u8]> {
+        if self.ahead.is_empty() && self.inner.fill_buf().await?.len() >= length {
+            return self.fill_buf().await;
+        }
+
+        while self.ahead.len() < length {
+            let buf = self.inner.fill_buf().await?;
+            if buf.is_empty() {
+                break;
+            }
+            let count = buf.len();
+            self.ahead.extend_from_slice(buf);
+            AsyncBufReadExt::consume(&mut self.inner, count);
+        }
+        self.fill_buf().await
+    }
+}
+
+impl<R: BufRead> Read for LineTracker<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let count = {
+            let available = self.fill_buf()?;
+            let count = available.len().min(buf.len());
+            buf[..count].copy_from_slice(&available[..count]);
+            count
+        };
+        self.consume(count);
+        Ok(count)
+    }
+}
+
+impl<R: BufRead> BufRead for LineTracker<R> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        let buf = if self.ahead.is_empty() { self.inner.fill_buf()? } else { &self.ahead };
+        self.lines.scan(self.consumed, buf);
+        Ok(buf)
+    }
+
+    fn consume(&mut self, amt: usize) {
+        if self.ahead.is_empty() {
+            self.inner.consume(amt);
+        } else {
+            self.ahead.drain(..amt);
+        }
+        self.consumed += amt as u64;
+    }
+}
+
+#[cfg(feature = "async")]
+impl<R: AsyncBufRead + Unpin> AsyncRead for LineTracker<R> {
+    fn poll_read(mut self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &mut ReadBuf<'_>) -> Poll<io::Result<()>> {
+        let count = match self.as_mut().poll_fill_buf(cx) {
+            Poll::Ready(Ok(available)) => {
+                let count = available.len().min(buf.remaining());
+                buf.put_slice(&available[..count]);
+                count
+            }
+            Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+            Poll::Pending => return Poll::Pending,
+        };
+        self.consume(count);
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[cfg(feature = "async")]
+impl<R: AsyncBufRead + Unpin> AsyncBufRead for LineTracker<R> {
+    fn poll_fill_buf(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<&[u8]>> {
+        let this = self.get_mut();
+        if !this.ahead.is_empty() {
+            this.lines.scan(this.consumed, &this.ahead);
+            return Poll::Ready(Ok(&this.ahead));
+        }
+        match Pin::new(&mut this.inner).poll_fill_buf(cx) {
+            Poll::Ready(Ok(buf)) => {
+                this.lines.scan(this.consumed, buf);
+                Poll::Ready(Ok(buf))
+            }
+            other => other,
+        }
+    }
+
+    fn consume(self: Pin<&mut Self>, amt: usize) {
+        let this = self.get_mut();
+        if this.ahead.is_empty() {
+            Pin::new(&mut this.inner).consume(amt);
+        } else {
+            this.ahead.drain(..amt);
+        }
+        this.consumed += amt as u64;
+    }
+}
+
+/// A location within the source
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(crate) struct Location {
+    /// Offset from the start of the content, in bytes
+    pub offset: u64,
+    /// Line number, starting at 1
+    pub line: u64,
+    /// Column within the line, in bytes and starting at 1
+    pub column: u64,
+}
+
+// The line containing the marked offset, and the offsets at which the lines following it begin
+#[derive(Default)]
+struct LineIndex {
+    // Number of lines preceding the one containing the marked offset, and the offset at which that line starts
+    line: u64,
+    line_start: u64,
+    // Offsets at which the following lines begin, up to the end of the content examined so far
+    starts: VecDeque<u64>,
+    // Offset up to which the content has been examined
+    scanned: u64,
+}
+
+impl LineIndex {
+    // Records the line boundaries within the buffer (which starts at the supplied offset), skipping any content seen previously
+    fn scan(&mut self, offset: u64, buf: &[u8]) {
+        let skip = self.scanned.saturating_sub(offset) as usize;
+        if skip >= buf.len() {
+            return;
+        }
+
+        let newlines = buf[skip..].iter().enumerate().filter(|(_, b)| **b == b'\n');
+        self.starts.extend(newlines.map(|(i, _)| offset + (skip + i) as u64 + 1));
+        self.scanned = offset + buf.len() as u64;
+    }
+
+    // Moves to the line containing the offset, discarding the boundaries before it
+    fn mark(&mut self, offset: u64) {
+        while let Some(start) = self.starts.front().copied().filter(|start| *start <= offset) {
+            self.line += 1;
+            self.line_start = start;
+            self.starts.pop_front();
+        }
+    }
+
+    // Converts the byte offset to a line and column (both starting at 1, with the column counted in bytes)
+    fn locate(&self, offset: u64) -> (u64, u64) {
+        let following = self.starts.partition_point(|start| *start <= offset);
+        let line_start = if following == 0 { self.line_start } else { self.starts[following - 1] };
+        (self.line + following as u64 + 1, offset.saturating_sub(line_start) + 1)
+    }
+}
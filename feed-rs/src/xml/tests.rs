@@ -1,5 +1,5 @@
 use std::error::Error;
-use std::io::BufRead;
+use std::io::{BufRead, BufReader};
 
 use crate::util::test;
 
@@ -257,3 +257,144 @@ fn test_iso8859_decode() -> TestResult {
 
     Ok(())
 }
+
+// Verifies the position (offset, line, column and element path) is tracked as elements are consumed
+#[test]
+fn test_position() -> TestResult {
+    let xml = "<feed>\n  <entry/>\n  <entry>\n    <title>second</title>\n  </entry>\n</feed>";
+    let source = ElementSource::new(xml.as_bytes(), None)?;
+    let root = source.root()?.unwrap();
+    let mut entries = root.children();
+
+    let _first = entries.next().unwrap()?;
+    let position = source.position();
+    assert_eq!((position.offset, position.line, position.column), (9, 2, 3));
+    assert_eq!(position.path, "/feed/entry");
+
+    let second = entries.next().unwrap()?;
+    let _title = second.children().next().unwrap()?;
+    let position = source.position();
+    assert_eq!((position.offset, position.line, position.column), (32, 4, 5));
+    assert_eq!(position.path, "/feed/entry[2]/title");
+
+    // Lines are tracked as the content is read, so positions are the same however the source is buffered
+    let source = ElementSource::new(BufReader::with_capacity(3, xml.as_bytes()), None)?;
+    let root = source.root()?.unwrap();
+    let mut entries = root.children();
+    let _first = entries.next().unwrap()?;
+    let second = entries.next().unwrap()?;
+    let _title = second.children().next().unwrap()?;
+    let position = source.position();
+    assert_eq!((position.offset, position.line, position.column), (32, 4, 5));
+
+    Ok(())
+}
+
+#[test]
+fn test_recover() -> TestResult {
+    let config = XmlConfig {
+        recover: true,
+        ..XmlConfig::default()
+    };
+
+    // Unclosed elements are closed by their parent, and unmatched end tags dropped
+    let xml = "<feed><entry><content>a<br>b</p></content></entry><entry><title>x &amp; y & z</title></entry>";
+    let source = ElementSource::with_config(xml.as_bytes(), None, config.clone())?;
+    let root = source.root()?.unwrap();
+    let mut entries = root.children();
+    let first = entries.next().unwrap()?;
+    let content = first.children().next().unwrap()?;
+    assert_eq!(content.children_as_string()?.unwrap(), "a<br>b</br>");
+
+    // Bare ampersands are retained, and the truncated content closes the open elements
+    let second = entries.next().unwrap()?;
+    let title = second.children().next().unwrap()?;
+    assert_eq!(title.child_as_text().unwrap(), "x & y & z");
+    assert!(entries.next().is_none());
+
+    // Content preceding the XML declaration is skipped, with positions relative to the whole source
+    let xml = "Warning: <b>deprecated</b>\n<?xml version=\"1.0\"?>\n<feed>\n  <entry/>\n</feed>";
+    let source = ElementSource::with_config(xml.as_bytes(), None, config.clone())?;
+    let root = source.root()?.unwrap();
+    assert_eq!(root.name, "feed");
+    let _entry = root.children().next().unwrap()?;
+    let position = source.position();
+    assert_eq!((position.offset, position.line, position.column), (58, 4, 3));
+
+    // Without a declaration, the content preceding the root element is skipped, even when it spans several buffers
+    let xml = "<br />\n<b>Warning</b>: a < b in <b>feed.php</b> on line 3\n<atom:feed xmlns:atom=\"http://www.w3.org/2005/Atom\"><atom:entry/></atom:feed>";
+    for capacity in [3, 7, 64] {
+        let source = ElementSource::with_config(BufReader::with_capacity(capacity, xml.as_bytes()), None, config.clone())?;
+        let root = source.root()?.unwrap();
+        assert_eq!(root.name, "feed");
+        assert_eq!(root.children().next().unwrap()?.name, "entry");
+        let position = source.position();
+        assert_eq!((position.line, position.column), (3, 53));
+    }
+
+    // Markup at the start of the document is retained
+    let xml = "\u{feff}\n<!-- generated --><rss version=\"2.0\"/>";
+    let source = ElementSource::with_config(xml.as_bytes(), None, config)?;
+    assert_eq!(source.root()?.unwrap().name, "rss");
+
+    // Without recovery, the same content fails
+    let xml = "<feed><entry><content>a<br>b</content></entry></feed>";
+    let source = ElementSource::new(xml.as_bytes(), None)?;
+    let root = source.root()?.unwrap();
+    assert!(root.children().next().unwrap()?.children().next().unwrap()?.children_as_string().is_err());
+
+    Ok(())
+}
+
+// Verify the mixed case names include each element name matched by the parsers, so these are matched regardless of case
+#[test]
+fn test_mixed_case_names() -> TestResult {
+    let mut dirs = vec![std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("src/parser")];
+    let mut names = Vec::new();
+    while let Some(dir) = dirs.pop() {
+        for entry in std::fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                dirs.push(path);
+            } else if path.extension().is_some_and(|ext| ext == "rs") && !path.ends_with("tests.rs") {
+                names.extend(matched_names(&std::fs::read_to_string(path)?));
+            }
+        }
+    }
+
+    assert!(names.iter().any(|name| name == "pubDate"));
+    for name in names.iter().filter(|name| name.chars().any(|c| c.is_ascii_uppercase())) {
+        assert_eq!(
+            &normalize_name(&name.to_ascii_lowercase()),
+            name,
+            "{} is missing from the mixed case names",
+            name
+        );
+    }
+    Ok(())
+}
+
+// Finds the element names in a parser source: the names in a namespace (e.g. `(NS::RSS, "pubDate")`) or arms of a match on the name alone
+fn matched_names(source: &str) -> Vec<String> {
+    let literal = |text: &str| text.strip_prefix('"').and_then(|rest| rest.split_once('"')).map(|(name, _)| name.to_string());
+
+    let mut names = Vec::new();
+    let mut name_match: Option<usize> = None;
+    for line in source.lines() {
+        let trimmed = line.trim_start();
+        let indent = line.len() - trimmed.len();
+        names.extend(
+            line.split("(NS::")
+                .skip(1)
+                .filter_map(|rest| rest.split_once(", ").and_then(|(_, name)| literal(name))),
+        );
+
+        match name_match {
+            Some(depth) if indent == depth && trimmed.starts_with('}') => name_match = None,
+            Some(_) => names.extend(literal(trimmed)),
+            None if trimmed.starts_with("match ") && trimmed.contains("ns_and_tag().1") => name_match = Some(indent),
+            None => {}
+        }
+    }
+    names
+}
@@ -0,0 +1,138 @@
+#![cfg(feature = "async")]
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use feed_rs::model::{Link, Text};
+use feed_rs::parser::{self, Limit, ParseErrorKind, ParseFeedError};
+use tokio::io::{AsyncRead, BufReader, ReadBuf};
+
+// Deterministic IDs so the results of the synchronous and asynchronous parsers can be compared
+fn test_parser() -> parser::Parser {
+    parser::Builder::new()
+        .id_generator(|links: &[Link], title: &Option<Text>, _uri| {
+            format!("{:?}-{:?}", links.first().map(|link| &link.href), title.as_ref().map(|title| &title.content))
+        })
+        .build()
+}
+
+fn fixtures(dir: &Path, files: &mut Vec<PathBuf>) {
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            fixtures(&path, files);
+        } else if path.extension().is_some_and(|ext| ext == "xml" || ext == "json") && !path.to_str().unwrap().ends_with(".serde.json") {
+            files.push(path);
+        }
+    }
+}
+
+// Verify that parsing every fixture asynchronously produces the same result as the synchronous parser
+#[tokio::test]
+async fn test_fixtures() {
+    let mut files = Vec::new();
+    fixtures(&PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("fixture"), &mut files);
+
+    let parser = test_parser();
+    for path in files {
+        let source = fs::read(&path).unwrap();
+        let expected = parser.parse(source.as_slice());
+
+        // A small buffer so the content arrives in many pieces
+        let actual = parser.parse_async(BufReader::with_capacity(16, source.as_slice())).await;
+
+        match (expected, actual) {
+            (Ok(expected), Ok(actual)) => assert_eq!(expected, actual, "{:?}", path),
+            (Err(_), Err(_)) => {}
+            (expected, actual) => panic!("{:?} parsed as {:?} but asynchronously as {:?}", path, expected.is_ok(), actual.is_ok()),
+        }
+    }
+}
+
+// Errors in the XML are reported
+#[tokio::test]
+async fn test_malformed() {
+    let xml = "<rss version=\"2.0\"><channel><title>t</title><item><title>one</title>";
+    assert!(parser::Builder::new().build().parse_async(xml.as_bytes()).await.is_err());
+
+    assert!(parser::Builder::new().build().parse_async("not a feed".as_bytes()).await.is_err());
+
+    // Unless recovering, in which case the elements are closed
+    let parser = parser::Builder::new().recover_xml(true).build();
+    let feed = parser.parse_async(format!("junk\n<?xml version=\"1.0\"?>{}", xml).as_bytes()).await.unwrap();
+    assert_eq!(feed.entries.len(), 1);
+    assert_eq!(feed.entries[0].title.as_ref().unwrap().content, "one");
+
+    // The junk may contain markup and span several buffers, with no declaration following it
+    let source = format!("<b>junk</b> a < b\n{}", xml);
+    let feed = parser.parse_async(BufReader::with_capacity(4, source.as_bytes())).await.unwrap();
+    assert_eq!(feed.entries.len(), 1);
+}
+
+// Limits apply to content read asynchronously
+#[tokio::test]
+async fn test_limits() {
+    let xml = "<rss version=\"2.0\"><channel><title>t</title><item><title>one</title></item></channel></rss>";
+
+    let parser = parser::Builder::new().max_bytes(xml.len() as u64).max_depth(4).build();
+    assert!(parser.parse_async(xml.as_bytes()).await.is_ok());
+
+    let parser = parser::Builder::new().max_bytes(xml.len() as u64 - 1).build();
+    let error = parser.parse_async(BufReader::with_capacity(16, xml.as_bytes())).await.unwrap_err();
+    assert!(matches!(error, ParseFeedError::ParseError(ParseErrorKind::LimitExceeded(Limit::Bytes), _)));
+
+    let parser = parser::Builder::new().max_depth(3).build();
+    let error = parser.parse_async(xml.as_bytes()).await.unwrap_err();
+    assert!(matches!(error, ParseFeedError::ParseError(ParseErrorKind::LimitExceeded(Limit::Depth), _)));
+
+    let json = r#"{"version": "https://jsonfeed.org/version/1.1", "title": "t", "items": []}"#;
+    let parser = parser::Builder::new().max_bytes(16).build();
+    let error = parser.parse_async(json.as_bytes()).await.unwrap_err();
+    assert!(matches!(error, ParseFeedError::ParseError(ParseErrorKind::LimitExceeded(Limit::Bytes), _)));
+}
+
+// A source that never ends, repeating the content following the prefix
+struct Endless {
+    content: Vec<u8>,
+    prefix: usize,
+    position: usize,
+}
+
+impl Endless {
+    fn new(prefix: &str, repeated: &str) -> Endless {
+        Endless {
+            content: [prefix, repeated].concat().into_bytes(),
+            prefix: prefix.len(),
+            position: 0,
+        }
+    }
+}
+
+impl AsyncRead for Endless {
+    fn poll_read(mut self: Pin<&mut Self>, _cx: &mut Context<'_>, buf: &mut ReadBuf<'_>) -> Poll<std::io::Result<()>> {
+        let count = buf.remaining().min(self.content.len() - self.position);
+        let start = self.position;
+        buf.put_slice(&self.content[start..start + count]);
+        self.position += count;
+        if self.position == self.content.len() {
+            self.position = self.prefix;
+        }
+        Poll::Ready(Ok(()))
+    }
+}
+
+// The limits are applied as the content is read, rather than once the source is exhausted
+#[tokio::test]
+async fn test_limits_endless() {
+    let parser = parser::Builder::new().max_text_length(1024).build();
+    let source = BufReader::new(Endless::new("<rss version=\"2.0\"><channel><title>", "fish &amp; <![CDATA[chips]]> "));
+    let error = parser.parse_async(source).await.unwrap_err();
+    assert!(matches!(error, ParseFeedError::ParseError(ParseErrorKind::LimitExceeded(Limit::TextLength), _)));
+
+    let parser = parser::Builder::new().max_depth(32).build();
+    let source = BufReader::new(Endless::new("<rss version=\"2.0\"><channel>", "<div>"));
+    let error = parser.parse_async(source).await.unwrap_err();
+    assert!(matches!(error, ParseFeedError::ParseError(ParseErrorKind::LimitExceeded(Limit::Depth), _)));
+}
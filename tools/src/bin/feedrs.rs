@@ -0,0 +1,93 @@
+use std::fs::File;
+use std::io::{self, BufWriter, Read, Write};
+use std::path::PathBuf;
+
+use clap::{Parser, Subcommand, ValueEnum};
+use feed_rs::model::FeedType;
+use feed_rs::{parser, writer};
+
+// Command line interface to the feed-rs parser and writers
+#[derive(Parser)]
+#[command(name = "feedrs", about = "Parse and convert Atom, RSS and JSON feeds")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Convert a feed from any supported format to the target format
+    Convert {
+        /// Format of the output
+        #[arg(long, value_enum)]
+        to: Format,
+
+        /// Source of the content, used to resolve relative URLs
+        #[arg(long)]
+        base_uri: Option<String>,
+
+        /// Sanitize HTML content to remove potentially dangerous elements
+        #[arg(long)]
+        sanitize: bool,
+
+        /// Indent the output for readability
+        #[arg(long)]
+        pretty: bool,
+
+        /// File containing the feed, or "-" to read from stdin
+        #[arg(default_value = "-")]
+        input: PathBuf,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    Atom,
+    Json,
+    Rss1,
+    Rss2,
+}
+
+impl From<Format> for FeedType {
+    fn from(format: Format) -> Self {
+        match format {
+            Format::Atom => FeedType::Atom,
+            Format::Json => FeedType::JSON,
+            Format::Rss1 => FeedType::RSS1,
+            Format::Rss2 => FeedType::RSS2,
+        }
+    }
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    match Cli::parse().command {
+        Command::Convert {
+            to,
+            base_uri,
+            sanitize,
+            pretty,
+            input,
+        } => convert(to, base_uri, sanitize, pretty, input),
+    }
+}
+
+// Parses the input and writes it to stdout in the requested format
+fn convert(to: Format, base_uri: Option<String>, sanitize: bool, pretty: bool, input: PathBuf) -> Result<(), Box<dyn std::error::Error>> {
+    let source: Box<dyn Read> = if input.as_os_str() == "-" {
+        Box::new(io::stdin().lock())
+    } else {
+        Box::new(File::open(&input)?)
+    };
+
+    let parser = parser::Builder::new().base_uri(base_uri).sanitize_content(sanitize).build();
+    let feed = parser.parse(source)?;
+
+    let stdout = io::stdout().lock();
+    let mut out = BufWriter::new(stdout);
+    let writer = writer::Builder::new().pretty(pretty).build();
+    writer.write(&feed, to.into(), &mut out)?;
+    writeln!(out)?;
+    out.flush()?;
+
+    Ok(())
+}
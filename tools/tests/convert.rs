@@ -0,0 +1,130 @@
+use std::fs;
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+const ATOM: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<feed xmlns="http://www.w3.org/2005/Atom">
+    <title>Example Feed</title>
+    <id>urn:uuid:60a76c80-d399-11d9-b93C-0003939e0af6</id>
+    <updated>2003-12-13T18:30:02Z</updated>
+    <link href="/feed" rel="self"/>
+    <entry>
+        <title>Atom-Powered Robots Run Amok</title>
+        <id>urn:uuid:1225c695-cfb8-4ebb-aaaa-80da344efa6a</id>
+        <updated>2003-12-13T18:30:02Z</updated>
+        <content type="html">&lt;p&gt;Some text.&lt;/p&gt;&lt;script&gt;alert(1)&lt;/script&gt;</content>
+    </entry>
+</feed>"#;
+
+// Runs the command with the arguments, supplying the input on stdin
+fn feedrs(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_feedrs"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    // The command may exit without reading the input (e.g. on a usage error)
+    let _ = child.stdin.take().unwrap().write_all(stdin.as_bytes());
+    child.wait_with_output().unwrap()
+}
+
+// Runs the command expecting it to succeed, returning the output
+fn convert(args: &[&str], stdin: &str) -> String {
+    let output = feedrs(args, stdin);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    String::from_utf8(output.stdout).unwrap()
+}
+
+// Verify the input is read from stdin by default, or when given as "-"
+#[test]
+fn test_stdin() {
+    let output = convert(&["convert", "--to", "json"], ATOM);
+    assert_eq!(output, convert(&["convert", "--to", "json", "-"], ATOM));
+
+    let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(json["title"], "Example Feed");
+    assert_eq!(json["items"][0]["id"], "urn:uuid:1225c695-cfb8-4ebb-aaaa-80da344efa6a");
+}
+
+// Verify the input may be read from a file
+#[test]
+fn test_file() {
+    let path = std::env::temp_dir().join(format!("feedrs-convert-{}.xml", std::process::id()));
+    fs::write(&path, ATOM).unwrap();
+    let output = convert(&["convert", "--to", "json", path.to_str().unwrap()], "");
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(output, convert(&["convert", "--to", "json"], ATOM));
+}
+
+// Verify each output format is written, and can be parsed again
+#[test]
+fn test_formats() {
+    let formats = [
+        ("atom", "<feed xmlns=\"http://www.w3.org/2005/Atom\""),
+        ("json", "{\"version\":\"https://jsonfeed.org/version/1.1\""),
+        ("rss1", "<rdf:RDF"),
+        ("rss2", "<rss version=\"2.0\""),
+    ];
+    for (format, root) in formats {
+        let output = convert(&["convert", "--to", format], ATOM);
+        assert!(output.contains(root), "{} output was {}", format, output);
+        assert!(output.ends_with('\n'));
+
+        let feed = feed_rs::parser::parse(output.as_bytes()).unwrap();
+        assert_eq!(feed.title.unwrap().content, "Example Feed");
+        assert_eq!(feed.entries.len(), 1);
+    }
+}
+
+// Verify the options controlling the parser and writer are applied
+#[test]
+fn test_options() {
+    // The relative link is resolved against the base URI
+    let output = convert(&["convert", "--to", "json", "--base-uri", "https://example.com/"], ATOM);
+    assert!(output.contains("\"feed_url\":\"https://example.com/feed\""), "{}", output);
+
+    // Content is only sanitized on request
+    let output = convert(&["convert", "--to", "json"], ATOM);
+    assert!(output.contains("<script>"), "{}", output);
+    let output = convert(&["convert", "--to", "json", "--sanitize"], ATOM);
+    assert!(!output.contains("<script>"), "{}", output);
+    assert!(output.contains("<p>Some text.</p>"), "{}", output);
+
+    // The output is only indented on request
+    let output = convert(&["convert", "--to", "rss2"], ATOM);
+    assert_eq!(output.lines().count(), 1);
+    let output = convert(&["convert", "--to", "rss2", "--pretty"], ATOM);
+    assert!(output.lines().count() > 1);
+    assert!(output.contains("\n  <channel>"), "{}", output);
+    let output = convert(&["convert", "--to", "json", "--pretty"], ATOM);
+    assert!(output.contains("\n  \"title\": \"Example Feed\""), "{}", output);
+}
+
+// Verify failures are reported with a non-zero exit code
+#[test]
+fn test_errors() {
+    // Content that is not a feed, or a missing file
+    let output = feedrs(&["convert", "--to", "json"], "not a feed");
+    assert_eq!(output.status.code(), Some(1));
+    assert!(output.stdout.is_empty());
+    assert!(!output.stderr.is_empty());
+
+    let output = feedrs(&["convert", "--to", "json", "/nonexistent/feed.xml"], "");
+    assert_eq!(output.status.code(), Some(1));
+    assert!(!output.stderr.is_empty());
+
+    // Invalid arguments are reported as usage errors
+    for args in [
+        &["convert"][..],
+        &["convert", "--to", "html"],
+        &["convert", "--to", "json", "--unknown"],
+        &["export"],
+    ] {
+        let output = feedrs(args, ATOM);
+        assert_eq!(output.status.code(), Some(2), "{:?}", args);
+        assert!(output.stdout.is_empty());
+    }
+}